@@ -1,11 +1,33 @@
 //! Supported AI coding agents and their skill directories.
 
-use serde::Deserialize;
-use std::path::{Path, PathBuf};
+use crate::cli::ScriptLang;
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::path::{Component, Path, PathBuf};
+
+/// Agents defined in config, registered once at startup.
+static CUSTOM_AGENTS: OnceCell<Vec<CustomAgent>> = OnceCell::new();
+
+/// Built-in agents, in listing order.
+const BUILTIN_AGENTS: [Agent; 14] = [
+    Agent::OpenCode,
+    Agent::Claude,
+    Agent::Codex,
+    Agent::Cursor,
+    Agent::Amp,
+    Agent::KiloCode,
+    Agent::RooCode,
+    Agent::Goose,
+    Agent::Gemini,
+    Agent::Antigravity,
+    Agent::Copilot,
+    Agent::Clawdbot,
+    Agent::Droid,
+    Agent::Windsurf,
+];
 
 /// Supported AI coding agents.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize)]
-#[serde(rename_all = "kebab-case")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub enum Agent {
     /// OpenCode.
     OpenCode,
@@ -36,10 +58,49 @@ pub enum Agent {
     Droid,
     /// Windsurf by Codeium.
     Windsurf,
+    /// An agent defined in an `[[agents]]` config entry.
+    Custom(&'static CustomAgent),
+}
+
+/// An agent defined in config.
+///
+/// ```toml
+/// [[agents]]
+/// name = "acme"
+/// display_name = "Acme Assistant"
+/// skills_dir = ".acme/skills"
+/// global_skills_dir = "~/.acme/skills"
+/// features = { allowed_tools = true }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct CustomAgent {
+    /// Name used with `--agent` and in config.
+    pub name: String,
+    /// Name shown in listings (defaults to `name`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// Project-level skills directory, relative to the project root.
+    pub skills_dir: String,
+    /// Global skills directory; `~/` expands to the home directory.
+    pub global_skills_dir: String,
+    /// Directory whose presence in a project means the agent is used
+    /// (defaults to `skills_dir`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detection_dir: Option<String>,
+    /// Directory whose presence means the agent is installed globally
+    /// (defaults to `global_skills_dir`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub global_detection_dir: Option<String>,
+    /// Supported skill features.
+    #[serde(default)]
+    pub features: AgentFeatures,
 }
 
 /// Agent feature support flags.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+///
+/// The default is script support only, the baseline every agent offers.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(default)]
 pub struct AgentFeatures {
     /// Supports `context: fork` in SKILL.md.
     pub context_fork: bool,
@@ -49,6 +110,76 @@ pub struct AgentFeatures {
     pub allowed_tools: bool,
     /// Supports scripts.
     pub scripts: bool,
+    /// Script languages the agent can run. If None, any language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub script_langs: Option<Vec<ScriptLang>>,
+}
+
+impl Default for AgentFeatures {
+    fn default() -> Self {
+        Self {
+            context_fork: false,
+            hooks: false,
+            allowed_tools: false,
+            scripts: true,
+            script_langs: None,
+        }
+    }
+}
+
+impl CustomAgent {
+    /// Check the agent's name and directories.
+    ///
+    /// Names must be lowercase alphanumeric with hyphens and must not clash
+    /// with `all` or a built-in agent. `skills_dir` must be relative and stay
+    /// inside the project; `global_skills_dir` must be under `~/`.
+    pub fn validate(&self) -> Result<(), String> {
+        let valid = !self.name.is_empty()
+            && self
+                .name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+        if !valid {
+            return Err(format!(
+                "invalid agent name '{}': use lowercase letters, digits and hyphens",
+                self.name
+            ));
+        }
+        let clashes = self.name == "all"
+            || BUILTIN_AGENTS
+                .iter()
+                .any(|a| a.cli_name() == self.name || a.config_name() == self.name);
+        if clashes {
+            return Err(format!(
+                "agent name '{}' is already used by a built-in agent",
+                self.name
+            ));
+        }
+
+        if !is_inside(&self.skills_dir) {
+            return Err(format!(
+                "agent '{}': skills_dir '{}' must be a relative path inside the project",
+                self.name, self.skills_dir
+            ));
+        }
+        let global = self.global_skills_dir.strip_prefix("~/").unwrap_or("");
+        if !is_inside(global) {
+            return Err(format!(
+                "agent '{}': global_skills_dir '{}' must be a path under ~/",
+                self.name, self.global_skills_dir
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Whether `path` is a non-empty relative path that stays inside its base.
+fn is_inside(path: &str) -> bool {
+    let path = Path::new(path);
+    path.components().any(|c| matches!(c, Component::Normal(_)))
+        && path
+            .components()
+            .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
 }
 
 /// Information about a detected agent.
@@ -65,24 +196,48 @@ pub struct DetectedAgent {
 }
 
 impl Agent {
-    /// Returns all supported agents.
-    pub fn all() -> &'static [Agent] {
-        &[
-            Agent::OpenCode,
-            Agent::Claude,
-            Agent::Codex,
-            Agent::Cursor,
-            Agent::Amp,
-            Agent::KiloCode,
-            Agent::RooCode,
-            Agent::Goose,
-            Agent::Gemini,
-            Agent::Antigravity,
-            Agent::Copilot,
-            Agent::Clawdbot,
-            Agent::Droid,
-            Agent::Windsurf,
-        ]
+    /// Returns all supported agents: the built-in ones, then those from config.
+    pub fn all() -> Vec<Agent> {
+        let custom = CUSTOM_AGENTS.get().map(Vec::as_slice).unwrap_or_default();
+        BUILTIN_AGENTS
+            .into_iter()
+            .chain(custom.iter().map(Agent::Custom))
+            .collect()
+    }
+
+    /// Returns the built-in agents.
+    pub fn builtin() -> &'static [Agent] {
+        &BUILTIN_AGENTS
+    }
+
+    /// Register the agents defined in config.
+    ///
+    /// Agents are registered once, before anything refers to them by name;
+    /// a second call is an error.
+    pub fn register(agents: Vec<CustomAgent>) -> Result<(), String> {
+        for agent in &agents {
+            agent.validate()?;
+        }
+        CUSTOM_AGENTS
+            .set(agents)
+            .map_err(|_| "custom agents are already registered".to_string())
+    }
+
+    /// Look up an agent by its config name (`open-code`) or CLI name (`opencode`).
+    pub fn from_name(name: &str) -> Option<Agent> {
+        Agent::all()
+            .into_iter()
+            .find(|a| a.config_name() == name || a.cli_name() == name)
+    }
+
+    /// Returns the name used in config files and accepted by `--agent`.
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            Agent::OpenCode => "open-code",
+            Agent::KiloCode => "kilo-code",
+            Agent::RooCode => "roo-code",
+            _ => self.cli_name(),
+        }
     }
 
     /// Returns the project-level skills directory for this agent.
@@ -102,6 +257,7 @@ impl Agent {
             Agent::Clawdbot => "skills",
             Agent::Droid => ".factory/skills",
             Agent::Windsurf => ".windsurf/skills",
+            Agent::Custom(agent) => &agent.skills_dir,
         }
     }
 
@@ -122,6 +278,7 @@ impl Agent {
             Agent::Clawdbot => "~/.clawdbot/skills",
             Agent::Droid => "~/.factory/skills",
             Agent::Windsurf => "~/.codeium/windsurf/skills",
+            Agent::Custom(agent) => &agent.global_skills_dir,
         }
     }
 
@@ -142,6 +299,7 @@ impl Agent {
             Agent::Clawdbot => "Clawdbot",
             Agent::Droid => "Droid",
             Agent::Windsurf => "Windsurf",
+            Agent::Custom(agent) => agent.display_name.as_deref().unwrap_or(&agent.name),
         }
     }
 
@@ -162,6 +320,7 @@ impl Agent {
             Agent::Clawdbot => "clawdbot",
             Agent::Droid => "droid",
             Agent::Windsurf => "windsurf",
+            Agent::Custom(agent) => &agent.name,
         }
     }
 
@@ -173,19 +332,17 @@ impl Agent {
                 hooks: true,
                 allowed_tools: true,
                 scripts: true,
+                script_langs: None,
             },
             Agent::Cursor | Agent::Codex | Agent::OpenCode | Agent::Antigravity => AgentFeatures {
                 context_fork: false,
                 hooks: false,
                 allowed_tools: true, // Partial support
                 scripts: true,
+                script_langs: None,
             },
-            _ => AgentFeatures {
-                context_fork: false,
-                hooks: false,
-                allowed_tools: false,
-                scripts: true,
-            },
+            Agent::Custom(agent) => agent.features.clone(),
+            _ => AgentFeatures::default(),
         }
     }
 
@@ -206,6 +363,7 @@ impl Agent {
             Agent::Clawdbot => "skills", // Special case: no dot prefix
             Agent::Droid => ".factory",
             Agent::Windsurf => ".windsurf",
+            Agent::Custom(agent) => agent.detection_dir.as_deref().unwrap_or(&agent.skills_dir),
         }
     }
 
@@ -226,6 +384,10 @@ impl Agent {
             Agent::Clawdbot => "~/.clawdbot",
             Agent::Droid => "~/.factory",
             Agent::Windsurf => "~/.codeium/windsurf",
+            Agent::Custom(agent) => agent
+                .global_detection_dir
+                .as_deref()
+                .unwrap_or(&agent.global_skills_dir),
         }
     }
 
@@ -255,18 +417,16 @@ impl Agent {
     /// Detect all agents installed at the project level.
     pub fn detect_project(project_root: &Path) -> Vec<Agent> {
         Agent::all()
-            .iter()
+            .into_iter()
             .filter(|a| a.is_detected_project(project_root))
-            .copied()
             .collect()
     }
 
     /// Detect all agents installed at the global level.
     pub fn detect_global() -> Vec<Agent> {
         Agent::all()
-            .iter()
+            .into_iter()
             .filter(|a| a.is_detected_global())
-            .copied()
             .collect()
     }
 
@@ -274,7 +434,7 @@ impl Agent {
     pub fn detect_all(project_root: &Path) -> Vec<DetectedAgent> {
         let mut detected = Vec::new();
 
-        for agent in Agent::all() {
+        for agent in &Agent::all() {
             // Check project level
             let project_path = agent.resolve_project_skills_dir(project_root);
             if agent.is_detected_project(project_root) {
@@ -305,6 +465,26 @@ impl Agent {
     }
 }
 
+impl Serialize for Agent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.config_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Agent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Agent::from_name(&name).ok_or_else(|| {
+            let names: Vec<&str> = Agent::all().iter().map(|a| a.config_name()).collect();
+            serde::de::Error::custom(format!(
+                "unknown agent '{}', expected one of {}",
+                name,
+                names.join(", ")
+            ))
+        })
+    }
+}
+
 impl std::fmt::Display for Agent {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.cli_name())
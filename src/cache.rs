@@ -6,13 +6,28 @@
 //! ├── config.toml
 //! └── git/
 //!     ├── checkouts/    # Working trees at specific commits
-//!     └── db/           # Bare git repositories (fetch targets)
+//!     ├── db/           # Bare git repositories (fetch targets)
+//!     └── usage.json    # When each repository and checkout was last used
 //! ```
+//!
+//! With `[cache] max_size` set, the least recently used entries are evicted
+//! once the cache grows past the budget.
 
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::env;
 use std::fs;
-use std::path::PathBuf;
-use std::time::SystemTime;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const KB: u64 = 1024;
+const MB: u64 = KB * 1024;
+const GB: u64 = MB * 1024;
+
+/// File in the git cache directory recording when entries were last used.
+const USAGE_FILE: &str = "usage.json";
 
 /// Get the skilo home directory.
 ///
@@ -129,6 +144,19 @@ pub struct CachedCheckout {
     pub modified: Option<SystemTime>,
 }
 
+/// Outcome of a `cache clean`.
+#[derive(Debug, Default)]
+pub struct CleanReport {
+    /// Maximum checkout age in days, or `None` if the whole cache was cleared.
+    pub max_age_days: Option<u32>,
+    /// Number of repositories removed from db/.
+    pub repos_removed: usize,
+    /// Number of checkouts removed from checkouts/.
+    pub checkouts_removed: usize,
+    /// Bytes freed.
+    pub freed: u64,
+}
+
 /// Get cache statistics.
 #[derive(Debug, Default)]
 pub struct CacheStats {
@@ -222,10 +250,6 @@ fn dir_size(path: &PathBuf) -> u64 {
 
 /// Format bytes as human-readable string.
 pub fn format_size(bytes: u64) -> String {
-    const KB: u64 = 1024;
-    const MB: u64 = KB * 1024;
-    const GB: u64 = MB * 1024;
-
     if bytes >= GB {
         format!("{:.1} GB", bytes as f64 / GB as f64)
     } else if bytes >= MB {
@@ -237,6 +261,230 @@ pub fn format_size(bytes: u64) -> String {
     }
 }
 
+/// Parse a size such as `500MB`, `1.5 GB` or `1048576` (bytes).
+///
+/// Units are binary, matching [`format_size`]: `1KB` is 1024 bytes.
+pub fn parse_size(s: &str) -> Option<u64> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let number: f64 = number.parse().ok()?;
+    let multiplier = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" | "kib" => KB,
+        "m" | "mb" | "mib" => MB,
+        "g" | "gb" | "gib" => GB,
+        _ => return None,
+    };
+    Some((number * multiplier as f64) as u64)
+}
+
+/// Kind of cache entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EntryKind {
+    /// A bare repository in `db/`.
+    Repo,
+    /// A working tree in `checkouts/`.
+    Checkout,
+}
+
+impl EntryKind {
+    /// Directory holding entries of this kind, relative to the git cache.
+    fn dir_name(&self) -> &'static str {
+        match self {
+            Self::Repo => "db",
+            Self::Checkout => "checkouts",
+        }
+    }
+}
+
+/// Last-use times of cache entries, in seconds since the Unix epoch.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UsageIndex {
+    #[serde(default)]
+    db: BTreeMap<String, u64>,
+    #[serde(default)]
+    checkouts: BTreeMap<String, u64>,
+}
+
+impl UsageIndex {
+    /// Load the index from the git cache directory, starting empty if it is
+    /// missing or unreadable.
+    pub fn load(git: &Path) -> Self {
+        fs::read_to_string(git.join(USAGE_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write the index to the git cache directory.
+    pub fn save(&self, git: &Path) -> io::Result<()> {
+        ensure_dir(&git.to_path_buf())?;
+        let content = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(git.join(USAGE_FILE), content)
+    }
+
+    /// Mark an entry as used now.
+    pub fn touch(&mut self, kind: EntryKind, name: &str) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        self.entries_mut(kind).insert(name.to_string(), now);
+    }
+
+    /// When an entry was last used, if recorded.
+    pub fn last_used(&self, kind: EntryKind, name: &str) -> Option<u64> {
+        match kind {
+            EntryKind::Repo => self.db.get(name).copied(),
+            EntryKind::Checkout => self.checkouts.get(name).copied(),
+        }
+    }
+
+    fn entries_mut(&mut self, kind: EntryKind) -> &mut BTreeMap<String, u64> {
+        match kind {
+            EntryKind::Repo => &mut self.db,
+            EntryKind::Checkout => &mut self.checkouts,
+        }
+    }
+}
+
+/// Record that a repository and one of its checkouts were just used.
+///
+/// Failing to update the index only makes eviction less accurate.
+pub fn record_use(repo: &str, checkout: &str) {
+    let Some(git) = git_dir() else {
+        return;
+    };
+    let mut index = UsageIndex::load(&git);
+    index.touch(EntryKind::Repo, repo);
+    index.touch(EntryKind::Checkout, checkout);
+    let _ = index.save(&git);
+}
+
+/// A cache entry removed to fit the size budget.
+#[derive(Debug, Clone, Serialize)]
+pub struct Evicted {
+    /// Whether the entry is a repository or a checkout.
+    pub kind: EntryKind,
+    /// Entry name (`owner-repo` or `owner-repo-rev`).
+    pub name: String,
+    /// Size in bytes.
+    pub size: u64,
+}
+
+/// Outcome of evicting cache entries.
+#[derive(Debug, Default)]
+pub struct GcReport {
+    /// Entries removed, least recently used first.
+    pub evicted: Vec<Evicted>,
+    /// Cache size before eviction, in bytes.
+    pub size_before: u64,
+    /// Cache size after eviction, in bytes.
+    pub size_after: u64,
+}
+
+/// Run `git gc` on every bare repository in the git cache directory.
+///
+/// Returns the number of repositories compacted. Repositories are skipped
+/// if `git` is not installed.
+pub fn compact_repos(git: &Path) -> usize {
+    let Ok(entries) = fs::read_dir(git.join(EntryKind::Repo.dir_name())) else {
+        return 0;
+    };
+
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter(|entry| {
+            Command::new("git")
+                .arg("-C")
+                .arg(entry.path())
+                .args(["gc", "--quiet"])
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()
+                .is_ok_and(|status| status.success())
+        })
+        .count()
+}
+
+/// Remove the least recently used entries from the git cache directory
+/// until it takes at most `max_size` bytes.
+///
+/// Entries missing from the usage index count as last used when they were
+/// last modified. Checkouts named in `protected` are never evicted, though
+/// their size still counts. With `dry_run`, nothing is removed.
+pub fn evict(
+    git: &Path,
+    max_size: u64,
+    protected: &[String],
+    dry_run: bool,
+) -> io::Result<GcReport> {
+    let mut index = UsageIndex::load(git);
+
+    let mut entries = Vec::new();
+    for kind in [EntryKind::Repo, EntryKind::Checkout] {
+        let Ok(dir) = fs::read_dir(git.join(kind.dir_name())) else {
+            continue;
+        };
+        for entry in dir.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().to_string();
+            let last_used = index.last_used(kind, &name).unwrap_or_else(|| {
+                entry
+                    .metadata()
+                    .and_then(|m| m.modified())
+                    .ok()
+                    .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                    .map_or(0, |d| d.as_secs())
+            });
+            entries.push((last_used, kind, name, dir_size(&path), path));
+        }
+    }
+    entries.sort_by(|a, b| (a.0, &a.2).cmp(&(b.0, &b.2)));
+
+    let size_before: u64 = entries.iter().map(|e| e.3).sum();
+    let mut report = GcReport {
+        size_before,
+        size_after: size_before,
+        ..GcReport::default()
+    };
+
+    let mut failure = None;
+    for (_, kind, name, size, path) in entries {
+        if report.size_after <= max_size {
+            break;
+        }
+        if kind == EntryKind::Checkout && protected.contains(&name) {
+            continue;
+        }
+        if !dry_run {
+            if let Err(e) = fs::remove_dir_all(&path) {
+                failure = Some(e);
+                break;
+            }
+            index.entries_mut(kind).remove(&name);
+        }
+        report.size_after -= size;
+        report.evicted.push(Evicted { kind, name, size });
+    }
+
+    // Entries removed before a failure are gone, so record them either way
+    if !dry_run && !report.evicted.is_empty() {
+        index.save(git)?;
+    }
+    match failure {
+        Some(e) => Err(e),
+        None => Ok(report),
+    }
+}
+
 /// Clean checkouts older than the given age in days.
 pub fn clean_old_checkouts(max_age_days: u32) -> std::io::Result<(usize, u64)> {
     let checkouts = match checkouts_dir() {
@@ -327,6 +575,7 @@ pub fn clean_all() -> std::io::Result<(usize, usize, u64)> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tempfile::TempDir;
 
     #[test]
     fn test_parse_owner_repo_https() {
@@ -362,4 +611,108 @@ mod tests {
         assert_eq!(format_size(1024 * 1024), "1.0 MB");
         assert_eq!(format_size(1024 * 1024 * 1024), "1.0 GB");
     }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("1048576"), Some(1024 * 1024));
+        assert_eq!(parse_size("500MB"), Some(500 * 1024 * 1024));
+        assert_eq!(parse_size("1.5 GB"), Some(3 * 512 * 1024 * 1024));
+        assert_eq!(parse_size("2k"), Some(2048));
+        assert_eq!(parse_size("lots"), None);
+        assert_eq!(parse_size("5 TB"), None);
+    }
+
+    #[test]
+    fn test_evict_least_recently_used_first() {
+        let temp = TempDir::new().unwrap();
+        let git = temp.path();
+        let mut index = UsageIndex::default();
+        for (kind, name) in [
+            (EntryKind::Repo, "a-repo"),
+            (EntryKind::Checkout, "a-repo-1111111"),
+            (EntryKind::Checkout, "a-repo-2222222"),
+        ] {
+            let dir = git.join(kind.dir_name()).join(name);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("data"), vec![0u8; 100]).unwrap();
+            index.touch(kind, name);
+        }
+        // The repository was used most recently, the first checkout least
+        index.db.insert("a-repo".into(), 300);
+        index.checkouts.insert("a-repo-1111111".into(), 100);
+        index.checkouts.insert("a-repo-2222222".into(), 200);
+        index.save(git).unwrap();
+
+        let report = evict(git, 250, &[], true).unwrap();
+        assert_eq!(report.size_before, 300);
+        assert_eq!(report.size_after, 200);
+        assert!(git.join("checkouts/a-repo-1111111").exists());
+
+        let report = evict(git, 150, &[], false).unwrap();
+        let evicted: Vec<&str> = report.evicted.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(evicted, vec!["a-repo-1111111", "a-repo-2222222"]);
+        assert!(!git.join("checkouts/a-repo-2222222").exists());
+        assert!(git.join("db/a-repo").exists());
+
+        let index = UsageIndex::load(git);
+        assert_eq!(index.last_used(EntryKind::Checkout, "a-repo-1111111"), None);
+        assert_eq!(index.last_used(EntryKind::Repo, "a-repo"), Some(300));
+    }
+
+    #[test]
+    fn test_evict_skips_protected_checkouts() {
+        let temp = TempDir::new().unwrap();
+        let git = temp.path();
+        let mut index = UsageIndex::default();
+        for (name, last_used) in [("a-repo-1111111", 100), ("a-repo-2222222", 200)] {
+            let dir = git.join("checkouts").join(name);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("data"), vec![0u8; 100]).unwrap();
+            index.checkouts.insert(name.into(), last_used);
+        }
+        index.save(git).unwrap();
+
+        let report = evict(git, 0, &["a-repo-1111111".to_string()], false).unwrap();
+        let evicted: Vec<&str> = report.evicted.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(evicted, vec!["a-repo-2222222"]);
+        assert_eq!(report.size_after, 100);
+        assert!(git.join("checkouts/a-repo-1111111").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_evict_records_removals_before_a_failure() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = TempDir::new().unwrap();
+        let git = temp.path();
+        let mut index = UsageIndex::default();
+        for (name, last_used) in [("a-repo-1111111", 100), ("a-repo-2222222", 200)] {
+            let dir = git.join("checkouts").join(name).join("locked");
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("data"), vec![0u8; 100]).unwrap();
+            index.checkouts.insert(name.into(), last_used);
+        }
+        index.save(git).unwrap();
+
+        // The second checkout cannot be removed
+        let locked = git.join("checkouts/a-repo-2222222/locked");
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o555)).unwrap();
+        if fs::write(locked.join("probe"), "").is_ok() {
+            // Permissions do not apply, e.g. when running as root
+            return;
+        }
+
+        let result = evict(git, 0, &[], false);
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o755)).unwrap();
+        assert!(result.is_err());
+        assert!(!git.join("checkouts/a-repo-1111111").exists());
+
+        let index = UsageIndex::load(git);
+        assert_eq!(index.last_used(EntryKind::Checkout, "a-repo-1111111"), None);
+        assert_eq!(
+            index.last_used(EntryKind::Checkout, "a-repo-2222222"),
+            Some(200)
+        );
+    }
 }
@@ -62,6 +62,34 @@ pub enum Command {
     /// Alias for lint --strict
     Validate(LintArgs),
 
+    /// Watch skills and re-check them on change
+    ///
+    /// Runs lint and format checks whenever SKILL.md, scripts or
+    /// references change, redrawing a compact report.
+    #[command(verbatim_doc_comment)]
+    Watch(WatchArgs),
+
+    /// Start the language server for SKILL.md files
+    ///
+    /// Speaks the Language Server Protocol over stdio, providing
+    /// diagnostics, quick fixes, formatting, completion and hover.
+    #[command(verbatim_doc_comment)]
+    Lsp,
+
+    /// Serve installed skills over the Model Context Protocol
+    ///
+    /// Runs an MCP server over stdio that exposes each skill's
+    /// metadata, SKILL.md and bundled files as resources.
+    #[command(verbatim_doc_comment)]
+    Mcp(McpArgs),
+
+    /// Explain a lint rule
+    ///
+    /// Prints the documentation for a rule, looked up by code (E009)
+    /// or name (references-exist). Without a rule, lists all rules.
+    #[command(verbatim_doc_comment)]
+    Explain(ExplainArgs),
+
     /// Read skill properties as JSON
     ///
     /// Outputs skill metadata including name, description, license,
@@ -69,14 +97,45 @@ pub enum Command {
     #[command(verbatim_doc_comment)]
     ReadProperties(ReadPropertiesArgs),
 
-    /// Generate XML prompt for available skills
+    /// Generate a prompt listing of available skills
     ///
-    /// Outputs an <available_skills> XML block suitable for use in
-    /// agent system prompts, containing skill names, descriptions,
-    /// and file locations.
+    /// Outputs an <available_skills> XML block (or JSON, YAML, Markdown,
+    /// plain text or a custom template) suitable for use in agent system
+    /// prompts, containing skill names, descriptions, and file locations.
     #[command(verbatim_doc_comment)]
     ToPrompt(ToPromptArgs),
 
+    /// Export skills as agent-native rule files
+    ///
+    /// Converts skills into Cursor rules (.mdc), Copilot instructions,
+    /// Windsurf rules and GEMINI.md sections. Re-running updates the
+    /// generated files in place.
+    #[command(verbatim_doc_comment)]
+    Export(ExportArgs),
+
+    /// Convert Cursor rules, Claude commands and Copilot prompts into skills
+    ///
+    /// Detects .cursor/rules/*.mdc, .claude/commands/*.md and
+    /// .github/prompts/*.prompt.md files, writes a skill for each one and
+    /// validates the result.
+    #[command(verbatim_doc_comment)]
+    Import(ImportArgs),
+
+    /// Update the skills index in AGENTS.md, CLAUDE.md or GEMINI.md
+    ///
+    /// Rewrites the block between <!-- skilo:begin --> and
+    /// <!-- skilo:end --> with the skills installed in the project.
+    #[command(verbatim_doc_comment)]
+    SyncIndex(SyncIndexArgs),
+
+    /// Mirror skills across agents
+    ///
+    /// Makes the skills directory of every detected agent match
+    /// ./skills/ (or one agent's directory with --from): missing skills
+    /// are added, changed ones updated and others deleted.
+    #[command(verbatim_doc_comment)]
+    Sync(SyncArgs),
+
     /// List installed skills
     ///
     /// Shows skills installed at project or global level.
@@ -103,6 +162,15 @@ pub enum Command {
     #[command(verbatim_doc_comment)]
     Cache(CacheArgs),
 
+    /// Inspect and edit configuration
+    ///
+    /// Configuration is merged from ~/.skilo/config.toml, then the config
+    /// files from the git root down to the current directory.
+    /// `set` and `unset` edit the nearest project config file, or the user
+    /// config with --user.
+    #[command(verbatim_doc_comment)]
+    Config(ConfigArgs),
+
     /// Manage the skilo installation
     #[command(name = "self")]
     SelfCmd(SelfArgs),
@@ -138,7 +206,7 @@ pub struct AddArgs {
     ///
     /// Can be specified multiple times: --agent claude --agent cursor
     /// Use 'all' to install to all detected agents.
-    #[arg(long, short, value_enum)]
+    #[arg(long, short)]
     pub agent: Option<Vec<Agent>>,
 
     /// Install to global skills directory (~/.claude/skills/)
@@ -159,80 +227,122 @@ pub enum AgentSelection {
     Single(crate::agent::Agent),
 }
 
-/// Supported AI coding agents (CLI enum).
-#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+/// An `--agent` value: `all`, or the name of a built-in or configured agent.
+///
+/// Agents from `[[agents]]` config entries are only known once the config is
+/// loaded, so names are resolved by [`Agent::to_selection`] rather than while
+/// parsing arguments.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Agent {
     /// All detected agents
     All,
-    /// OpenCode
-    OpenCode,
-    /// Claude Code
-    Claude,
-    /// Codex
-    Codex,
-    /// Cursor
-    Cursor,
-    /// Amp
-    Amp,
-    /// Kilo Code
-    KiloCode,
-    /// Roo Code
-    RooCode,
-    /// Goose
-    Goose,
-    /// Gemini CLI
-    Gemini,
-    /// Antigravity
-    Antigravity,
-    /// GitHub Copilot
-    Copilot,
-    /// Clawdbot
-    Clawdbot,
-    /// Droid
-    Droid,
-    /// Windsurf
-    Windsurf,
+    /// A single agent, by name
+    Named(String),
 }
 
 impl Agent {
-    /// Convert to an agent selection.
-    pub fn to_selection(&self) -> AgentSelection {
+    /// Convert to an agent selection, resolving the agent name.
+    pub fn to_selection(&self) -> Result<AgentSelection, crate::error::SkiloError> {
         match self {
-            Agent::All => AgentSelection::All,
-            Agent::OpenCode => AgentSelection::Single(crate::agent::Agent::OpenCode),
-            Agent::Claude => AgentSelection::Single(crate::agent::Agent::Claude),
-            Agent::Codex => AgentSelection::Single(crate::agent::Agent::Codex),
-            Agent::Cursor => AgentSelection::Single(crate::agent::Agent::Cursor),
-            Agent::Amp => AgentSelection::Single(crate::agent::Agent::Amp),
-            Agent::KiloCode => AgentSelection::Single(crate::agent::Agent::KiloCode),
-            Agent::RooCode => AgentSelection::Single(crate::agent::Agent::RooCode),
-            Agent::Goose => AgentSelection::Single(crate::agent::Agent::Goose),
-            Agent::Gemini => AgentSelection::Single(crate::agent::Agent::Gemini),
-            Agent::Antigravity => AgentSelection::Single(crate::agent::Agent::Antigravity),
-            Agent::Copilot => AgentSelection::Single(crate::agent::Agent::Copilot),
-            Agent::Clawdbot => AgentSelection::Single(crate::agent::Agent::Clawdbot),
-            Agent::Droid => AgentSelection::Single(crate::agent::Agent::Droid),
-            Agent::Windsurf => AgentSelection::Single(crate::agent::Agent::Windsurf),
+            Agent::All => Ok(AgentSelection::All),
+            Agent::Named(name) => crate::agent::Agent::from_name(name)
+                .map(AgentSelection::Single)
+                .ok_or_else(|| {
+                    crate::error::SkiloError::UnknownAgent(
+                        name.clone(),
+                        crate::agent::Agent::all()
+                            .iter()
+                            .map(|a| a.config_name())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    )
+                }),
+        }
+    }
+
+    /// Resolve a list of agent names, where `all` stands for every agent.
+    pub fn resolve_list(
+        values: &[Agent],
+    ) -> Result<Vec<crate::agent::Agent>, crate::error::SkiloError> {
+        let mut agents = Vec::new();
+        for value in values {
+            match value.to_selection()? {
+                AgentSelection::All => return Ok(crate::agent::Agent::all()),
+                AgentSelection::Single(agent) if !agents.contains(&agent) => agents.push(agent),
+                AgentSelection::Single(_) => {}
+            }
         }
+        Ok(agents)
     }
 }
 
+/// Parses `--agent` values, listing the built-in agents in help and completions.
+#[derive(Clone)]
+pub struct AgentValueParser;
+
+impl clap::builder::TypedValueParser for AgentValueParser {
+    type Value = Agent;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &std::ffi::OsStr,
+    ) -> Result<Agent, clap::Error> {
+        let name = clap::builder::NonEmptyStringValueParser::new().parse_ref(cmd, arg, value)?;
+        Ok(if name == "all" {
+            Agent::All
+        } else {
+            Agent::Named(name)
+        })
+    }
+
+    fn possible_values(
+        &self,
+    ) -> Option<Box<dyn Iterator<Item = clap::builder::PossibleValue> + '_>> {
+        let all = clap::builder::PossibleValue::new("all").help("All detected agents");
+        let builtin = crate::agent::Agent::builtin().iter().map(|agent| {
+            clap::builder::PossibleValue::new(agent.config_name()).help(agent.display_name())
+        });
+        Some(Box::new(std::iter::once(all).chain(builtin)))
+    }
+}
+
+impl clap::builder::ValueParserFactory for Agent {
+    type Parser = AgentValueParser;
+
+    fn value_parser() -> Self::Parser {
+        AgentValueParser
+    }
+}
+
+/// Parse a `KEY=VALUE` argument.
+fn parse_key_value(s: &str) -> Result<(String, String), String> {
+    s.split_once('=')
+        .map(|(k, v)| (k.trim().to_string(), v.to_string()))
+        .ok_or_else(|| format!("expected KEY=VALUE, got '{}'", s))
+}
+
 /// Arguments for the `new` command.
 #[derive(clap::Args, Clone)]
 pub struct NewArgs {
-    /// Name of the skill to create
-    pub name: String,
+    /// Name of the skill to create (asked for interactively when omitted)
+    pub name: Option<String>,
+
+    /// Template to use: hello-world, minimal, full, script-based, a template directory, or owner/repo#dir [default: hello-world]
+    #[arg(long, short, env = "SKILO_TEMPLATE", value_name = "TEMPLATE")]
+    pub template: Option<String>,
 
-    /// Template to use
-    #[arg(long, short, default_value = "hello-world", value_enum)]
-    pub template: Template,
+    /// Value for a variable declared in the template's template.toml
+    #[arg(long = "var", value_name = "KEY=VALUE", value_parser = parse_key_value)]
+    pub vars: Vec<(String, String)>,
 
-    /// Preferred script language
-    #[arg(long, default_value = "python", value_enum)]
-    pub lang: ScriptLang,
+    /// Preferred script language [default: python]
+    #[arg(long, env = "SKILO_LANG", value_enum)]
+    pub lang: Option<ScriptLang>,
 
     /// License for the skill (SPDX identifier)
-    #[arg(long)]
+    #[arg(long, env = "SKILO_LICENSE")]
     pub license: Option<String>,
 
     /// Skill description
@@ -248,7 +358,7 @@ pub struct NewArgs {
     pub no_scripts: bool,
 
     /// Target agent (determines output directory)
-    #[arg(long, short, value_enum)]
+    #[arg(long, short)]
     pub agent: Option<Agent>,
 
     /// Create skill in global skills directory
@@ -274,6 +384,16 @@ pub struct LintArgs {
     /// Auto-fix simple issues
     #[arg(long)]
     pub fix: bool,
+
+    /// Validate every skill, ignoring cached results in ~/.skilo/lint
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Check skills against these agents' features (comma-separated, or 'all')
+    ///
+    /// Overrides `lint.target_agents` from config.
+    #[arg(long, value_delimiter = ',', value_name = "AGENTS")]
+    pub target_agents: Vec<Agent>,
 }
 
 /// Arguments for the `fmt` command.
@@ -287,7 +407,9 @@ pub struct FmtArgs {
     #[arg(long)]
     pub check: bool,
 
-    /// Show diff of changes
+    /// Show a unified diff of changes without modifying
+    ///
+    /// Combine with --check to also exit non-zero when files differ.
     #[arg(long)]
     pub diff: bool,
 }
@@ -298,6 +420,61 @@ pub struct CheckArgs {
     /// Path to skill or directory containing skills
     #[arg(default_value = ".")]
     pub path: PathBuf,
+
+    /// Validate every skill, ignoring cached lint results
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Check skills against these agents' features (comma-separated, or 'all')
+    #[arg(long, value_delimiter = ',', value_name = "AGENTS")]
+    pub target_agents: Vec<Agent>,
+}
+
+/// Arguments for the `watch` command.
+#[derive(clap::Args, Clone)]
+pub struct WatchArgs {
+    /// Path to skill or directory containing skills
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// Treat warnings as errors
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Milliseconds to wait for further changes before re-checking
+    #[arg(long, default_value_t = 200)]
+    pub debounce: u64,
+}
+
+/// Arguments for the `mcp` command.
+#[derive(clap::Args, Clone)]
+pub struct McpArgs {
+    /// Project directory to serve installed skills from
+    #[arg(long, default_value = ".")]
+    pub project: PathBuf,
+
+    /// Only serve skills installed for this agent
+    #[arg(long, short)]
+    pub agent: Option<Agent>,
+
+    /// Additional directories to discover skills in
+    #[arg(long = "path", value_name = "DIR")]
+    pub paths: Vec<PathBuf>,
+
+    /// Also expose each skill as a prompt
+    #[arg(long)]
+    pub prompts: bool,
+}
+
+/// Arguments for the `explain` command.
+#[derive(clap::Args, Clone)]
+pub struct ExplainArgs {
+    /// Rule code or name (e.g., E009 or references-exist)
+    pub rule: Option<String>,
+
+    /// Print the full documentation for every rule
+    #[arg(long, conflicts_with = "rule")]
+    pub all: bool,
 }
 
 /// Arguments for the `read-properties` command.
@@ -314,6 +491,211 @@ pub struct ToPromptArgs {
     /// Paths to skills or directories containing skills
     #[arg(default_value = ".")]
     pub paths: Vec<PathBuf>,
+
+    /// Shape of the generated skill listing
+    #[arg(long, value_enum, default_value_t = PromptFormat::Xml)]
+    pub prompt_format: PromptFormat,
+
+    /// Render skills through a template file instead of a built-in format
+    #[arg(long, value_name = "FILE", conflicts_with = "prompt_format")]
+    pub template: Option<PathBuf>,
+
+    /// Trim the listing to an estimated token budget
+    #[arg(long, env = "SKILO_MAX_TOKENS", value_name = "N")]
+    pub max_tokens: Option<usize>,
+
+    /// Print estimated token counts to stderr
+    #[arg(long)]
+    pub report: bool,
+
+    /// Embed each skill's instructions (the SKILL.md body)
+    #[arg(long)]
+    pub inline: bool,
+
+    /// With --inline, also embed files referenced under references/
+    #[arg(long, requires = "inline")]
+    pub inline_references: bool,
+
+    /// Truncate each embedded body or file to this many bytes
+    #[arg(long, value_name = "BYTES", default_value_t = crate::prompt::inline::DEFAULT_MAX_BYTES)]
+    pub max_inline_bytes: usize,
+
+    /// How to write skill locations [default: as discovered, or relative with --base-dir]
+    #[arg(long, value_enum)]
+    pub path_style: Option<PathStyle>,
+
+    /// Directory that relative and agent locations are resolved against
+    #[arg(long, value_name = "DIR")]
+    pub base_dir: Option<PathBuf>,
+
+    /// Agent whose skills directory is used with --path-style agent
+    #[arg(long, short, required_if_eq("path_style", "agent"))]
+    pub agent: Option<Agent>,
+
+    /// Use the agent's global skills directory with --path-style agent
+    #[arg(long, short = 'g')]
+    pub global: bool,
+}
+
+/// Location styles for `to-prompt` output.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathStyle {
+    /// Canonical absolute paths.
+    Absolute,
+    /// Paths relative to --base-dir (default: the current directory).
+    Relative,
+    /// Paths where the target agent installs the skill.
+    Agent,
+}
+
+/// Arguments for the `export` command.
+#[derive(clap::Args, Clone)]
+pub struct ExportArgs {
+    /// Paths to skills or directories containing skills
+    #[arg(default_value = ".")]
+    pub paths: Vec<PathBuf>,
+
+    /// Rule formats to generate
+    #[arg(long, short, value_enum, value_delimiter = ',', required = true)]
+    pub to: Vec<ExportTarget>,
+
+    /// Project directory to write rule files into
+    #[arg(long, short, default_value = ".")]
+    pub output_dir: PathBuf,
+
+    /// Overwrite files that were not generated by skilo
+    #[arg(long)]
+    pub force: bool,
+
+    /// Show what would be written without changing any files
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// Arguments for the `import` command.
+#[derive(clap::Args, Clone)]
+pub struct ImportArgs {
+    /// Files or directories to import from
+    #[arg(default_value = ".")]
+    pub paths: Vec<PathBuf>,
+
+    /// Target agent (determines output directory)
+    #[arg(long, short)]
+    pub agent: Option<Agent>,
+
+    /// Import into the global skills directory
+    #[arg(long, short = 'g')]
+    pub global: bool,
+
+    /// Output directory (defaults to agent skills directory)
+    #[arg(long, short, conflicts_with_all = ["agent", "global"])]
+    pub output: Option<PathBuf>,
+
+    /// Replace skills that already exist
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Arguments for the `sync` command.
+#[derive(clap::Args, Clone)]
+pub struct SyncArgs {
+    /// Project directory
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// Agent whose skills directory is the source of truth [default: ./skills/]
+    #[arg(long)]
+    pub from: Option<Agent>,
+
+    /// Agents to update (comma-separated, or 'all') [default: detected agents]
+    #[arg(long, short, value_delimiter = ',', value_name = "AGENTS")]
+    pub agent: Vec<Agent>,
+
+    /// Copy skills, or link them to the source [default: copy]
+    #[arg(long, value_enum)]
+    pub mode: Option<SyncMode>,
+
+    /// Fail if an agent is out of sync instead of updating it
+    #[arg(long, conflicts_with = "dry_run")]
+    pub check: bool,
+
+    /// Show what would change without changing any files
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Delete skills missing from the source without asking
+    #[arg(long, short)]
+    pub yes: bool,
+}
+
+/// Arguments for the `sync-index` command.
+#[derive(clap::Args, Clone)]
+pub struct SyncIndexArgs {
+    /// Project directory
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// Instruction files to update, relative to the project [default: existing AGENTS.md, CLAUDE.md and GEMINI.md, or AGENTS.md]
+    #[arg(long = "file", value_name = "FILE")]
+    pub files: Vec<PathBuf>,
+
+    /// Only list skills installed for this agent
+    #[arg(long, short)]
+    pub agent: Option<Agent>,
+
+    /// Fail if an index is out of date instead of updating it
+    #[arg(long, conflicts_with = "dry_run")]
+    pub check: bool,
+
+    /// Show what would be written without changing any files
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Save --agent and --file to [index] in the project config
+    #[arg(long, conflicts_with_all = ["check", "dry_run"])]
+    pub save: bool,
+}
+
+/// Agent-native rule formats for `export`.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportTarget {
+    /// Cursor rules in `.cursor/rules/<name>.mdc`.
+    Cursor,
+    /// GitHub Copilot instructions in `.github/instructions/<name>.instructions.md`.
+    Copilot,
+    /// Windsurf rules in `.windsurf/rules/<name>.md`.
+    Windsurf,
+    /// A section per skill in `GEMINI.md`.
+    Gemini,
+}
+
+impl std::fmt::Display for ExportTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ExportTarget::Cursor => "cursor",
+            ExportTarget::Copilot => "copilot",
+            ExportTarget::Windsurf => "windsurf",
+            ExportTarget::Gemini => "gemini",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Built-in shapes for `to-prompt` output.
+#[derive(ValueEnum, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum PromptFormat {
+    /// `<available_skills>` XML block.
+    #[default]
+    Xml,
+    /// JSON array of skill objects.
+    Json,
+    /// YAML list of skill objects.
+    Yaml,
+    /// Markdown bullet list.
+    Markdown,
+    /// Plain text, one skill per entry.
+    Plain,
 }
 
 /// Output format for command results.
@@ -326,6 +708,19 @@ pub enum OutputFormat {
     Json,
     /// SARIF output for code scanning integrations.
     Sarif,
+    /// JUnit XML report, one test case per skill.
+    Junit,
+    /// Checkstyle XML report.
+    Checkstyle,
+    /// GitHub Actions workflow commands (inline annotations).
+    Github,
+}
+
+impl OutputFormat {
+    /// Returns true for machine-readable formats (anything but text).
+    pub fn is_structured(&self) -> bool {
+        !matches!(self, Self::Text)
+    }
 }
 
 /// Available skill templates.
@@ -343,18 +738,57 @@ pub enum Template {
     ScriptBased,
 }
 
+/// How `sync` mirrors skills into agent directories.
+#[derive(
+    ValueEnum, Clone, Copy, Default, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncMode {
+    /// Copy each skill directory.
+    #[default]
+    Copy,
+    /// Link each skill directory to the source with a relative symlink.
+    Symlink,
+}
+
 /// Supported script languages.
-#[derive(ValueEnum, Clone, Copy, Default, Debug)]
+#[derive(
+    ValueEnum,
+    Clone,
+    Copy,
+    Default,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+#[serde(rename_all = "lowercase")]
 pub enum ScriptLang {
     /// Python scripts.
     #[default]
     Python,
+    /// Python scripts run with `uv run --script`, with inline dependencies.
+    Uv,
     /// Bash scripts.
     Bash,
     /// JavaScript scripts.
     Javascript,
     /// TypeScript scripts.
     Typescript,
+    /// TypeScript scripts run with Deno.
+    Deno,
+    /// Go programs run with `go run`.
+    Go,
+    /// Rust programs run with `rust-script`.
+    Rust,
+    /// Ruby scripts.
+    Ruby,
+    /// PowerShell scripts.
+    #[value(alias = "pwsh")]
+    #[serde(alias = "pwsh")]
+    Powershell,
 }
 
 /// Arguments for the `list` command.
@@ -373,7 +807,7 @@ pub struct ListArgs {
     pub all: bool,
 
     /// Target agent
-    #[arg(long, short, value_enum)]
+    #[arg(long, short)]
     pub agent: Option<Agent>,
 }
 
@@ -389,7 +823,7 @@ pub struct RemoveArgs {
     pub global: bool,
 
     /// Target agent
-    #[arg(long, short, value_enum)]
+    #[arg(long, short)]
     pub agent: Option<Agent>,
 
     /// Skip confirmation prompts
@@ -429,6 +863,86 @@ pub enum CacheCommand {
         #[arg(long, default_value = "30")]
         max_age: u32,
     },
+
+    /// Compact repositories and evict least recently used entries
+    ///
+    /// Runs `git gc` on every cached repository, then removes the least
+    /// recently used checkouts and repositories until the cache fits in
+    /// the size budget.
+    #[command(verbatim_doc_comment)]
+    Gc {
+        /// Size budget, e.g. 500MB or 2GB [default: cache.max_size from config]
+        #[arg(long, value_name = "SIZE", value_parser = parse_size_arg)]
+        max_size: Option<u64>,
+
+        /// Show what would be evicted without removing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+fn parse_size_arg(s: &str) -> Result<u64, String> {
+    crate::cache::parse_size(s).ok_or_else(|| format!("expected a size like 500MB, got '{}'", s))
+}
+
+/// Arguments for the `config` command.
+#[derive(clap::Args, Clone)]
+pub struct ConfigArgs {
+    /// Config subcommand
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+/// Config subcommands.
+#[derive(Subcommand, Clone)]
+pub enum ConfigCommand {
+    /// Show the effective configuration
+    Show {
+        /// Show the file each value comes from
+        #[arg(long)]
+        origin: bool,
+    },
+
+    /// List values set in config files, with the file each comes from
+    List,
+
+    /// Print the effective value of a key
+    Get {
+        /// Dotted key (e.g. lint.rules.body_length)
+        key: String,
+    },
+
+    /// Set a key in a config file
+    Set {
+        /// Dotted key (e.g. lint.rules.body_length)
+        key: String,
+
+        /// Value, as a TOML literal or a plain string
+        value: String,
+
+        /// Edit the user config (~/.skilo/config.toml)
+        #[arg(long)]
+        user: bool,
+    },
+
+    /// Remove a key from a config file
+    Unset {
+        /// Dotted key (e.g. lint.rules.body_length)
+        key: String,
+
+        /// Edit the user config (~/.skilo/config.toml)
+        #[arg(long)]
+        user: bool,
+    },
+
+    /// Check config files for unknown keys and invalid values
+    Validate {
+        /// Config file to check (default: every file in use)
+        file: Option<PathBuf>,
+    },
+
+    /// Print the JSON Schema for config files
+    Schema,
 }
 
 /// Arguments for the `self` command.
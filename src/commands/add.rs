@@ -1,6 +1,7 @@
 //! The `add` command implementation for installing skills from git repositories.
 
 use crate::agent::{expand_tilde, Agent};
+use crate::cache::{evict, git_dir};
 use crate::cli::{AddArgs, Cli};
 use crate::config::Config;
 use crate::git::{fetch, Source};
@@ -8,7 +9,9 @@ use crate::output::get_formatter;
 use crate::scope::Scope;
 use crate::skill::discovery::Discovery;
 use crate::skill::manifest::Manifest;
+use crate::skill::rules::{AgentCompatibilityRule, Rule};
 use crate::skill::validator::Validator;
+use crate::sync::copy_dir;
 use crate::SkiloError;
 use colored::Colorize;
 use dialoguer::Confirm;
@@ -61,7 +64,7 @@ fn resolve_targets(args: &AddArgs, config: &Config) -> Result<Vec<InstallTarget>
     let agents: Option<Vec<Agent>> = if let Some(ref cli_agents) = args.agent {
         let mut resolved = Vec::new();
         for cli_agent in cli_agents {
-            match cli_agent.to_selection() {
+            match cli_agent.to_selection()? {
                 crate::cli::AgentSelection::All => {
                     // "all" means all detected agents
                     let detected = if args.global {
@@ -148,6 +151,7 @@ pub fn run(args: AddArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError>
     let source = Source::parse_with_options(&args.source, args.branch.clone(), args.tag.clone())?;
 
     // Extract source path based on source type
+    let mut checkout = None;
     let (source_path, source_name, _temp_dir) = match source {
         Source::Git(git_source) => {
             let display_name = git_source.display_name();
@@ -172,6 +176,11 @@ pub fn run(args: AddArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError>
                 }
             }
 
+            checkout = fetch_result
+                .checkout_dir
+                .as_deref()
+                .and_then(|dir| dir.file_name())
+                .map(|name| name.to_string_lossy().to_string());
             (
                 fetch_result.root.clone(),
                 display_name,
@@ -292,7 +301,7 @@ pub fn run(args: AddArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError>
         // Check for feature compatibility warnings
         if !cli.quiet {
             if let Some(agent) = target.agent {
-                check_feature_warnings(&skills, agent, &source_path);
+                check_feature_warnings(&skills, agent);
             }
         }
 
@@ -309,6 +318,18 @@ pub fn run(args: AddArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError>
         }
     }
 
+    // The skills have been copied, so the cache can shrink to its budget,
+    // keeping the checkout they came from
+    if let (Some(max_size), Some(git)) = (config.cache.max_size, git_dir()) {
+        let protected: Vec<String> = checkout.into_iter().collect();
+        if let Err(e) = evict(&git, max_size, &protected, false) {
+            formatter.format_message(&format!(
+                "warning: could not shrink the git cache to cache.max_size: {}",
+                e
+            ));
+        }
+    }
+
     if !cli.quiet && targets.len() > 1 {
         println!();
         formatter.format_success(&format!(
@@ -327,36 +348,25 @@ pub fn run(args: AddArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError>
     }
 }
 
-/// Check for feature compatibility warnings.
-fn check_feature_warnings(skills: &[SkillInfo], agent: Agent, _source_path: &Path) {
-    let features = agent.features();
+/// Warn about skill features the agent does not support.
+fn check_feature_warnings(skills: &[SkillInfo], agent: Agent) {
+    let rule = AgentCompatibilityRule::new(vec![agent]);
 
     for skill in skills {
         if !skill.valid {
             continue;
         }
 
-        // Try to read skill manifest to check for feature usage
-        let skill_md = skill.source_path.join("SKILL.md");
-        if let Ok(content) = std::fs::read_to_string(&skill_md) {
-            // Check for context: fork usage
-            if content.contains("context: fork") && !features.context_fork {
-                eprintln!(
-                    "{}: Skill '{}' uses 'context: fork' which is only supported by Claude Code",
-                    "Warning".yellow(),
-                    skill.name.cyan()
-                );
-            }
-
-            // Check for hooks usage
-            if content.contains("hooks:") && !features.hooks {
-                eprintln!(
-                    "{}: Skill '{}' uses hooks which may not be supported by {}",
-                    "Warning".yellow(),
-                    skill.name.cyan(),
-                    agent.display_name()
-                );
-            }
+        let Ok(manifest) = Manifest::parse(skill.source_path.join("SKILL.md")) else {
+            continue;
+        };
+        for diagnostic in rule.check(&manifest) {
+            eprintln!(
+                "{}: Skill '{}': {}",
+                "Warning".yellow(),
+                skill.name.cyan(),
+                diagnostic.message
+            );
         }
     }
 }
@@ -481,10 +491,14 @@ fn truncate_description(s: &str, max_len: usize) -> String {
     // Take first sentence or truncate
     let first_sentence = s.split(". ").next().unwrap_or(s);
 
-    if first_sentence.len() <= max_len {
+    if first_sentence.chars().count() <= max_len {
         first_sentence.to_string()
     } else {
-        format!("{}...", &first_sentence[..max_len.saturating_sub(3)])
+        let truncated: String = first_sentence
+            .chars()
+            .take(max_len.saturating_sub(3))
+            .collect();
+        format!("{}...", truncated)
     }
 }
 
@@ -541,7 +555,7 @@ fn install_skills(
         }
 
         // Copy the skill directory
-        copy_dir_all(&skill.source_path, &dest)?;
+        copy_dir(&skill.source_path, &dest)?;
 
         if !quiet {
             println!(" {}", "done".green());
@@ -553,26 +567,6 @@ fn install_skills(
     Ok(installed)
 }
 
-/// Recursively copy a directory.
-fn copy_dir_all(src: &Path, dst: &Path) -> Result<(), SkiloError> {
-    fs::create_dir_all(dst)?;
-
-    for entry in fs::read_dir(src)? {
-        let entry = entry?;
-        let ty = entry.file_type()?;
-        let src_path = entry.path();
-        let dst_path = dst.join(entry.file_name());
-
-        if ty.is_dir() {
-            copy_dir_all(&src_path, &dst_path)?;
-        } else {
-            fs::copy(&src_path, &dst_path)?;
-        }
-    }
-
-    Ok(())
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,17 +1,22 @@
 //! Cache management commands.
 
-use crate::cache::{clean_all, clean_old_checkouts, format_size, git_dir, CacheStats};
+use crate::cache::{
+    clean_all, clean_old_checkouts, compact_repos, evict, format_size, git_dir, CacheStats,
+    CleanReport, EntryKind,
+};
 use crate::cli::{CacheArgs, CacheCommand, Cli};
 use crate::config::Config;
 use crate::error::SkiloError;
-use colored::Colorize;
-use std::time::SystemTime;
+use crate::output::get_formatter;
 
 /// Run the cache command.
-pub fn run(args: CacheArgs, _config: &Config, cli: &Cli) -> Result<i32, SkiloError> {
+pub fn run(args: CacheArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError> {
     match args.command {
         Some(CacheCommand::Path) => show_path(cli),
         Some(CacheCommand::Clean { all, max_age }) => clean(all, max_age, cli),
+        Some(CacheCommand::Gc { max_size, dry_run }) => {
+            gc(max_size.or(config.cache.max_size), dry_run, cli)
+        }
         None => show_status(cli),
     }
 }
@@ -31,119 +36,120 @@ fn show_status(cli: &Cli) -> Result<i32, SkiloError> {
     let git = git_dir()
         .ok_or_else(|| SkiloError::Config("Could not determine cache directory".to_string()))?;
 
-    if !git.exists() {
+    if !git.exists() && !cli.format.is_structured() {
         if !cli.quiet {
             println!("Cache directory: {} (not created yet)", git.display());
         }
         return Ok(0);
     }
 
+    let formatter = get_formatter(cli.format, cli.quiet);
     let stats = CacheStats::collect();
 
-    println!("Cache directory: {}", git.display().to_string().cyan());
-    println!();
-
-    // Show db stats
-    println!(
-        "  {}: {} repositories, {}",
-        "db/".bold(),
-        stats.repos.len(),
-        format_size(stats.db_size)
-    );
-    for repo in &stats.repos {
-        println!("    {}", repo.name);
-    }
-
-    if !stats.repos.is_empty() && !stats.checkouts.is_empty() {
-        println!();
-    }
-
-    // Show checkout stats
-    println!(
-        "  {}: {} checkouts, {}",
-        "checkouts/".bold(),
-        stats.checkouts.len(),
-        format_size(stats.checkouts_size)
-    );
-    for checkout in &stats.checkouts {
-        let age = format_age(checkout.modified);
-        println!("    {} {}", checkout.name, age.dimmed());
-    }
-
-    if !stats.checkouts.is_empty() || !stats.repos.is_empty() {
-        println!();
-        println!("Total: {}", format_size(stats.total_size()).cyan());
-    }
+    println!("{}", formatter.format_cache_stats(&stats, &git).trim_end());
 
     Ok(0)
 }
 
-/// Format age as a human-readable string.
-fn format_age(modified: Option<SystemTime>) -> String {
-    let Some(modified) = modified else {
-        return String::new();
-    };
+/// Clean cache.
+fn clean(all: bool, max_age: u32, cli: &Cli) -> Result<i32, SkiloError> {
+    let formatter = get_formatter(cli.format, cli.quiet);
+    let structured = cli.format.is_structured();
 
-    let Ok(age) = SystemTime::now().duration_since(modified) else {
-        return String::new();
+    let report = if all {
+        if !structured {
+            formatter.format_message("Removing all cached data...");
+        }
+        let (repos_removed, checkouts_removed, freed) = clean_all().map_err(SkiloError::Io)?;
+        CleanReport {
+            max_age_days: None,
+            repos_removed,
+            checkouts_removed,
+            freed,
+        }
+    } else {
+        if !structured {
+            formatter.format_message(&format!(
+                "Removing checkouts older than {} days...",
+                max_age
+            ));
+        }
+        let (checkouts_removed, freed) = clean_old_checkouts(max_age).map_err(SkiloError::Io)?;
+        CleanReport {
+            max_age_days: Some(max_age),
+            checkouts_removed,
+            freed,
+            ..Default::default()
+        }
     };
 
-    let secs = age.as_secs();
-    let mins = secs / 60;
-    let hours = mins / 60;
-    let days = hours / 24;
-    let weeks = days / 7;
-
-    if weeks > 0 {
-        format!("({} week{} ago)", weeks, if weeks == 1 { "" } else { "s" })
-    } else if days > 0 {
-        format!("({} day{} ago)", days, if days == 1 { "" } else { "s" })
-    } else if hours > 0 {
-        format!("({} hour{} ago)", hours, if hours == 1 { "" } else { "s" })
-    } else if mins > 0 {
-        format!("({} minute{} ago)", mins, if mins == 1 { "" } else { "s" })
-    } else {
-        "(just now)".to_string()
+    if structured || !cli.quiet {
+        println!("{}", formatter.format_cache_clean(&report).trim_end());
     }
-}
 
-/// Clean cache.
-fn clean(all: bool, max_age: u32, cli: &Cli) -> Result<i32, SkiloError> {
-    if all {
-        if !cli.quiet {
-            println!("Removing all cached data...");
-        }
+    Ok(0)
+}
 
-        let (repos, checkouts, freed) = clean_all().map_err(SkiloError::Io)?;
+/// Compact repositories and evict entries until the cache fits in `max_size`.
+fn gc(max_size: Option<u64>, dry_run: bool, cli: &Cli) -> Result<i32, SkiloError> {
+    let formatter = get_formatter(cli.format, cli.quiet);
+    let git = git_dir()
+        .ok_or_else(|| SkiloError::Config("Could not determine cache directory".to_string()))?;
 
-        if !cli.quiet {
-            println!(
-                "Removed {} repositories, {} checkouts ({} freed)",
-                repos,
-                checkouts,
-                format_size(freed).green()
-            );
-        }
-    } else {
-        if !cli.quiet {
-            println!("Removing checkouts older than {} days...", max_age);
+    if !dry_run {
+        let compacted = compact_repos(&git);
+        if compacted > 0 {
+            formatter.format_message(&format!(
+                "Compacted {} repositor{}",
+                compacted,
+                if compacted == 1 { "y" } else { "ies" }
+            ));
         }
+    }
 
-        let (removed, freed) = clean_old_checkouts(max_age).map_err(SkiloError::Io)?;
+    let Some(max_size) = max_size else {
+        formatter.format_message("No size budget; set cache.max_size or pass --max-size");
+        return Ok(0);
+    };
 
-        if !cli.quiet {
-            if removed > 0 {
-                println!(
-                    "Removed {} checkout{} ({} freed)",
-                    removed,
-                    if removed == 1 { "" } else { "s" },
-                    format_size(freed).green()
-                );
-            } else {
-                println!("No checkouts older than {} days found", max_age);
-            }
-        }
+    // Without a lockfile no checkout is known to be in use by a project
+    let report = evict(&git, max_size, &[], dry_run).map_err(SkiloError::Io)?;
+    if report.evicted.is_empty() {
+        formatter.format_success(&format!(
+            "Cache is {} (budget {})",
+            format_size(report.size_before),
+            format_size(max_size)
+        ));
+        return Ok(0);
+    }
+
+    let verb = if dry_run { "Would evict" } else { "Evicted" };
+    for entry in &report.evicted {
+        let kind = match entry.kind {
+            EntryKind::Repo => "repository",
+            EntryKind::Checkout => "checkout",
+        };
+        formatter.format_message(&format!(
+            "{} {} {} ({})",
+            verb,
+            kind,
+            entry.name,
+            format_size(entry.size)
+        ));
     }
+    formatter.format_success(&format!(
+        "{} {} entr{} ({} freed, {} of {} budget used)",
+        verb,
+        report.evicted.len(),
+        if report.evicted.len() == 1 {
+            "y"
+        } else {
+            "ies"
+        },
+        format_size(report.size_before - report.size_after),
+        format_size(report.size_after),
+        format_size(max_size)
+    ));
 
     Ok(0)
 }
@@ -1,12 +1,18 @@
-//! Runs both lint and format check in sequence.
+//! Runs lint, format check and the skills index check in sequence.
 
+use crate::agent::Agent;
 use crate::cli::{CheckArgs, Cli, FmtArgs, LintArgs};
 use crate::config::Config;
 use crate::error::SkiloError;
+use crate::export::index;
 use crate::output::get_formatter;
 
 /// Run the check command, which executes lint and format check.
 ///
+/// When `path` is a project with a skills index block in `AGENTS.md`,
+/// `CLAUDE.md`, `GEMINI.md` or the `[index] files`, the block must also be up
+/// to date with the skills `sync-index` would list.
+///
 /// Returns 0 if all checks pass, 1 if any fail.
 pub fn run(args: CheckArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError> {
     let formatter = get_formatter(cli.format, cli.quiet);
@@ -18,6 +24,8 @@ pub fn run(args: CheckArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloErro
         path: args.path.clone(),
         strict: true,
         fix: false,
+        no_cache: args.no_cache,
+        target_agents: args.target_agents.clone(),
     };
     let lint_result = super::lint::run(lint_args, config, cli)?;
 
@@ -25,17 +33,97 @@ pub fn run(args: CheckArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloErro
 
     // Run format check
     let fmt_args = FmtArgs {
-        path: args.path,
+        path: args.path.clone(),
         check: true,
         diff: false,
     };
     let fmt_result = super::fmt::run(fmt_args, config, cli)?;
 
-    // Return non-zero if either failed
-    if lint_result != 0 || fmt_result != 0 {
+    // Check the skills index, if the project keeps one
+    let mut index_result = 0;
+    if args.path.is_dir() {
+        let agents = config
+            .index
+            .agent
+            .map_or_else(Agent::all, |agent| vec![agent]);
+        let skills = index::installed_skills(&args.path, &agents);
+        let content = index::render(&skills, &args.path);
+        let files = index::index_files(&config.index.files);
+        for file in index::stale_files(&args.path, &files, &content) {
+            formatter.format_error(&format!(
+                "{}: skills index is out of date; run `skilo sync-index`",
+                file.display()
+            ));
+            index_result = 1;
+        }
+    }
+
+    // Return non-zero if any check failed
+    if lint_result != 0 || fmt_result != 0 || index_result != 0 {
         Ok(1)
     } else {
         formatter.format_success("\nAll checks passed!");
         Ok(0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use tempfile::TempDir;
+
+    fn install(root: &std::path::Path, dir: &str, name: &str) {
+        let skill = root.join(dir).join(name);
+        std::fs::create_dir_all(&skill).unwrap();
+        std::fs::write(
+            skill.join("SKILL.md"),
+            format!(
+                "---\nname: {}\ndescription: The {} skill.\n---\n\n# {}\n",
+                name, name, name
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_check_accepts_index_written_for_one_agent() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().to_str().unwrap();
+        install(temp.path(), ".claude/skills", "pdf-tools");
+        install(temp.path(), ".codex/skills", "api-docs");
+
+        let cli = Cli::parse_from([
+            "skilo",
+            "--quiet",
+            "sync-index",
+            root,
+            "--agent",
+            "claude",
+            "--file",
+            "docs/AI.md",
+            "--save",
+        ]);
+        let crate::cli::Command::SyncIndex(args) = &cli.command else {
+            unreachable!()
+        };
+        let code =
+            crate::commands::sync_index::run(args.clone(), &Config::default(), &cli).unwrap();
+        assert_eq!(code, 0);
+
+        let config = Config::load(Some(&temp.path().join(".skilorc.toml"))).unwrap();
+        let cli = Cli::parse_from(["skilo", "--quiet", "check", root, "--no-cache"]);
+        let crate::cli::Command::Check(args) = &cli.command else {
+            unreachable!()
+        };
+        assert_eq!(run(args.clone(), &config, &cli).unwrap(), 0);
+
+        // An index missing the selected agent's skills is stale
+        std::fs::write(
+            temp.path().join("docs/AI.md"),
+            "<!-- skilo:begin -->\n<!-- skilo:end -->\n",
+        )
+        .unwrap();
+        assert_eq!(run(args.clone(), &config, &cli).unwrap(), 1);
+    }
+}
@@ -0,0 +1,135 @@
+//! Configuration inspection and editing commands.
+
+use crate::cli::{Cli, ConfigArgs, ConfigCommand};
+use crate::config::{self, schema, Config, CONFIG_FILES};
+use crate::error::SkiloError;
+use crate::output::get_formatter;
+use std::path::{Path, PathBuf};
+
+/// Run the config command.
+pub fn run(args: ConfigArgs, _config: &Config, cli: &Cli) -> Result<i32, SkiloError> {
+    match args.command {
+        ConfigCommand::Show { origin } => show(origin, false, cli),
+        ConfigCommand::List => show(true, true, cli),
+        ConfigCommand::Get { key } => get(&key, cli),
+        ConfigCommand::Set { key, value, user } => set(&key, &value, user, cli),
+        ConfigCommand::Unset { key, user } => unset(&key, user, cli),
+        ConfigCommand::Validate { file } => validate(file, cli),
+        ConfigCommand::Schema => {
+            print!("{}", schema::render());
+            Ok(0)
+        }
+    }
+}
+
+/// Print the effective configuration, or only the values set in files.
+fn show(origin: bool, set_only: bool, cli: &Cli) -> Result<i32, SkiloError> {
+    let formatter = get_formatter(cli.format, cli.quiet);
+    let mut entries = Config::entries(&Config::sources(cli.config.as_ref()))
+        .map_err(|e| SkiloError::Config(e.to_string()))?;
+    if set_only {
+        entries.retain(|entry| entry.origin.is_some());
+    }
+
+    println!("{}", formatter.format_config(&entries, origin).trim_end());
+
+    Ok(0)
+}
+
+/// Print the effective value of a key, or of every key in a section.
+fn get(key: &str, cli: &Cli) -> Result<i32, SkiloError> {
+    schema::check_key(key).map_err(|e| SkiloError::Config(e.to_string()))?;
+
+    let entries = Config::entries(&Config::sources(cli.config.as_ref()))
+        .map_err(|e| SkiloError::Config(e.to_string()))?;
+    let section = format!("{}.", key);
+    let mut found = false;
+    for entry in &entries {
+        if entry.key == key {
+            match &entry.value {
+                toml::Value::String(value) => println!("{}", value),
+                value => println!("{}", value),
+            }
+            found = true;
+        } else if entry.key.starts_with(&section) {
+            println!("{} = {}", entry.key, entry.value);
+            found = true;
+        }
+    }
+
+    // Like `git config`, an unset key prints nothing and fails
+    Ok(if found { 0 } else { 1 })
+}
+
+/// Set a key in the target config file.
+fn set(key: &str, raw: &str, user: bool, cli: &Cli) -> Result<i32, SkiloError> {
+    let formatter = get_formatter(cli.format, cli.quiet);
+    let value = schema::parse_value(key, raw).map_err(SkiloError::Config)?;
+    let path = target_file(user, cli)?;
+
+    config::set_value(&path, key, &value).map_err(|e| SkiloError::Config(e.to_string()))?;
+
+    formatter.format_success(&format!("Set {} = {} in {}", key, value, path.display()));
+    Ok(0)
+}
+
+/// Remove a key from the target config file.
+fn unset(key: &str, user: bool, cli: &Cli) -> Result<i32, SkiloError> {
+    let formatter = get_formatter(cli.format, cli.quiet);
+    let path = target_file(user, cli)?;
+
+    // Unknown keys can be removed too; that is how typos get cleaned up
+    if config::unset_value(&path, key).map_err(|e| SkiloError::Config(e.to_string()))? {
+        formatter.format_success(&format!("Removed {} from {}", key, path.display()));
+        return Ok(0);
+    }
+
+    schema::check_key(key).map_err(|e| SkiloError::Config(e.to_string()))?;
+    formatter.format_message(&format!("{} is not set in {}", key, path.display()));
+    Ok(0)
+}
+
+/// Check config files, reporting every problem found.
+fn validate(file: Option<PathBuf>, cli: &Cli) -> Result<i32, SkiloError> {
+    let formatter = get_formatter(cli.format, cli.quiet);
+    let files = match file {
+        Some(file) => vec![file],
+        None => Config::sources(cli.config.as_ref()),
+    };
+    if files.is_empty() {
+        formatter.format_message("No config files found");
+        return Ok(0);
+    }
+
+    let mut invalid = 0;
+    for path in &files {
+        let issues = config::check_file(path)?;
+        for issue in &issues {
+            formatter.format_error(&format!("{}: {}", path.display(), issue));
+        }
+        if issues.is_empty() {
+            formatter.format_success(&format!("{} is valid", path.display()));
+        } else {
+            invalid += 1;
+        }
+    }
+
+    Ok(if invalid == 0 { 0 } else { 1 })
+}
+
+/// The file `set` and `unset` edit: `--config`, the user config with
+/// `--user`, or the nearest project config (`.skilorc.toml` if there is none).
+fn target_file(user: bool, cli: &Cli) -> Result<PathBuf, SkiloError> {
+    if let Some(path) = &cli.config {
+        return Ok(path.clone());
+    }
+    if user {
+        return Config::user_config_path()
+            .ok_or_else(|| SkiloError::Config("Could not determine home directory".to_string()));
+    }
+
+    let cwd = std::env::current_dir()?;
+    Ok(Config::project_config_paths(&cwd)
+        .pop()
+        .unwrap_or_else(|| Path::new(CONFIG_FILES[0]).to_path_buf()))
+}
@@ -0,0 +1,30 @@
+//! Prints documentation for lint rules.
+
+use crate::cli::{Cli, ExplainArgs};
+use crate::config::Config;
+use crate::error::SkiloError;
+use crate::output::get_formatter;
+use crate::skill::rules::registry;
+
+/// Run the explain command.
+///
+/// Prints the long-form documentation of a single rule, of every rule with
+/// `--all`, or a summary listing when no rule is given.
+pub fn run(args: ExplainArgs, _config: &Config, cli: &Cli) -> Result<i32, SkiloError> {
+    let formatter = get_formatter(cli.format, cli.quiet);
+
+    let output = match &args.rule {
+        Some(query) => {
+            let meta =
+                registry::find(query).ok_or_else(|| SkiloError::UnknownRule(query.clone()))?;
+            formatter.format_rules(&[meta], true)
+        }
+        // The text form of --all is the rule reference committed as docs/rules.md
+        None if args.all && !cli.format.is_structured() => registry::render_docs(),
+        None => formatter.format_rules(&registry::all(), args.all),
+    };
+
+    println!("{}", output.trim_end());
+
+    Ok(0)
+}
@@ -0,0 +1,115 @@
+//! Exports skills as agent-native rule files.
+
+use crate::cli::{Cli, ExportArgs};
+use crate::config::Config;
+use crate::error::SkiloError;
+use crate::export::{self, ExportReport, ExportedFile};
+use crate::output::get_formatter;
+use crate::skill::{Discovery, Manifest};
+
+/// Run the export command.
+///
+/// Writes a rule file (or `GEMINI.md` section) per skill and target. Skills
+/// that fail to parse or have an invalid name are reported and skipped.
+pub fn run(args: ExportArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError> {
+    let formatter = get_formatter(cli.format, cli.quiet);
+
+    let mut skill_paths = Vec::new();
+    for path in &args.paths {
+        skill_paths.extend(Discovery::find_skills(path, &config.discovery.ignore));
+    }
+    if skill_paths.is_empty() {
+        return Err(SkiloError::NoSkillsFound {
+            path: args
+                .paths
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        });
+    }
+
+    let mut report = ExportReport {
+        files: Vec::new(),
+        dry_run: args.dry_run,
+    };
+
+    let mut failed = 0;
+    for path in skill_paths {
+        let manifest = match Manifest::parse(path.clone()) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                formatter.format_error(&format!("{}: skipped, {}", path.display(), e));
+                failed += 1;
+                continue;
+            }
+        };
+        for target in &args.to {
+            let output = match export::render(&manifest, *target, &args.output_dir) {
+                Ok(output) => output,
+                Err(e) => {
+                    formatter.format_error(&format!("{}: skipped, {}", path.display(), e));
+                    failed += 1;
+                    break;
+                }
+            };
+            let status = export::write(&output, &args.output_dir, args.force, args.dry_run)?;
+            report.files.push(ExportedFile {
+                skill: manifest.frontmatter.name.clone(),
+                target: *target,
+                path: output.path().to_path_buf(),
+                status,
+            });
+        }
+    }
+
+    println!("{}", formatter.format_export(&report).trim_end());
+
+    Ok(if failed == 0 && report.is_ok() { 0 } else { 1 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_broken_skill_does_not_stop_the_export() {
+        let temp = TempDir::new().unwrap();
+        let skills = temp.path().join("skills");
+        for (name, content) in [
+            ("broken", "No frontmatter here.\n"),
+            (
+                "escape",
+                "---\nname: ../../escape\ndescription: Escape\n---\n\nBody\n",
+            ),
+            (
+                "review",
+                "---\nname: review\ndescription: Review the diff.\n---\n\nBody\n",
+            ),
+        ] {
+            std::fs::create_dir_all(skills.join(name)).unwrap();
+            std::fs::write(skills.join(name).join("SKILL.md"), content).unwrap();
+        }
+
+        let output = temp.path().join("out");
+        let cli = Cli::parse_from([
+            "skilo",
+            "--quiet",
+            "export",
+            skills.to_str().unwrap(),
+            "--to",
+            "cursor",
+            "--output-dir",
+            output.to_str().unwrap(),
+        ]);
+        let crate::cli::Command::Export(args) = &cli.command else {
+            unreachable!()
+        };
+
+        assert_eq!(run(args.clone(), &Config::default(), &cli).unwrap(), 1);
+        assert!(output.join(".cursor/rules/review.mdc").is_file());
+        assert!(!temp.path().join("escape.mdc").exists());
+    }
+}
@@ -2,16 +2,20 @@
 
 use crate::cli::{Cli, FmtArgs};
 use crate::config::Config;
+use crate::diff::{FileDiff, DEFAULT_CONTEXT};
 use crate::error::SkiloError;
 use crate::output::get_formatter;
 use crate::skill::{Discovery, Formatter, FormatterConfig, Manifest};
 use colored::Colorize;
+use std::io::IsTerminal;
 
 /// Run the format command.
 ///
 /// Formats skills in place, shows diff, or checks formatting depending on args.
 pub fn run(args: FmtArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError> {
     let output_formatter = get_formatter(cli.format, cli.quiet);
+    // JSON and SARIF report all diffs as a single document at the end
+    let structured = cli.format.is_structured();
     let skill_formatter = Formatter::new(FormatterConfig::from(&config.fmt));
 
     // Find all skills
@@ -25,6 +29,7 @@ pub fn run(args: FmtArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError>
 
     let mut files_changed = 0;
     let mut files_checked = 0;
+    let mut diffs: Vec<FileDiff> = Vec::new();
 
     for path in &skill_paths {
         match Manifest::parse(path.clone()) {
@@ -46,17 +51,28 @@ pub fn run(args: FmtArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError>
                 if formatted != current {
                     files_changed += 1;
 
-                    if args.check {
-                        output_formatter.format_message(&format!(
-                            "{} {} needs formatting",
-                            "!".yellow(),
-                            path.display()
-                        ));
-                    } else if args.diff {
-                        // Show diff
-                        println!("{}", format!("--- {}", path.display()).dimmed());
-                        println!("{}", format!("+++ {}", path.display()).dimmed());
-                        print_diff(&current, &formatted);
+                    if args.check || args.diff {
+                        let diff = FileDiff::compute(
+                            path.display().to_string(),
+                            &current,
+                            &formatted,
+                            DEFAULT_CONTEXT,
+                        );
+
+                        if structured {
+                            diffs.push(diff);
+                        } else if args.diff || !cli.quiet {
+                            // Plain hunks when piped, so `--check` output can be fed
+                            // to `git apply`
+                            let piped = !std::io::stdout().is_terminal();
+                            if piped {
+                                colored::control::set_override(false);
+                            }
+                            print!("{}", output_formatter.format_diffs(&[diff], 1));
+                            if piped {
+                                colored::control::unset_override();
+                            }
+                        }
                     } else {
                         // Write formatted content
                         std::fs::write(path, &formatted)?;
@@ -74,13 +90,30 @@ pub fn run(args: FmtArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError>
         }
     }
 
+    if structured && (args.check || args.diff) {
+        println!(
+            "{}",
+            output_formatter
+                .format_diffs(&diffs, files_checked)
+                .trim_end()
+        );
+        return Ok(if args.check && files_changed > 0 {
+            1
+        } else {
+            0
+        });
+    }
+
+    // Summaries after hunks go to stderr, keeping stdout a clean patch
     if args.check {
         if files_changed > 0 {
-            output_formatter.format_message(&format!(
-                "\n{} {} file(s) need formatting",
-                "!".yellow(),
-                files_changed
-            ));
+            if !cli.quiet {
+                eprintln!(
+                    "\n{} {} file(s) need formatting",
+                    "!".yellow(),
+                    files_changed
+                );
+            }
             Ok(1)
         } else {
             output_formatter.format_success(&format!(
@@ -90,7 +123,15 @@ pub fn run(args: FmtArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError>
             Ok(0)
         }
     } else {
-        if files_changed > 0 {
+        if files_changed > 0 && args.diff {
+            if !cli.quiet {
+                eprintln!(
+                    "\n{} {} file(s) would be reformatted",
+                    "!".yellow(),
+                    files_changed
+                );
+            }
+        } else if files_changed > 0 {
             output_formatter.format_success(&format!("Formatted {} file(s)", files_changed));
         } else {
             output_formatter.format_success(&format!(
@@ -101,34 +142,3 @@ pub fn run(args: FmtArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError>
         Ok(0)
     }
 }
-
-/// Print a simple line-by-line diff between two strings.
-fn print_diff(old: &str, new: &str) {
-    let old_lines: Vec<&str> = old.lines().collect();
-    let new_lines: Vec<&str> = new.lines().collect();
-
-    // Simple line-by-line diff
-    let max_lines = old_lines.len().max(new_lines.len());
-
-    for i in 0..max_lines {
-        let old_line = old_lines.get(i).copied();
-        let new_line = new_lines.get(i).copied();
-
-        match (old_line, new_line) {
-            (Some(o), Some(n)) if o == n => {
-                println!(" {}", o);
-            }
-            (Some(o), Some(n)) => {
-                println!("{}", format!("-{}", o).red());
-                println!("{}", format!("+{}", n).green());
-            }
-            (Some(o), None) => {
-                println!("{}", format!("-{}", o).red());
-            }
-            (None, Some(n)) => {
-                println!("{}", format!("+{}", n).green());
-            }
-            (None, None) => {}
-        }
-    }
-}
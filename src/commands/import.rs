@@ -0,0 +1,155 @@
+//! Imports Cursor rules, Claude commands and Copilot prompts as skills.
+
+use crate::cli::{Cli, ImportArgs};
+use crate::config::Config;
+use crate::error::SkiloError;
+use crate::import;
+use crate::output::get_formatter;
+use crate::skill::{Manifest, Validator};
+use std::collections::HashSet;
+
+/// Run the import command.
+///
+/// Writes one skill per detected file and validates each result. Returns 1
+/// if a file could not be imported or an imported skill has errors.
+pub fn run(args: ImportArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError> {
+    let formatter = get_formatter(cli.format, cli.quiet);
+
+    let sources: Vec<_> = args
+        .paths
+        .iter()
+        .flat_map(|path| import::find_sources(path))
+        .collect();
+    if sources.is_empty() {
+        return Err(SkiloError::NothingToImport {
+            path: args
+                .paths
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        });
+    }
+
+    let output_dir = super::new::resolve_output_dir(
+        args.output.as_ref(),
+        args.agent.as_ref(),
+        args.global,
+        config,
+    )?;
+    let max_body_lines = config
+        .lint
+        .rules
+        .body_length
+        .resolve(500)
+        .unwrap_or(usize::MAX);
+    let validator = Validator::new(&config.lint);
+
+    let mut names = HashSet::new();
+    let mut failed = 0;
+    let mut results = Vec::new();
+
+    for (path, format) in sources {
+        let skill = match import::convert(&path, format, max_body_lines) {
+            Ok(skill) => skill,
+            Err(e) => {
+                formatter.format_error(&format!("{}: {}", path.display(), e));
+                failed += 1;
+                continue;
+            }
+        };
+        let skill_dir = output_dir.join(skill.name());
+
+        if !names.insert(skill.name().to_string()) {
+            formatter.format_error(&format!(
+                "{}: skipped, another file was already imported as '{}'",
+                path.display(),
+                skill.name()
+            ));
+            failed += 1;
+            continue;
+        }
+        if skill_dir.exists() {
+            if !args.force {
+                formatter.format_error(&format!(
+                    "{}: skipped, skill '{}' already exists at {} (use --force to replace it)",
+                    path.display(),
+                    skill.name(),
+                    skill_dir.display()
+                ));
+                failed += 1;
+                continue;
+            }
+            std::fs::remove_dir_all(&skill_dir)?;
+        }
+
+        for warning in &skill.warnings {
+            formatter.format_message(&format!("warning: {}", warning));
+        }
+        let skill_md = match skill.write(&output_dir) {
+            Ok(skill_md) => skill_md,
+            Err(e) => {
+                formatter.format_error(&format!("{}: {}", path.display(), e));
+                failed += 1;
+                continue;
+            }
+        };
+        formatter.format_success(&format!(
+            "Imported {} {} as '{}' at {}",
+            format,
+            path.display(),
+            skill.name(),
+            skill_dir.display()
+        ));
+
+        let manifest = Manifest::parse(skill_md.clone())?;
+        results.push((
+            skill_md.display().to_string(),
+            validator.validate(&manifest),
+        ));
+    }
+
+    if !results.is_empty() {
+        print!("{}", formatter.format_validation(&results));
+    }
+
+    let has_errors = results.iter().any(|(_, r)| !r.is_ok());
+    Ok(if failed > 0 || has_errors { 1 } else { 0 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_unreadable_file_does_not_stop_the_import() {
+        let temp = TempDir::new().unwrap();
+        let commands = temp.path().join(".claude/commands");
+        std::fs::create_dir_all(&commands).unwrap();
+        std::fs::write(commands.join("broken.md"), [0xff, 0xfe, 0x00]).unwrap();
+        std::fs::write(
+            commands.join("review.md"),
+            "---\ndescription: Review the current change.\n---\n\nReview the diff.\n",
+        )
+        .unwrap();
+
+        let output = temp.path().join("skills");
+        let cli = Cli::parse_from([
+            "skilo",
+            "--quiet",
+            "import",
+            temp.path().to_str().unwrap(),
+            "--output",
+            output.to_str().unwrap(),
+        ]);
+        let crate::cli::Command::Import(args) = &cli.command else {
+            unreachable!()
+        };
+
+        assert_eq!(run(args.clone(), &Config::default(), &cli).unwrap(), 1);
+        assert!(output.join("review/SKILL.md").is_file());
+        assert!(!output.join("broken").exists());
+    }
+}
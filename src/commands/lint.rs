@@ -1,10 +1,11 @@
 //! Validates skills against the Agent Skills specification rules.
 
-use crate::cli::{Cli, LintArgs};
+use crate::cli::{Agent, Cli, LintArgs};
 use crate::config::Config;
 use crate::error::SkiloError;
 use crate::output::get_formatter;
-use crate::skill::{Discovery, Manifest, ValidationResult, Validator};
+use crate::skill::{Discovery, LintCache, Manifest, ValidationResult, Validator};
+use rayon::prelude::*;
 
 /// Run the lint command.
 ///
@@ -22,15 +23,44 @@ pub fn run(args: LintArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError
         });
     }
 
-    // Load and validate skills
-    let validator = Validator::new(&config.lint);
+    let mut lint_config = config.lint.clone();
+    if !args.target_agents.is_empty() {
+        lint_config.target_agents = Agent::resolve_list(&args.target_agents)?;
+    }
+
+    // Load and validate skills in parallel, reusing cached results for unchanged skills
+    let validator = Validator::new(&lint_config);
+    let mut cache = (!args.no_cache)
+        .then(|| LintCache::path_for(&args.path))
+        .flatten()
+        .map(|path| LintCache::load(&path, &lint_config));
+
+    let outcomes: Vec<_> = skill_paths
+        .par_iter()
+        .map(|path| {
+            let fingerprint = cache.as_ref().and_then(|c| c.fingerprint(path));
+            if let (Some(cache), Some(key)) = (&cache, &fingerprint) {
+                if let Some(result) = cache.get(path, key) {
+                    return (path, Ok(result), None);
+                }
+            }
+
+            match Manifest::parse(path.clone()) {
+                Ok(manifest) => (path, Ok(validator.validate(&manifest)), fingerprint),
+                Err(e) => (path, Err(e), None),
+            }
+        })
+        .collect();
+
     let mut results: Vec<(String, ValidationResult)> = Vec::new();
     let mut parse_errors = 0;
 
-    for path in &skill_paths {
-        match Manifest::parse(path.clone()) {
-            Ok(manifest) => {
-                let result = validator.validate(&manifest);
+    for (path, outcome, fingerprint) in outcomes {
+        match outcome {
+            Ok(result) => {
+                if let (Some(cache), Some(key)) = (cache.as_mut(), fingerprint) {
+                    cache.insert(path, key, &result);
+                }
                 results.push((path.display().to_string(), result));
             }
             Err(e) => {
@@ -40,6 +70,12 @@ pub fn run(args: LintArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError
         }
     }
 
+    // A cache that cannot be written only costs speed on the next run
+    if let Some(cache) = &mut cache {
+        cache.retain(&skill_paths);
+        let _ = cache.save();
+    }
+
     // Output results
     let output = formatter.format_validation(&results);
     if !output.is_empty() {
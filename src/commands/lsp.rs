@@ -0,0 +1,12 @@
+//! Starts the language server.
+
+use crate::config::Config;
+use crate::error::SkiloError;
+
+/// Run the lsp command.
+///
+/// Serves the language server over stdio until the client exits.
+pub fn run(config: &Config) -> Result<i32, SkiloError> {
+    crate::lsp::run(config)?;
+    Ok(0)
+}
@@ -0,0 +1,34 @@
+//! Starts the Model Context Protocol server.
+
+use crate::agent::Agent;
+use crate::cli::{AgentSelection, Cli, McpArgs};
+use crate::config::Config;
+use crate::error::SkiloError;
+use crate::mcp::catalog::Catalog;
+use crate::mcp::Server;
+
+/// Run the mcp command.
+///
+/// Serves installed and discovered skills over stdio until the client
+/// closes the connection.
+pub fn run(args: McpArgs, config: &Config, _cli: &Cli) -> Result<i32, SkiloError> {
+    let project_root = args
+        .project
+        .canonicalize()
+        .unwrap_or_else(|_| args.project.clone());
+
+    let agents: Vec<Agent> = match args.agent.as_ref().map(|a| a.to_selection()).transpose()? {
+        Some(AgentSelection::Single(agent)) => vec![agent],
+        Some(AgentSelection::All) | None => Agent::all(),
+    };
+
+    let catalog = Catalog::load(
+        &agents,
+        &project_root,
+        &args.paths,
+        &config.discovery.ignore,
+    );
+    crate::mcp::run(&Server::new(catalog, args.prompts))?;
+
+    Ok(0)
+}
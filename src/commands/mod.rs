@@ -10,12 +10,24 @@ pub mod cache;
 pub mod check;
 /// The `completions` command implementation.
 pub mod completions;
+/// The `config` command implementation.
+pub mod config;
+/// The `explain` command implementation.
+pub mod explain;
+/// The `export` command implementation.
+pub mod export;
 /// The `fmt` command implementation.
 pub mod fmt;
+/// The `import` command implementation.
+pub mod import;
 /// The `lint` command implementation.
 pub mod lint;
 /// The `list` command implementation.
 pub mod list;
+/// The `lsp` command implementation.
+pub mod lsp;
+/// The `mcp` command implementation.
+pub mod mcp;
 /// The `new` command implementation.
 pub mod new;
 /// The `read-properties` command implementation.
@@ -24,5 +36,11 @@ pub mod read_properties;
 pub mod remove;
 /// The `self update` command implementation.
 pub mod self_update;
+/// The `sync` command implementation.
+pub mod sync;
+/// The `sync-index` command implementation.
+pub mod sync_index;
 /// The `to-prompt` command implementation.
 pub mod to_prompt;
+/// The `watch` command implementation.
+pub mod watch;
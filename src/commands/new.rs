@@ -1,95 +1,402 @@
 //! Creates new skills from templates.
 
-use crate::cli::{Cli, NewArgs};
+use crate::agent::Agent;
+use crate::cli::{Cli, NewArgs, ScriptLang, Template};
 use crate::config::Config;
 use crate::error::SkiloError;
 use crate::output::get_formatter;
-use crate::scope::{ensure_skills_dir, Scope};
-use crate::templates::{get_template, TemplateContext};
+use crate::scope::Scope;
+use crate::skill::{Manifest, Validator};
+use crate::templates::{load_template, TemplateContext, TemplatePrompt, TemplateSource};
+use clap::ValueEnum;
+use colored::Colorize;
+use dialoguer::console::{Key, Term};
+use dialoguer::{Input, MultiSelect, Select};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use std::collections::BTreeMap;
+use std::io::IsTerminal;
 use std::path::PathBuf;
 
 /// Pattern for valid skill names.
 static NAME_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*$").unwrap());
 
+/// Common SPDX license identifiers offered by the wizard.
+const LICENSES: &[&str] = &[
+    "MIT",
+    "Apache-2.0",
+    "BSD-2-Clause",
+    "BSD-3-Clause",
+    "GPL-3.0-only",
+    "LGPL-3.0-only",
+    "MPL-2.0",
+    "ISC",
+    "Unlicense",
+];
+
 /// Run the new command.
 ///
-/// Creates a new skill from the specified template.
-pub fn run(args: NewArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError> {
+/// Creates a new skill from the specified template. Without a name, asks
+/// for the skill's details in a terminal and lints the result.
+pub fn run(mut args: NewArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError> {
     let formatter = get_formatter(cli.format, cli.quiet);
 
-    // Validate name
-    if !NAME_REGEX.is_match(&args.name) {
-        return Err(SkiloError::InvalidName(args.name));
-    }
+    // Flags (and their environment variables) win over the config, which
+    // wins over the built-in defaults
+    let mut template_spec = args
+        .template
+        .clone()
+        .or_else(|| config.new.default_template.clone())
+        .unwrap_or_else(|| "hello-world".to_string());
+    let mut lang = args.lang.or(config.new.default_lang).unwrap_or_default();
 
-    if args.name.len() > 64 {
-        return Err(SkiloError::InvalidName(format!(
-            "{} (name too long, max 64 chars)",
-            args.name
-        )));
+    let interactive = args.name.is_none();
+    let mut agents = Vec::new();
+    if interactive {
+        if !std::io::stdin().is_terminal() {
+            return Err(SkiloError::Config(
+                "A skill name is required when not running in a terminal".to_string(),
+            ));
+        }
+        agents = wizard(&mut args, &mut template_spec, &mut lang, config)?;
     }
+    let name = args.name.clone().unwrap_or_default();
 
-    // Determine output directory based on --output, --agent, --global flags
-    let output_dir = resolve_output_dir(&args, config)?;
-    let skill_dir = output_dir.join(&args.name);
+    // Validate name
+    validate_name(&name)?;
+
+    // Determine output directories based on the chosen agents, or on
+    // --output, --agent and --global
+    let output_dirs = if agents.is_empty() {
+        vec![resolve_output_dir(
+            args.output.as_ref(),
+            args.agent.as_ref(),
+            args.global,
+            config,
+        )?]
+    } else {
+        let project_root = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+        let scope = if args.global {
+            Scope::Global
+        } else {
+            Scope::Project
+        };
+        agents
+            .iter()
+            .map(|agent| {
+                scope
+                    .resolve_skills_dir(*agent, &project_root)
+                    .ok_or_else(|| {
+                        SkiloError::Config("Could not determine home directory".to_string())
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    };
 
     // Check if skill already exists
-    if skill_dir.exists() {
-        return Err(SkiloError::SkillExists {
-            name: args.name,
-            path: skill_dir.display().to_string(),
-        });
+    for output_dir in &output_dirs {
+        let skill_dir = output_dir.join(&name);
+        if skill_dir.exists() {
+            return Err(SkiloError::SkillExists {
+                name,
+                path: skill_dir.display().to_string(),
+            });
+        }
     }
 
+    // Load the template (fetching remote templates) and collect its variables
+    let template = load_template(&TemplateSource::parse(&template_spec)?)?;
+    let variables = prompt_variables(template.prompts(), &args.vars)?;
+
     // Get license (from args or config)
     let license = args.license.or_else(|| config.new.default_license.clone());
 
     // Build template context
     let ctx = TemplateContext {
-        name: args.name.clone(),
+        name: name.clone(),
         description: args
             .description
-            .unwrap_or_else(|| format!("A {} skill.", args.name.replace('-', " "))),
+            .unwrap_or_else(|| format!("A {} skill.", name.replace('-', " "))),
         license,
-        lang: args.lang,
+        lang,
         include_optional_dirs: !args.no_optional_dirs,
         include_scripts: !args.no_scripts,
+        variables,
     };
 
-    // Render template
-    let template = get_template(args.template);
-    template.render(&ctx, &output_dir)?;
+    // Render template, creating the skills directories only now that
+    // everything has been validated
+    let mut results = Vec::new();
+    for output_dir in &output_dirs {
+        std::fs::create_dir_all(output_dir)
+            .map_err(|e| SkiloError::Config(format!("Failed to create skills directory: {}", e)))?;
+        template.render(&ctx, output_dir)?;
 
-    formatter.format_success(&format!(
-        "Created skill '{}' at {}",
-        args.name,
-        skill_dir.display()
-    ));
+        let skill_dir = output_dir.join(&name);
+        formatter.format_success(&format!(
+            "Created skill '{}' at {}",
+            name,
+            skill_dir.display()
+        ));
+
+        if interactive {
+            let skill_md = skill_dir.join("SKILL.md");
+            let manifest = Manifest::parse(skill_md.clone())?;
+            let result = Validator::new(&config.lint).validate(&manifest);
+            results.push((skill_md.display().to_string(), result));
+        }
+    }
+
+    // Lint what the wizard created straight away
+    if !results.is_empty() {
+        print!("{}", formatter.format_validation(&results));
+        if results.iter().any(|(_, r)| !r.is_ok()) {
+            return Ok(1);
+        }
+    }
 
     Ok(0)
 }
 
-/// Resolve the output directory based on CLI arguments.
-fn resolve_output_dir(args: &NewArgs, config: &Config) -> Result<PathBuf, SkiloError> {
+/// Check a skill name against the spec.
+fn validate_name(name: &str) -> Result<(), SkiloError> {
+    if !NAME_REGEX.is_match(name) {
+        return Err(SkiloError::InvalidName(name.to_string()));
+    }
+
+    if name.len() > 64 {
+        return Err(SkiloError::InvalidName(format!(
+            "{} (name too long, max 64 chars)",
+            name
+        )));
+    }
+
+    Ok(())
+}
+
+/// Ask for the skill's details, filling in `args`, `template` and `lang`.
+///
+/// Returns the agents to create the skill for; empty means the directory
+/// from `--output`, `--agent` or the config.
+fn wizard(
+    args: &mut NewArgs,
+    template: &mut String,
+    lang: &mut ScriptLang,
+    config: &Config,
+) -> Result<Vec<Agent>, SkiloError> {
+    let name: String = Input::new()
+        .with_prompt("Skill name")
+        .validate_with(|input: &String| validate_name(input).map_err(|e| e.to_string()))
+        .interact_text()
+        .map_err(|_| SkiloError::Cancelled)?;
+
+    let max_description = config.lint.rules.description_length.resolve(1024);
+    let description = read_description(max_description).map_err(|_| SkiloError::Cancelled)?;
+
+    let mut templates: Vec<String> = Template::value_variants()
+        .iter()
+        .filter_map(|t| t.to_possible_value())
+        .map(|v| v.get_name().to_string())
+        .collect();
+    let default = templates.iter().position(|t| t == template).unwrap_or(0);
+    templates.push("custom (directory or repository)".to_string());
+    let choice = Select::new()
+        .with_prompt("Template")
+        .items(&templates)
+        .default(default)
+        .interact()
+        .map_err(|_| SkiloError::Cancelled)?;
+    *template = if choice == templates.len() - 1 {
+        Input::new()
+            .with_prompt("Template directory or owner/repo#dir")
+            .interact_text()
+            .map_err(|_| SkiloError::Cancelled)?
+    } else {
+        templates[choice].clone()
+    };
+
+    let langs = ScriptLang::value_variants();
+    let lang_names: Vec<String> = langs
+        .iter()
+        .filter_map(|l| l.to_possible_value())
+        .map(|v| v.get_name().to_string())
+        .collect();
+    let choice = Select::new()
+        .with_prompt("Script language")
+        .items(&lang_names)
+        .default(langs.iter().position(|l| l == lang).unwrap_or(0))
+        .interact()
+        .map_err(|_| SkiloError::Cancelled)?;
+    *lang = langs[choice];
+
+    let mut licenses: Vec<&str> = vec!["none"];
+    licenses.extend(LICENSES);
+    licenses.push("other");
+    let current = args
+        .license
+        .as_deref()
+        .or(config.new.default_license.as_deref());
+    let choice = Select::new()
+        .with_prompt("License")
+        .items(&licenses)
+        .default(
+            current
+                .and_then(|c| licenses.iter().position(|l| *l == c))
+                .unwrap_or(0),
+        )
+        .interact()
+        .map_err(|_| SkiloError::Cancelled)?;
+    args.license = match licenses[choice] {
+        "none" => None,
+        "other" => Some(
+            Input::new()
+                .with_prompt("SPDX license identifier")
+                .interact_text()
+                .map_err(|_| SkiloError::Cancelled)?,
+        ),
+        license => Some(license.to_string()),
+    };
+
+    let mut agents = Vec::new();
+    if args.output.is_none() && args.agent.is_none() {
+        let project_root = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+        let detected = Agent::detect_project(&project_root);
+        let items: Vec<&str> = Agent::all().iter().map(|a| a.display_name()).collect();
+        let defaults: Vec<bool> = Agent::all().iter().map(|a| detected.contains(a)).collect();
+        let chosen = MultiSelect::new()
+            .with_prompt("Target agents (none for ./skills)")
+            .items(&items)
+            .defaults(&defaults)
+            .interact()
+            .map_err(|_| SkiloError::Cancelled)?;
+        agents = chosen.into_iter().map(|i| Agent::all()[i]).collect();
+    }
+
+    let chosen = MultiSelect::new()
+        .with_prompt("Optional directories")
+        .items(&["scripts/", "references/ and assets/"])
+        .defaults(&[!args.no_scripts, !args.no_optional_dirs])
+        .interact()
+        .map_err(|_| SkiloError::Cancelled)?;
+    args.no_scripts = !chosen.contains(&0);
+    args.no_optional_dirs = !chosen.contains(&1);
+
+    args.name = Some(name);
+    args.description = Some(description.trim().to_string());
+    Ok(agents)
+}
+
+/// Check a description: required, and at most `max` characters if given.
+fn validate_description(input: &str, max: Option<usize>) -> Result<(), String> {
+    let length = input.trim().chars().count();
+    match max {
+        _ if length == 0 => Err("A description is required".to_string()),
+        Some(max) if length > max => Err(format!("Too long: {}/{} characters", length, max)),
+        _ => Ok(()),
+    }
+}
+
+/// Read a description on stderr, with a character counter that updates as
+/// the user types.
+fn read_description(max: Option<usize>) -> std::io::Result<String> {
+    let term = Term::stderr();
+    let mut text = String::new();
+
+    loop {
+        let length = text.trim().chars().count();
+        let counter = match max {
+            Some(max) if length > max => format!("[{}/{}]", length, max).red(),
+            Some(max) => format!("[{}/{}]", length, max).dimmed(),
+            None => format!("[{}]", length).dimmed(),
+        };
+        term.clear_line()?;
+        term.write_str(&format!("Description {}: {}", counter, text))?;
+
+        match term.read_key()? {
+            Key::Enter => {
+                term.write_line("")?;
+                match validate_description(&text, max) {
+                    Ok(()) => return Ok(text.trim().to_string()),
+                    Err(e) => term.write_line(&format!("{} {}", "error:".red(), e))?,
+                }
+            }
+            Key::Backspace => {
+                text.pop();
+            }
+            Key::Char(c) if !c.is_control() => text.push(c),
+            Key::Escape | Key::CtrlC => {
+                term.write_line("")?;
+                return Err(std::io::ErrorKind::Interrupted.into());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Values for the template's custom variables.
+///
+/// Uses `--var` values first, then asks on a terminal, then falls back to
+/// the declared default.
+fn prompt_variables(
+    prompts: &[TemplatePrompt],
+    vars: &[(String, String)],
+) -> Result<BTreeMap<String, String>, SkiloError> {
+    let mut values: BTreeMap<String, String> = vars.iter().cloned().collect();
+    let interactive = std::io::stdin().is_terminal();
+
+    for prompt in prompts {
+        if values.contains_key(&prompt.name) {
+            continue;
+        }
+        let value = if interactive {
+            let mut input = Input::<String>::new()
+                .with_prompt(prompt.message.as_deref().unwrap_or(&prompt.name))
+                .allow_empty(true);
+            if let Some(default) = &prompt.default {
+                input = input.default(default.clone());
+            }
+            input.interact_text().map_err(|_| SkiloError::Cancelled)?
+        } else {
+            prompt.default.clone().ok_or_else(|| {
+                SkiloError::SkillTemplate(format!(
+                    "no value for '{}'; pass --var {}=<value>",
+                    prompt.name, prompt.name
+                ))
+            })?
+        };
+        values.insert(prompt.name.clone(), value);
+    }
+
+    Ok(values)
+}
+
+/// Resolve the output directory from `--output`, `--agent` and `--global`.
+///
+/// Also used by `import`, which places skills the same way. The directory
+/// is not created here, so nothing is left behind when validation fails.
+pub(crate) fn resolve_output_dir(
+    output: Option<&PathBuf>,
+    cli_agent: Option<&crate::cli::Agent>,
+    global: bool,
+    config: &Config,
+) -> Result<PathBuf, SkiloError> {
     let project_root = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
 
     // If --output is specified, use it directly
-    if let Some(ref output) = args.output {
+    if let Some(output) = output {
         return Ok(output.clone());
     }
 
     // Determine scope
-    let scope = if args.global {
+    let scope = if global {
         Scope::Global
     } else {
         Scope::Project
     };
 
     // Determine agent
-    let agent: Option<crate::agent::Agent> = if let Some(ref cli_agent) = args.agent {
-        match cli_agent.to_selection() {
+    let agent: Option<crate::agent::Agent> = if let Some(cli_agent) = cli_agent {
+        match cli_agent.to_selection()? {
             crate::cli::AgentSelection::Single(a) => Some(a),
             crate::cli::AgentSelection::All => config.add.default_agent,
         }
@@ -97,21 +404,67 @@ fn resolve_output_dir(args: &NewArgs, config: &Config) -> Result<PathBuf, SkiloE
         config.add.default_agent
     };
 
-    // Ensure skills directory exists and return it
     match agent {
-        Some(agent) => ensure_skills_dir(agent, scope, &project_root)
-            .map_err(|e| SkiloError::Config(format!("Failed to create skills directory: {}", e))),
+        Some(agent) => scope
+            .resolve_skills_dir(agent, &project_root)
+            .ok_or_else(|| SkiloError::Config("Could not determine home directory".to_string())),
         None => {
-            if args.global {
+            if global {
                 return Err(SkiloError::Config(
                     "Global installation requires an agent (use --agent)".to_string(),
                 ));
             }
-            let skills_dir = project_root.join("skills");
-            std::fs::create_dir_all(&skills_dir).map_err(|e| {
-                SkiloError::Config(format!("Failed to create skills directory: {}", e))
-            })?;
-            Ok(skills_dir)
+            Ok(project_root.join("skills"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[test]
+    fn test_validate_name() {
+        assert!(validate_name("pdf-tools").is_ok());
+        assert!(validate_name("v2").is_ok());
+        assert!(validate_name("PDF-Tools").is_err());
+        assert!(validate_name("-pdf").is_err());
+        assert!(validate_name("pdf--tools").is_err());
+        assert!(validate_name("").is_err());
+        assert!(validate_name(&"a".repeat(64)).is_ok());
+        assert!(validate_name(&"a".repeat(65))
+            .unwrap_err()
+            .to_string()
+            .contains("name too long"));
+    }
+
+    #[test]
+    fn test_validate_description() {
+        assert!(validate_description("Work with PDFs", Some(1024)).is_ok());
+        assert_eq!(
+            validate_description("   ", Some(1024)),
+            Err("A description is required".to_string())
+        );
+        assert_eq!(
+            validate_description("日本語のテキスト", Some(5)),
+            Err("Too long: 8/5 characters".to_string())
+        );
+        assert!(validate_description("日本語のテキスト", Some(8)).is_ok());
+        assert!(validate_description(&"x".repeat(5000), None).is_ok());
+    }
+
+    #[test]
+    fn test_wizard_requires_a_terminal() {
+        if std::io::stdin().is_terminal() {
+            return;
         }
+        let cli = Cli::parse_from(["skilo", "new"]);
+        let crate::cli::Command::New(args) = &cli.command else {
+            unreachable!()
+        };
+
+        let err = run(args.clone(), &Config::default(), &cli).unwrap_err();
+        assert!(err.to_string().contains("not running in a terminal"));
     }
 }
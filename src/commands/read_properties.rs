@@ -3,9 +3,9 @@
 use crate::cli::{Cli, ReadPropertiesArgs};
 use crate::config::Config;
 use crate::error::SkiloError;
-use crate::skill::{Discovery, Manifest};
+use crate::skill::{Discovery, Manifest, Requirements};
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 /// JSON output structure for a single skill's properties.
@@ -25,9 +25,13 @@ pub struct SkillProperties {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compatibility: Option<String>,
 
+    /// Agents, operating systems and tools recognized in the compatibility field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requirements: Option<Requirements>,
+
     /// Additional metadata key-value pairs
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<HashMap<String, String>>,
+    pub metadata: Option<BTreeMap<String, String>>,
 
     /// Pre-approved tools (space-delimited string)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -44,6 +48,12 @@ impl From<&Manifest> for SkillProperties {
             description: manifest.frontmatter.description.clone(),
             license: manifest.frontmatter.license.clone(),
             compatibility: manifest.frontmatter.compatibility.clone(),
+            requirements: manifest
+                .frontmatter
+                .compatibility
+                .as_deref()
+                .map(Requirements::parse)
+                .filter(|requirements| !requirements.is_empty()),
             metadata: manifest.frontmatter.metadata.clone(),
             allowed_tools: manifest.frontmatter.allowed_tools.clone(),
             path: manifest.path.clone(),
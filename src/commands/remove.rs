@@ -5,7 +5,7 @@ use crate::cli::{Cli, RemoveArgs};
 use crate::config::Config;
 use crate::error::SkiloError;
 use crate::output::get_formatter;
-use crate::scope::Scope;
+use crate::scope::{RemovalReport, Scope};
 use colored::Colorize;
 use dialoguer::Confirm;
 use std::path::PathBuf;
@@ -23,7 +23,7 @@ pub fn run(args: RemoveArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloErr
     };
 
     // Determine agent (None means use ./skills/)
-    let agent: Option<Agent> = match args.agent.as_ref().map(|a| a.to_selection()) {
+    let agent: Option<Agent> = match args.agent.as_ref().map(|a| a.to_selection()).transpose()? {
         Some(crate::cli::AgentSelection::Single(a)) => Some(a),
         Some(crate::cli::AgentSelection::All) => config.add.default_agent,
         None => config.add.default_agent,
@@ -56,27 +56,32 @@ pub fn run(args: RemoveArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloErr
     }
 
     // Find skills to remove
+    let structured = cli.format.is_structured();
+    let mut report = RemovalReport::default();
     let mut to_remove: Vec<(String, PathBuf)> = Vec::new();
-    let mut not_found: Vec<String> = Vec::new();
 
     for skill_name in &args.skills {
         let skill_path = skills_dir.join(skill_name);
         if skill_path.exists() && skill_path.join("SKILL.md").exists() {
             to_remove.push((skill_name.clone(), skill_path));
         } else {
-            not_found.push(skill_name.clone());
+            report.not_found.push(skill_name.clone());
         }
     }
 
     // Report not found skills
-    if !not_found.is_empty() && !cli.quiet {
-        for name in &not_found {
+    if !structured && !cli.quiet {
+        for name in &report.not_found {
             eprintln!("{}: Skill '{}' not found", "Warning".yellow(), name);
         }
     }
 
     if to_remove.is_empty() {
-        formatter.format_error("No skills to remove");
+        if structured {
+            println!("{}", formatter.format_removal(&report));
+        } else {
+            formatter.format_error("No skills to remove");
+        }
         return Ok(1);
     }
 
@@ -110,38 +115,39 @@ pub fn run(args: RemoveArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloErr
     }
 
     // Remove skills
-    let mut removed = 0;
-    for (name, path) in &to_remove {
-        if !cli.quiet {
+    let show_progress = !structured && !cli.quiet;
+    for (name, path) in to_remove {
+        if show_progress {
             print!("Removing {}...", name.cyan());
         }
 
-        match std::fs::remove_dir_all(path) {
+        match std::fs::remove_dir_all(&path) {
             Ok(()) => {
-                removed += 1;
-                if !cli.quiet {
+                if show_progress {
                     println!(" {}", "done".green());
                 }
+                report.removed.push((name, path));
             }
             Err(e) => {
-                if !cli.quiet {
+                if show_progress {
                     println!(" {}", "failed".red());
                 }
-                formatter.format_error(&format!("Failed to remove '{}': {}", name, e));
+                if !structured {
+                    formatter.format_error(&format!("Failed to remove '{}': {}", name, e));
+                }
+                report.failed.push((name, path, e.to_string()));
             }
         }
     }
 
-    if !cli.quiet {
+    if structured {
+        println!("{}", formatter.format_removal(&report));
+    } else if !cli.quiet {
         println!();
-        formatter.format_success(&format!(
-            "Removed {} skill{}",
-            removed,
-            if removed == 1 { "" } else { "s" }
-        ));
+        print!("{}", formatter.format_removal(&report));
     }
 
-    if removed == to_remove.len() {
+    if report.is_ok() {
         Ok(0)
     } else {
         Ok(1)
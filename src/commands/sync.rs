@@ -0,0 +1,177 @@
+//! Mirrors skills from one skills directory into every agent's directory.
+
+use crate::agent::Agent;
+use crate::cli::{Agent as AgentArg, AgentSelection, Cli, SyncArgs};
+use crate::config::Config;
+use crate::error::SkiloError;
+use crate::output::get_formatter;
+use crate::sync::{self, SyncAction, SyncReport};
+use colored::Colorize;
+use dialoguer::Confirm;
+use std::path::{Component, Path, PathBuf};
+
+/// Run the sync command.
+///
+/// Returns 1 with `--check` if any agent is out of sync.
+pub fn run(args: SyncArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError> {
+    let formatter = get_formatter(cli.format, cli.quiet);
+    let mode = args.mode.unwrap_or(config.sync.mode);
+
+    let from = match args.from.as_ref().map(AgentArg::to_selection).transpose()? {
+        Some(AgentSelection::All) => {
+            return Err(SkiloError::Sync(
+                "--from takes a single agent, not 'all'".into(),
+            ))
+        }
+        Some(AgentSelection::Single(agent)) => Some(agent),
+        None => config.sync.from,
+    };
+    let source = match from {
+        Some(agent) => agent.resolve_project_skills_dir(&args.path),
+        None => args.path.join("skills"),
+    };
+    if !source.is_dir() {
+        return Err(SkiloError::Sync(format!(
+            "Source directory {} does not exist",
+            source.display()
+        )));
+    }
+
+    let agents = if !args.agent.is_empty() {
+        AgentArg::resolve_list(&args.agent)?
+    } else if !config.sync.agents.is_empty() {
+        config.sync.agents.clone()
+    } else {
+        Agent::detect_project(&args.path)
+    };
+    // Agents sharing a skills directory (with the source or each other) are
+    // synced once, by the first of them
+    let mut targets = vec![dir_key(&source)];
+    let agents: Vec<Agent> = agents
+        .into_iter()
+        .filter(|agent| {
+            let key = dir_key(&agent.resolve_project_skills_dir(&args.path));
+            let first = !targets.contains(&key);
+            targets.push(key);
+            first
+        })
+        .collect();
+    if agents.is_empty() {
+        return Err(SkiloError::Sync(format!(
+            "No agents to sync in {}; detected agents are used unless --agent is given",
+            args.path.display()
+        )));
+    }
+
+    let dry_run = args.check || args.dry_run;
+    let mut report = SyncReport {
+        source: source.clone(),
+        agents: agents.clone(),
+        changes: Vec::new(),
+        dry_run,
+    };
+    for agent in &agents {
+        let target = agent.resolve_project_skills_dir(&args.path);
+        report
+            .changes
+            .extend(sync::plan(*agent, &source, &target, mode));
+    }
+
+    let deletions = report.count(SyncAction::Deleted);
+    if !dry_run && !args.yes && deletions > 0 {
+        println!("Skills not in {}:", source.display());
+        for change in &report.changes {
+            if change.action == SyncAction::Deleted {
+                println!(
+                    "  {} ({})",
+                    change.skill.cyan(),
+                    change.path.display().to_string().dimmed()
+                );
+            }
+        }
+        println!();
+
+        let prompt = format!(
+            "Delete {} skill{}?",
+            deletions,
+            if deletions == 1 { "" } else { "s" }
+        );
+        if !Confirm::new()
+            .with_prompt(prompt)
+            .interact()
+            .map_err(|_| SkiloError::Cancelled)?
+        {
+            return Err(SkiloError::Cancelled);
+        }
+        println!();
+    }
+
+    if !dry_run {
+        for change in &report.changes {
+            sync::apply(change, &source, mode)
+                .map_err(|e| SkiloError::Sync(format!("{}: {}", change.path.display(), e)))?;
+        }
+    }
+
+    let output = formatter.format_sync(&report);
+    if !output.is_empty() {
+        print!("{}", output);
+    }
+
+    if args.check && !report.is_in_sync() {
+        formatter.format_error("Agents are out of sync; run `skilo sync`");
+        return Ok(1);
+    }
+    Ok(0)
+}
+
+/// A directory's canonical path, or its path without `.` components if it
+/// does not exist yet.
+fn dir_key(dir: &Path) -> PathBuf {
+    dir.canonicalize().unwrap_or_else(|_| {
+        dir.components()
+            .filter(|c| !matches!(c, Component::CurDir))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use tempfile::TempDir;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_agents_sharing_a_directory_sync_once() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::create_dir_all(root.join("skills/review")).unwrap();
+        std::fs::write(
+            root.join("skills/review/SKILL.md"),
+            "---\nname: review\ndescription: Review the diff.\n---\n\nBody\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(root.join(".claude/skills")).unwrap();
+        std::os::unix::fs::symlink(root.join(".claude"), root.join(".codex")).unwrap();
+
+        for mode in ["symlink", "copy"] {
+            let cli = Cli::parse_from([
+                "skilo",
+                "--quiet",
+                "sync",
+                root.to_str().unwrap(),
+                "--agent",
+                "claude,codex",
+                "--mode",
+                mode,
+                "--yes",
+            ]);
+            let crate::cli::Command::Sync(args) = &cli.command else {
+                unreachable!()
+            };
+            assert_eq!(run(args.clone(), &Config::default(), &cli).unwrap(), 0);
+            assert!(root.join(".claude/skills/review/SKILL.md").is_file());
+        }
+    }
+}
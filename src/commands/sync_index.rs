@@ -0,0 +1,168 @@
+//! Keeps the skills index in agent instruction files up to date.
+
+use crate::agent::Agent;
+use crate::cli::{AgentSelection, Cli, SyncIndexArgs};
+use crate::config::{self, Config, CONFIG_FILES};
+use crate::error::SkiloError;
+use crate::export::index::{self, INDEX_FILES};
+use crate::export::{self, WriteStatus};
+use crate::output::get_formatter;
+use std::path::PathBuf;
+
+/// Run the sync-index command.
+///
+/// `--agent` and `--file` default to `[index] agent` and `files`. With
+/// `--save` they are written there so that `skilo check` verifies the same
+/// index.
+///
+/// Returns 1 with `--check` if any index is out of date.
+pub fn run(args: SyncIndexArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError> {
+    let formatter = get_formatter(cli.format, cli.quiet);
+
+    let agent = match args.agent.as_ref().map(|a| a.to_selection()).transpose()? {
+        Some(AgentSelection::Single(agent)) => Some(agent),
+        Some(AgentSelection::All) => None,
+        None => config.index.agent,
+    };
+    let agents = agent.map_or_else(Agent::all, |agent| vec![agent]);
+    let skills = index::installed_skills(&args.path, &agents);
+    let content = index::render(&skills, &args.path);
+
+    let files: Vec<PathBuf> = if !args.files.is_empty() {
+        args.files.clone()
+    } else if !config.index.files.is_empty() {
+        config.index.files.clone()
+    } else {
+        let existing: Vec<PathBuf> = INDEX_FILES
+            .iter()
+            .map(PathBuf::from)
+            .filter(|f| args.path.join(f).is_file())
+            .collect();
+        if existing.is_empty() {
+            vec![PathBuf::from(INDEX_FILES[0])]
+        } else {
+            existing
+        }
+    };
+
+    let dry_run = args.check || args.dry_run;
+    let mut stale = 0;
+    for file in &files {
+        let output = index::output(file, &content);
+        let status = export::write(&output, &args.path, false, dry_run)?;
+        let verb = match (&status, dry_run) {
+            (WriteStatus::Unchanged, _) => "up to date",
+            (_, true) if args.check => "out of date",
+            (WriteStatus::Created, true) => "would create",
+            (_, true) => "would update",
+            (WriteStatus::Created, false) => "created",
+            (_, false) => "updated",
+        };
+        if status != WriteStatus::Unchanged {
+            stale += 1;
+        }
+
+        let message = format!("{}: {}", file.display(), verb);
+        if args.check && status != WriteStatus::Unchanged {
+            formatter.format_error(&format!("{}; run `skilo sync-index`", message));
+        } else {
+            formatter.format_message(&message);
+        }
+    }
+
+    if args.check && stale > 0 {
+        return Ok(1);
+    }
+    if args.save {
+        if let Some(path) = save_selection(&args, agent, config, cli)? {
+            formatter.format_message(&format!("Saved index selection to {}", path.display()));
+        }
+    }
+    if !args.check && !dry_run {
+        formatter.format_success(&format!(
+            "Indexed {} skill(s) in {} file(s)",
+            skills.len(),
+            files.len()
+        ));
+    }
+    Ok(0)
+}
+
+/// Save an explicit `--agent` or `--file` selection to `[index]` in the
+/// project config, returning the config file if it changed.
+fn save_selection(
+    args: &SyncIndexArgs,
+    agent: Option<Agent>,
+    config: &Config,
+    cli: &Cli,
+) -> Result<Option<PathBuf>, SkiloError> {
+    let agent_changed = args.agent.is_some() && agent != config.index.agent;
+    let files_changed = !args.files.is_empty() && args.files != config.index.files;
+    if !agent_changed && !files_changed {
+        return Ok(None);
+    }
+
+    let path = match &cli.config {
+        Some(path) => path.clone(),
+        None => Config::project_config_paths(&args.path)
+            .pop()
+            .unwrap_or_else(|| args.path.join(CONFIG_FILES[0])),
+    };
+    let failed = |e: std::io::Error| SkiloError::Config(e.to_string());
+
+    if agent_changed {
+        match agent {
+            Some(agent) => {
+                let name = toml::Value::String(agent.config_name().to_string());
+                config::set_value(&path, "index.agent", &name).map_err(failed)?;
+            }
+            None => {
+                config::unset_value(&path, "index.agent").map_err(failed)?;
+            }
+        }
+    }
+    if files_changed {
+        let files = args
+            .files
+            .iter()
+            .map(|file| toml::Value::String(file.to_string_lossy().replace('\\', "/")))
+            .collect();
+        config::set_value(&path, "index.files", &toml::Value::Array(files)).map_err(failed)?;
+    }
+
+    Ok(Some(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use tempfile::TempDir;
+
+    fn sync_index(dir: &std::path::Path, extra: &[&str]) -> i32 {
+        let mut argv = vec!["skilo", "--quiet", "sync-index", dir.to_str().unwrap()];
+        argv.extend_from_slice(extra);
+        let cli = Cli::parse_from(argv);
+        let crate::cli::Command::SyncIndex(args) = &cli.command else {
+            unreachable!()
+        };
+        run(args.clone(), &Config::default(), &cli).unwrap()
+    }
+
+    #[test]
+    fn test_selection_is_only_saved_with_save() {
+        let temp = TempDir::new().unwrap();
+        let config = temp.path().join(CONFIG_FILES[0]);
+
+        assert_eq!(sync_index(temp.path(), &["--file", "CLAUDE.md"]), 0);
+        assert!(temp.path().join("CLAUDE.md").is_file());
+        assert!(!config.exists());
+
+        assert_eq!(
+            sync_index(temp.path(), &["--file", "CLAUDE.md", "--save"]),
+            0
+        );
+        let saved = std::fs::read_to_string(&config).unwrap();
+        assert!(saved.contains("CLAUDE.md"), "{}", saved);
+    }
+}
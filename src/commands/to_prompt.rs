@@ -1,46 +1,28 @@
-//! Generates XML for embedding skill information in agent prompts.
+//! Generates skill listings for embedding in agent prompts.
 
-use crate::cli::{Cli, ToPromptArgs};
+use crate::cli::{AgentSelection, Cli, OutputFormat, PathStyle, ToPromptArgs};
 use crate::config::Config;
 use crate::error::SkiloError;
+use crate::prompt::budget::{self, estimate_tokens, BudgetReport, Outcome};
+use crate::prompt::inline::{self, InlineOptions};
+use crate::prompt::location::Location;
+use crate::prompt::template::Template;
+use crate::prompt::{self, PromptSkill};
 use crate::skill::{Discovery, Manifest};
-use serde::Serialize;
 use std::path::PathBuf;
 
-/// Root element for XML output.
-#[derive(Serialize)]
-#[serde(rename = "available_skills")]
-struct AvailableSkills {
-    /// List of skills.
-    #[serde(rename = "skill")]
-    skills: Vec<SkillEntry>,
-}
-
-/// Represents a skill entry in XML output.
-#[derive(Serialize)]
-struct SkillEntry {
-    /// Skill name.
-    name: String,
-    /// Skill description.
-    description: String,
-    /// Path to the SKILL.md file.
-    location: String,
-}
-
-impl From<&Manifest> for SkillEntry {
-    fn from(manifest: &Manifest) -> Self {
-        Self {
-            name: manifest.frontmatter.name.clone(),
-            description: manifest.frontmatter.description.clone(),
-            location: manifest.path.display().to_string(),
-        }
-    }
-}
-
 /// Run the to-prompt command.
 ///
-/// Generates `<available_skills>` XML for agent prompts.
+/// Generates an `<available_skills>` block (or another format) for agent prompts.
 pub fn run(args: ToPromptArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError> {
+    // Fail on a broken template before doing any work
+    let template = match &args.template {
+        Some(path) => Some(Template::parse(&std::fs::read_to_string(path)?)?),
+        None => None,
+    };
+
+    let location = location(&args)?;
+
     // Collect all skill paths from all input paths
     let mut all_skill_paths: Vec<PathBuf> = Vec::new();
 
@@ -61,13 +43,24 @@ pub fn run(args: ToPromptArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloE
     }
 
     // Parse all skills and collect entries
-    let mut skills: Vec<SkillEntry> = Vec::new();
+    let inline_options = InlineOptions {
+        references: args.inline_references,
+        max_bytes: args.max_inline_bytes,
+    };
+    let mut skills: Vec<PromptSkill> = Vec::new();
     let mut errors: Vec<String> = Vec::new();
 
     for path in &all_skill_paths {
         match Manifest::parse(path.clone()) {
             Ok(manifest) => {
-                skills.push(SkillEntry::from(&manifest));
+                let mut skill = PromptSkill::from(&manifest);
+                skill.location = location.resolve(&manifest.path, &skill.name);
+                if args.inline {
+                    for warning in inline::inline(&mut skill, &manifest, inline_options) {
+                        eprintln!("Warning: {}", warning);
+                    }
+                }
+                skills.push(skill);
             }
             Err(e) => {
                 errors.push(format!("{}: {}", path.display(), e));
@@ -80,16 +73,37 @@ pub fn run(args: ToPromptArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloE
         eprintln!("Error: {}", error);
     }
 
-    // Generate and output XML
+    let render = |skills: &[PromptSkill]| match &template {
+        Some(template) => Ok(template.render(skills)),
+        None => prompt::render(skills, args.prompt_format),
+    };
+
+    // Trim to the token budget, if any
+    let max_tokens = args.max_tokens.or(config.prompt.max_tokens);
+    let (skills, report) = budget::fit(skills, max_tokens, &config.prompt.priority, |skills| {
+        render(skills)
+            .map(|output| estimate_tokens(&output))
+            .unwrap_or_default()
+    });
+
+    // Generate and output the listing
     if !cli.quiet {
-        let available_skills = AvailableSkills { skills };
-        let mut buffer = String::new();
-        let mut serializer = quick_xml::se::Serializer::new(&mut buffer);
-        serializer.indent(' ', 2);
-        available_skills
-            .serialize(serializer)
-            .map_err(|e| SkiloError::Config(format!("XML serialization failed: {}", e)))?;
-        println!("{}", buffer);
+        let output = render(&skills)?;
+        match &template {
+            // Templates control their own trailing whitespace
+            Some(_) => print!("{}", output),
+            None => println!("{}", output),
+        }
+    }
+
+    if args.report {
+        print_report(&report, cli)?;
+    } else if report.dropped() > 0 && !cli.quiet {
+        eprintln!(
+            "Dropped {} skill(s) to fit {} tokens (use --report for details)",
+            report.dropped(),
+            max_tokens.unwrap_or_default()
+        );
     }
 
     // Return error code if there were parsing failures
@@ -99,3 +113,74 @@ pub fn run(args: ToPromptArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloE
         Ok(1)
     }
 }
+
+/// Build the location style from `--path-style`, `--base-dir` and `--agent`.
+fn location(args: &ToPromptArgs) -> Result<Location, SkiloError> {
+    let style = match (args.path_style, &args.base_dir) {
+        (Some(style), _) => style,
+        (None, Some(_)) => PathStyle::Relative,
+        (None, None) => return Ok(Location::Verbatim),
+    };
+
+    Ok(match style {
+        PathStyle::Absolute => Location::Absolute,
+        PathStyle::Relative => {
+            Location::Relative(args.base_dir.clone().unwrap_or_else(|| PathBuf::from(".")))
+        }
+        PathStyle::Agent => match args.agent.as_ref().map(|a| a.to_selection()).transpose()? {
+            Some(AgentSelection::Single(agent)) => Location::Agent {
+                agent,
+                global: args.global,
+                base_dir: args.base_dir.clone(),
+            },
+            _ => {
+                return Err(SkiloError::Config(
+                    "--path-style agent needs a single --agent, not 'all'".to_string(),
+                ))
+            }
+        },
+    })
+}
+
+/// Print estimated token counts to stderr, as JSON with `--format json`.
+fn print_report(report: &BudgetReport, cli: &Cli) -> Result<(), SkiloError> {
+    if matches!(cli.format, OutputFormat::Json) {
+        let json = serde_json::to_string_pretty(report)
+            .map_err(|e| SkiloError::Config(format!("JSON serialization failed: {}", e)))?;
+        eprintln!("{}", json);
+        return Ok(());
+    }
+
+    let width = report
+        .skills
+        .iter()
+        .map(|s| s.tokens.to_string().len())
+        .max()
+        .unwrap_or(1);
+    for skill in &report.skills {
+        let tokens = match skill.outcome {
+            Outcome::Dropped => "-".to_string(),
+            _ => skill.tokens.to_string(),
+        };
+        let note = match skill.outcome {
+            Outcome::Kept => "",
+            Outcome::Shortened => " (shortened)",
+            Outcome::Dropped => " (dropped)",
+        };
+        eprintln!("{:>width$}  {}{}", tokens, skill.name, note, width = width);
+    }
+
+    let kept = report.skills.len() - report.dropped();
+    let budget = report
+        .max_tokens
+        .map(|max| format!(" / {}", max))
+        .unwrap_or_default();
+    eprintln!(
+        "Total: ~{}{} tokens ({} skill(s) included, {} dropped)",
+        report.total_tokens,
+        budget,
+        kept,
+        report.dropped()
+    );
+    Ok(())
+}
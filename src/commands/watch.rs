@@ -0,0 +1,249 @@
+//! Re-validates skills whenever their files change.
+
+use crate::cli::{Cli, WatchArgs};
+use crate::config::Config;
+use crate::error::SkiloError;
+use crate::skill::{Discovery, Formatter, FormatterConfig, Manifest, ValidationResult, Validator};
+use colored::Colorize;
+use notify_debouncer_full::notify::{EventKind, RecursiveMode};
+use notify_debouncer_full::{new_debouncer, DebounceEventResult};
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Lint and format status of a single skill.
+enum SkillStatus {
+    /// The manifest was parsed and checked.
+    Checked {
+        result: ValidationResult,
+        needs_format: bool,
+    },
+    /// The manifest could not be parsed.
+    Invalid(String),
+}
+
+impl SkillStatus {
+    fn is_clean(&self, strict: bool) -> bool {
+        match self {
+            Self::Checked {
+                result,
+                needs_format,
+            } => {
+                !needs_format
+                    && if strict {
+                        result.is_ok_strict()
+                    } else {
+                        result.is_ok()
+                    }
+            }
+            Self::Invalid(_) => false,
+        }
+    }
+}
+
+/// Run the watch command.
+///
+/// Checks every skill once, then re-checks the skills whose files change
+/// and redraws the report until interrupted.
+pub fn run(args: WatchArgs, config: &Config, cli: &Cli) -> Result<i32, SkiloError> {
+    let root = args
+        .path
+        .canonicalize()
+        .map_err(|_| SkiloError::NoSkillsFound {
+            path: args.path.display().to_string(),
+        })?;
+    let ignore = &config.discovery.ignore;
+    let strict = args.strict || config.lint.strict;
+
+    let validator = Validator::new(&config.lint);
+    let formatter = Formatter::new(FormatterConfig::from(&config.fmt));
+    let check = |path: &Path| check_skill(path, &validator, &formatter);
+
+    let mut skills: BTreeMap<PathBuf, SkillStatus> = Discovery::find_skills(&root, ignore)
+        .into_iter()
+        .map(|path| {
+            let status = check(&path);
+            (path, status)
+        })
+        .collect();
+
+    let (tx, rx) = mpsc::channel::<DebounceEventResult>();
+    let mut debouncer = new_debouncer(Duration::from_millis(args.debounce), None, tx)
+        .map_err(|e| SkiloError::Watch(e.to_string()))?;
+    debouncer
+        .watch(&root, RecursiveMode::Recursive)
+        .map_err(|e| SkiloError::Watch(e.to_string()))?;
+
+    redraw(&root, &skills, strict, cli.quiet);
+
+    for events in rx {
+        let events = match events {
+            Ok(events) => events,
+            Err(errors) => {
+                let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+                return Err(SkiloError::Watch(messages.join("; ")));
+            }
+        };
+
+        // Reading files while checking them produces access events; skip those
+        let changed: Vec<PathBuf> = events
+            .into_iter()
+            .filter(|event| !matches!(event.kind, EventKind::Access(_)))
+            .flat_map(|event| event.event.paths)
+            .filter(|path| !Discovery::is_ignored(&root, path, ignore))
+            .collect();
+        if changed.is_empty() {
+            continue;
+        }
+
+        // Pick up created and deleted skills, then re-check the affected ones
+        let current: HashSet<PathBuf> = Discovery::find_skills(&root, ignore).into_iter().collect();
+        let before = skills.len();
+        skills.retain(|path, _| current.contains(path));
+        let mut dirty = skills.len() != before;
+
+        for path in current {
+            let is_new = !skills.contains_key(&path);
+            if is_new || affects(&path, &changed) {
+                let status = check(&path);
+                skills.insert(path, status);
+                dirty = true;
+            }
+        }
+
+        if dirty {
+            redraw(&root, &skills, strict, cli.quiet);
+        }
+    }
+
+    Ok(0)
+}
+
+/// Check whether any of the changed paths lies inside the skill's directory.
+fn affects(manifest_path: &Path, changed: &[PathBuf]) -> bool {
+    let Some(skill_dir) = manifest_path.parent() else {
+        return false;
+    };
+    changed.iter().any(|path| path.starts_with(skill_dir))
+}
+
+fn check_skill(path: &Path, validator: &Validator, formatter: &Formatter) -> SkillStatus {
+    let manifest = match Manifest::parse(path.to_path_buf()) {
+        Ok(manifest) => manifest,
+        Err(e) => return SkillStatus::Invalid(e.to_string()),
+    };
+
+    let needs_format = match (formatter.format(&manifest), std::fs::read_to_string(path)) {
+        (Ok(formatted), Ok(current)) => formatted != current,
+        _ => false,
+    };
+
+    SkillStatus::Checked {
+        result: validator.validate(&manifest),
+        needs_format,
+    }
+}
+
+/// Clear the terminal and print a compact report of all skills.
+fn redraw(root: &Path, skills: &BTreeMap<PathBuf, SkillStatus>, strict: bool, quiet: bool) {
+    print!("\x1B[2J\x1B[H");
+
+    let mut failing = 0;
+    for (path, status) in skills {
+        if status.is_clean(strict) {
+            if !quiet {
+                println!("{} {}", "✓".green(), display_path(root, path));
+            }
+            continue;
+        }
+
+        failing += 1;
+        println!("{} {}", "✗".red(), display_path(root, path).bold());
+        match status {
+            SkillStatus::Checked {
+                result,
+                needs_format,
+            } => {
+                for diag in &result.errors {
+                    println!(
+                        "    {} {} {}",
+                        format!("[{}]", diag.code).red(),
+                        location(diag.line, diag.column).dimmed(),
+                        diag.message
+                    );
+                }
+                for diag in &result.warnings {
+                    println!(
+                        "    {} {} {}",
+                        format!("[{}]", diag.code).yellow(),
+                        location(diag.line, diag.column).dimmed(),
+                        diag.message
+                    );
+                }
+                if *needs_format {
+                    println!("    {} needs formatting", "[fmt]".yellow());
+                }
+            }
+            SkillStatus::Invalid(message) => {
+                println!("    {} {}", "error:".red(), message);
+            }
+        }
+    }
+
+    println!();
+    if failing == 0 {
+        println!(
+            "{} {} skill(s) checked, no issues found",
+            "✓".green().bold(),
+            skills.len()
+        );
+    } else {
+        println!(
+            "{} {} of {} skill(s) have issues",
+            "✗".red().bold(),
+            failing,
+            skills.len()
+        );
+    }
+    println!(
+        "{}",
+        format!("Watching {} for changes (Ctrl+C to stop)", root.display()).dimmed()
+    );
+}
+
+fn display_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+fn location(line: Option<usize>, column: Option<usize>) -> String {
+    match (line, column) {
+        (Some(line), Some(col)) => format!("{}:{}", line, col),
+        (Some(line), None) => format!("{}:", line),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_affects_only_files_in_skill_dir() {
+        let skill = Path::new("/repo/my-skill/SKILL.md");
+
+        assert!(affects(skill, &[PathBuf::from("/repo/my-skill/SKILL.md")]));
+        assert!(affects(
+            skill,
+            &[PathBuf::from("/repo/my-skill/scripts/run.sh")]
+        ));
+        assert!(!affects(
+            skill,
+            &[PathBuf::from("/repo/my-skill-2/SKILL.md")]
+        ));
+        assert!(!affects(skill, &[PathBuf::from("/repo/README.md")]));
+    }
+}
@@ -0,0 +1,919 @@
+//! Configuration file handling.
+//!
+//! Configuration is layered, later layers overriding earlier ones key by key:
+//!
+//! 1. Built-in defaults
+//! 2. The user config, `~/.skilo/config.toml`
+//! 3. Project configs, from the git root down to the current directory
+//!
+//! Each directory contributes at most one of [`CONFIG_FILES`]. Tables merge
+//! recursively; scalars and arrays are replaced as a whole, except
+//! `[[agents]]` entries, which are combined by name.
+
+pub mod schema;
+
+use crate::agent::{Agent, CustomAgent};
+use crate::cli::{ScriptLang, SyncMode, Template};
+use clap::ValueEnum;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::path::{Path, PathBuf};
+
+/// A configurable threshold that can be default, disabled, or a specific value.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Threshold {
+    /// Use the default value for this rule.
+    #[default]
+    Default,
+    /// Rule is disabled.
+    Disabled,
+    /// Rule is enabled with a specific value.
+    Value(usize),
+}
+
+impl Threshold {
+    /// Resolve the threshold to an `Option<usize>` given a default value.
+    pub fn resolve(self, default: usize) -> Option<usize> {
+        match self {
+            Self::Default => Some(default),
+            Self::Disabled => None,
+            Self::Value(n) => Some(n),
+        }
+    }
+}
+
+impl Serialize for Threshold {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Default => serializer.serialize_bool(true),
+            Self::Disabled => serializer.serialize_bool(false),
+            Self::Value(n) => serializer.serialize_u64(*n as u64),
+        }
+    }
+}
+
+fn deserialize_threshold<'de, D>(deserializer: D) -> Result<Threshold, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Value {
+        Bool(bool),
+        Number(usize),
+    }
+
+    match Value::deserialize(deserializer)? {
+        Value::Bool(false) => Ok(Threshold::Disabled),
+        Value::Bool(true) => Ok(Threshold::Default),
+        Value::Number(n) => Ok(Threshold::Value(n)),
+    }
+}
+
+/// Config file names looked for in each directory, in order of preference.
+pub const CONFIG_FILES: [&str; 3] = [".skilorc.toml", "skilo.toml", ".skilo/config.toml"];
+
+/// Top-level configuration.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Config {
+    /// Lint configuration.
+    pub lint: LintConfig,
+    /// Format configuration.
+    pub fmt: FmtConfig,
+    /// New command configuration.
+    pub new: NewConfig,
+    /// Add command configuration.
+    pub add: AddConfig,
+    /// Discovery configuration.
+    pub discovery: DiscoveryConfig,
+    /// To-prompt command configuration.
+    pub prompt: PromptConfig,
+    /// Sync command configuration.
+    pub sync: SyncConfig,
+    /// Sync-index command configuration, also used by `check`.
+    pub index: IndexConfig,
+    /// Git cache configuration.
+    pub cache: CacheConfig,
+    /// Agents defined in config, in addition to the built-in ones.
+    pub agents: Vec<CustomAgent>,
+}
+
+/// Configuration for the lint command.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct LintConfig {
+    /// Treat warnings as errors.
+    pub strict: bool,
+    /// Agents every skill should be compatible with (W005).
+    pub target_agents: Vec<Agent>,
+    /// Rule-specific configuration.
+    pub rules: RulesConfig,
+}
+
+/// Configuration for individual lint rules.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RulesConfig {
+    /// Enable name format validation (E001).
+    pub name_format: bool,
+    /// Maximum name length (E002).
+    #[serde(deserialize_with = "deserialize_threshold")]
+    pub name_length: Threshold,
+    /// Enable name/directory match validation (E003).
+    pub name_directory: bool,
+    /// Require description (E004).
+    pub description_required: bool,
+    /// Maximum description length (E005).
+    #[serde(deserialize_with = "deserialize_threshold")]
+    pub description_length: Threshold,
+    /// Maximum compatibility length (E006).
+    #[serde(deserialize_with = "deserialize_threshold")]
+    pub compatibility_length: Threshold,
+    /// Validate referenced files exist (E009).
+    pub references_exist: bool,
+    /// Maximum body length in lines (W001).
+    #[serde(deserialize_with = "deserialize_threshold")]
+    pub body_length: Threshold,
+    /// Check scripts are executable (W002).
+    pub script_executable: bool,
+    /// Check scripts have shebang (W003).
+    pub script_shebang: bool,
+}
+
+impl Default for RulesConfig {
+    fn default() -> Self {
+        Self {
+            name_format: true,
+            name_length: Threshold::Default,
+            name_directory: true,
+            description_required: true,
+            description_length: Threshold::Default,
+            compatibility_length: Threshold::Default,
+            references_exist: true,
+            body_length: Threshold::Default,
+            script_executable: true,
+            script_shebang: true,
+        }
+    }
+}
+
+/// Configuration for the fmt command.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FmtConfig {
+    /// Sort frontmatter keys.
+    pub sort_frontmatter: bool,
+    /// Indentation size.
+    pub indent_size: usize,
+    /// Format markdown tables.
+    pub format_tables: bool,
+}
+
+impl Default for FmtConfig {
+    fn default() -> Self {
+        Self {
+            sort_frontmatter: true,
+            indent_size: 2,
+            format_tables: true,
+        }
+    }
+}
+
+/// Configuration for the new command.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct NewConfig {
+    /// Default license for new skills.
+    pub default_license: Option<String>,
+    /// Default template for new skills: a built-in name, a directory or `owner/repo#dir`.
+    #[serde(deserialize_with = "deserialize_template")]
+    pub default_template: Option<String>,
+    /// Default script language for new skills.
+    pub default_lang: Option<ScriptLang>,
+}
+
+/// Accept built-in template names and anything that looks like a path or repository.
+fn deserialize_template<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let spec = String::deserialize(deserializer)?;
+    let is_builtin = Template::from_str(&spec, false).is_ok();
+    let is_location = spec.contains('/') || spec.starts_with('.') || spec.starts_with('~');
+    if is_builtin || is_location {
+        return Ok(Some(spec));
+    }
+
+    let builtin: Vec<String> = Template::value_variants()
+        .iter()
+        .filter_map(|t| t.to_possible_value())
+        .map(|v| v.get_name().to_string())
+        .collect();
+    Err(serde::de::Error::custom(format!(
+        "unknown template '{}', expected one of {}, a directory or owner/repo#dir",
+        spec,
+        builtin.join(", ")
+    )))
+}
+
+/// Configuration for the add command.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct AddConfig {
+    /// Target agent for skill installation. If None, installs to ./skills/ in current directory.
+    pub default_agent: Option<Agent>,
+    /// Prompt before installing (false for CI).
+    pub confirm: bool,
+    /// Validate skills before installing.
+    pub validate: bool,
+}
+
+impl Default for AddConfig {
+    fn default() -> Self {
+        Self {
+            default_agent: None,
+            confirm: true,
+            validate: true,
+        }
+    }
+}
+
+/// Configuration for skill discovery.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct DiscoveryConfig {
+    /// Glob patterns for directories to ignore during skill discovery.
+    ///
+    /// Patterns follow `.gitignore` style glob syntax:
+    /// - `target` - match directory named "target" at any depth
+    /// - `build-*` - match directories starting with "build-"
+    /// - `*.tmp` - match directories ending with ".tmp"
+    /// - `foo/bar` - match path "foo/bar" relative to search root
+    /// - `**/cache` - match "cache" directory at any depth
+    pub ignore: Vec<String>,
+}
+
+/// Configuration for the to-prompt command.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PromptConfig {
+    /// Token budget for generated prompts.
+    pub max_tokens: Option<usize>,
+    /// Skills to keep first when trimming to the budget, most important first.
+    pub priority: Vec<String>,
+}
+
+/// Configuration for the sync command.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct SyncConfig {
+    /// Agent whose skills directory is the source of truth. If None, syncs from ./skills/.
+    pub from: Option<Agent>,
+    /// Agents to keep in sync. If empty, syncs every detected agent.
+    pub agents: Vec<Agent>,
+    /// Copy skills or symlink them to the source.
+    pub mode: SyncMode,
+}
+
+/// Configuration for the sync-index command, also used by `check`.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct IndexConfig {
+    /// Only list skills installed for this agent. If None, lists skills of every agent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent: Option<Agent>,
+    /// Instruction files holding the index, relative to the project. If empty,
+    /// uses the existing AGENTS.md, CLAUDE.md and GEMINI.md.
+    pub files: Vec<PathBuf>,
+}
+
+/// Configuration for the git cache.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CacheConfig {
+    /// Size budget in bytes; least recently used entries are evicted beyond it.
+    #[serde(deserialize_with = "deserialize_size")]
+    pub max_size: Option<u64>,
+}
+
+/// Accept a number of bytes or a size with a unit, such as `"500MB"`.
+fn deserialize_size<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Value {
+        Bytes(u64),
+        Text(String),
+    }
+
+    match Value::deserialize(deserializer)? {
+        Value::Bytes(n) => Ok(Some(n)),
+        Value::Text(text) => crate::cache::parse_size(&text).map(Some).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "invalid size '{}', expected bytes or a size such as \"500MB\" or \"2GB\"",
+                text
+            ))
+        }),
+    }
+}
+
+/// The effective value of one config key.
+#[derive(Debug, Clone)]
+pub struct ConfigEntry {
+    /// Dotted key, e.g. `lint.rules.body_length`.
+    pub key: String,
+    /// Effective value.
+    pub value: toml::Value,
+    /// File that set the value, or `None` for a built-in default.
+    pub origin: Option<PathBuf>,
+}
+
+impl ConfigEntry {
+    /// Where the value came from: a file path, or `default`.
+    pub fn origin_label(&self) -> String {
+        self.origin
+            .as_ref()
+            .map_or_else(|| "default".to_string(), |p| p.display().to_string())
+    }
+}
+
+impl Config {
+    /// Load configuration from a file or find it automatically.
+    ///
+    /// An explicit `path` is used on its own. Otherwise the files returned by
+    /// [`Config::sources`] are layered key by key.
+    pub fn load(path: Option<&PathBuf>) -> std::result::Result<Self, std::io::Error> {
+        Self::load_layers(&Self::sources(path))
+    }
+
+    /// Config files to load, lowest precedence first.
+    ///
+    /// An explicit `path` is the only source. Otherwise these are the user
+    /// config followed by the project configs for the current directory.
+    pub fn sources(path: Option<&PathBuf>) -> Vec<PathBuf> {
+        if let Some(path) = path {
+            return vec![path.clone()];
+        }
+
+        let mut sources: Vec<PathBuf> = Self::user_config_path()
+            .into_iter()
+            .filter(|p| p.is_file())
+            .collect();
+        if let Ok(cwd) = std::env::current_dir() {
+            for path in Self::project_config_paths(&cwd) {
+                let is_user = sources.first().is_some_and(|user| same_file(user, &path));
+                if !is_user {
+                    sources.push(path);
+                }
+            }
+        }
+        sources
+    }
+
+    /// Path of the user config file (`~/.skilo/config.toml`).
+    pub fn user_config_path() -> Option<PathBuf> {
+        crate::cache::skilo_home().map(|home| home.join("config.toml"))
+    }
+
+    /// Project config files from the git root down to `dir`, outermost first.
+    ///
+    /// Outside a git repository only `dir` itself is searched.
+    pub fn project_config_paths(dir: &Path) -> Vec<PathBuf> {
+        let mut dirs: Vec<&Path> = Vec::new();
+        let mut in_repo = false;
+        for ancestor in dir.ancestors() {
+            dirs.push(ancestor);
+            if ancestor.join(".git").exists() {
+                in_repo = true;
+                break;
+            }
+        }
+        if !in_repo {
+            dirs.truncate(1);
+        }
+
+        dirs.iter()
+            .rev()
+            .filter_map(|dir| {
+                CONFIG_FILES
+                    .iter()
+                    .map(|name| dir.join(name))
+                    .find(|path| path.is_file())
+            })
+            .collect()
+    }
+
+    /// The effective value of every set key, with the file it came from.
+    pub fn entries(sources: &[PathBuf]) -> std::result::Result<Vec<ConfigEntry>, std::io::Error> {
+        let config = Self::load_layers(sources)?;
+        let effective = toml::Table::try_from(&config)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))?;
+
+        let mut origins = std::collections::HashMap::new();
+        for path in sources.iter().filter(|p| p.exists()) {
+            let table: toml::Table = toml::from_str(&std::fs::read_to_string(path)?)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))?;
+            for (key, _) in flatten(&table, "") {
+                origins.insert(key, path.clone());
+            }
+        }
+
+        Ok(flatten(&effective, "")
+            .into_iter()
+            .map(|(key, value)| ConfigEntry {
+                origin: origins.get(&key).cloned(),
+                key,
+                value,
+            })
+            .collect())
+    }
+
+    /// The agents defined in `[[agents]]` across the config files for `path`.
+    ///
+    /// Entries in later files replace earlier ones with the same name. These
+    /// are registered with [`Agent::register`] before the config is loaded,
+    /// so that other settings can refer to them.
+    pub fn custom_agents(
+        path: Option<&PathBuf>,
+    ) -> std::result::Result<Vec<CustomAgent>, std::io::Error> {
+        merge_agents(&read_layers(&Self::sources(path))?)
+    }
+
+    /// Merge the config files in `paths`, later files taking precedence.
+    ///
+    /// Each file is validated on its own so errors name the offending file.
+    fn load_layers<P: AsRef<Path>>(paths: &[P]) -> std::result::Result<Self, std::io::Error> {
+        let layers = read_layers(paths)?;
+        let agents = merge_agents(&layers)?;
+
+        let mut merged = toml::Table::new();
+        for (path, content, mut table) in layers {
+            toml::from_str::<Self>(&content).map_err(|e| invalid(&path, e))?;
+            if let Some(unknown) = schema::unknown_keys(&table).first() {
+                return Err(invalid(&path, unknown));
+            }
+            table.remove("agents");
+            if let Some(dir) = path.parent() {
+                resolve_template_dir(&mut table, dir);
+            }
+            merge_tables(&mut merged, table);
+        }
+
+        let mut config = Self::deserialize(toml::Value::Table(merged))
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))?;
+        config.agents = agents;
+        Ok(config)
+    }
+}
+
+/// Build an `InvalidData` error naming the config file at `path`.
+fn invalid(path: &Path, e: impl std::fmt::Display) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("{}: {}", path.display(), e),
+    )
+}
+
+/// Read and parse the existing config files in `paths`, in order.
+fn read_layers<P: AsRef<Path>>(
+    paths: &[P],
+) -> std::result::Result<Vec<(PathBuf, String, toml::Table)>, std::io::Error> {
+    let mut layers = Vec::new();
+    for path in paths {
+        let path = path.as_ref();
+        if !path.exists() {
+            continue;
+        }
+        let content = std::fs::read_to_string(path)?;
+        let table: toml::Table = toml::from_str(&content).map_err(|e| invalid(path, e))?;
+        layers.push((path.to_path_buf(), content, table));
+    }
+    Ok(layers)
+}
+
+/// Merge and validate the `[[agents]]` entries of `layers` by name.
+fn merge_agents(
+    layers: &[(PathBuf, String, toml::Table)],
+) -> std::result::Result<Vec<CustomAgent>, std::io::Error> {
+    let mut agents: Vec<CustomAgent> = Vec::new();
+    for (path, _, table) in layers {
+        let Some(entries) = table.get("agents") else {
+            continue;
+        };
+        let entries =
+            Vec::<CustomAgent>::deserialize(entries.clone()).map_err(|e| invalid(path, e))?;
+        for agent in entries {
+            agent
+                .validate()
+                .map_err(|e| invalid(path, format!("[[agents]]: {}", e)))?;
+            agents.retain(|a| a.name != agent.name);
+            agents.push(agent);
+        }
+    }
+    Ok(agents)
+}
+
+/// Make a relative `new.default_template` directory relative to `dir`, the
+/// directory of the config file that sets it, instead of the working directory.
+fn resolve_template_dir(table: &mut toml::Table, dir: &Path) {
+    let Some(toml::Value::String(spec)) = table
+        .get_mut("new")
+        .and_then(|new| new.as_table_mut())
+        .and_then(|new| new.get_mut("default_template"))
+    else {
+        return;
+    };
+    if Template::from_str(spec, false).is_ok() || spec.starts_with('~') {
+        return;
+    }
+
+    let resolved = dir.join(&*spec);
+    if Path::new(spec).is_relative() && (spec.starts_with('.') || resolved.is_dir()) {
+        *spec = resolved.to_string_lossy().into_owned();
+    }
+}
+
+/// Problems in the config file at `path`: unknown keys and invalid values.
+pub fn check_file(path: &Path) -> std::result::Result<Vec<String>, std::io::Error> {
+    check_content(&std::fs::read_to_string(path)?)
+        .map(|issues| issues.into_iter().map(|i| i.trim().to_string()).collect())
+}
+
+/// Set `key` to `value` in the config file at `path`, keeping its formatting.
+///
+/// The file is created if needed and left untouched if the result is invalid.
+pub fn set_value(
+    path: &Path,
+    key: &str,
+    value: &toml::Value,
+) -> std::result::Result<(), std::io::Error> {
+    let mut document = read_document(path)?;
+    let before = document.to_string();
+    let parts: Vec<&str> = key.split('.').collect();
+    let (name, sections) = parts.split_last().unwrap_or((&"", &[]));
+
+    let mut table = document.as_table_mut();
+    for section in sections {
+        let entry = table.entry(section).or_insert_with(|| {
+            let mut table = toml_edit::Table::new();
+            table.set_implicit(true);
+            toml_edit::Item::Table(table)
+        });
+        table = entry.as_table_mut().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("{}: `{}` is not a table", path.display(), section),
+            )
+        })?;
+    }
+
+    let literal: toml_edit::DocumentMut = format!("value = {}", value)
+        .parse()
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, format!("{}", e)))?;
+    table.insert(name, literal["value"].clone());
+    write_document(path, &before, &document)
+}
+
+/// Remove `key` from the config file at `path`, returning false if it was not set.
+pub fn unset_value(path: &Path, key: &str) -> std::result::Result<bool, std::io::Error> {
+    if !path.exists() {
+        return Ok(false);
+    }
+
+    let mut document = read_document(path)?;
+    let before = document.to_string();
+    let parts: Vec<&str> = key.split('.').collect();
+    let (name, sections) = parts.split_last().unwrap_or((&"", &[]));
+
+    let mut table = document.as_table_mut();
+    for section in sections {
+        match table
+            .get_mut(section)
+            .and_then(toml_edit::Item::as_table_mut)
+        {
+            Some(child) => table = child,
+            None => return Ok(false),
+        }
+    }
+    if table.remove(name).is_none() {
+        return Ok(false);
+    }
+
+    write_document(path, &before, &document)?;
+    Ok(true)
+}
+
+fn read_document(path: &Path) -> std::result::Result<toml_edit::DocumentMut, std::io::Error> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    content.parse().map_err(|e| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })
+}
+
+/// Write `document` to `path`, unless it has problems that `before` did not.
+fn write_document(
+    path: &Path,
+    before: &str,
+    document: &toml_edit::DocumentMut,
+) -> std::result::Result<(), std::io::Error> {
+    let content = document.to_string();
+    let existing = check_content(before)?;
+    let issues = check_content(&content)?;
+    if let Some(issue) = issues.iter().find(|issue| !existing.contains(issue)) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            issue.trim().to_string(),
+        ));
+    }
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, content)
+}
+
+/// Problems in config file `content`: unknown keys, then invalid values.
+fn check_content(content: &str) -> std::result::Result<Vec<String>, std::io::Error> {
+    let table: toml::Table = match toml::from_str(content) {
+        Ok(table) => table,
+        Err(e) => return Ok(vec![e.to_string()]),
+    };
+
+    let mut issues: Vec<String> = schema::unknown_keys(&table)
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    match toml::from_str::<Config>(content) {
+        Ok(config) => issues.extend(
+            config
+                .agents
+                .iter()
+                .filter_map(|agent| agent.validate().err())
+                .map(|e| format!("[[agents]]: {}", e)),
+        ),
+        Err(e) => issues.push(e.to_string()),
+    }
+    Ok(issues)
+}
+
+/// Returns true if `a` and `b` name the same existing file.
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Leaf values of `table` keyed by dotted path, in key order.
+fn flatten(table: &toml::Table, prefix: &str) -> Vec<(String, toml::Value)> {
+    let mut entries = Vec::new();
+    for (key, value) in table {
+        let key = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match value {
+            toml::Value::Table(table) => entries.extend(flatten(table, &key)),
+            value => entries.push((key, value.clone())),
+        }
+    }
+    entries
+}
+
+/// Recursively merge `overlay` into `base`, overlay values winning.
+fn merge_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(overlay)) => {
+                merge_tables(base, overlay)
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_project_config_overrides_user_config() {
+        let temp = TempDir::new().unwrap();
+        let user = temp.path().join("user.toml");
+        let project = temp.path().join("project.toml");
+        std::fs::write(
+            &user,
+            "[new]\ndefault_license = \"MIT\"\ndefault_lang = \"bash\"\n",
+        )
+        .unwrap();
+        std::fs::write(&project, "[new]\ndefault_lang = \"typescript\"\n").unwrap();
+
+        let config = Config::load_layers(&[&user, &project]).unwrap();
+        assert_eq!(config.new.default_license.as_deref(), Some("MIT"));
+        assert_eq!(config.new.default_lang, Some(ScriptLang::Typescript));
+        assert_eq!(config.new.default_template, None);
+    }
+
+    #[test]
+    fn test_invalid_values_name_the_config_file() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".skilorc.toml");
+
+        std::fs::write(&path, "[new]\ndefault_lang = \"cobol\"\n").unwrap();
+        let err = Config::load_layers(&[&path]).unwrap_err().to_string();
+        assert!(err.starts_with(&path.display().to_string()));
+        assert!(err.contains("cobol"));
+
+        std::fs::write(&path, "[new]\ndefault_template = \"hello-wrld\"\n").unwrap();
+        let err = Config::load_layers(&[&path]).unwrap_err().to_string();
+        assert!(err.contains("unknown template 'hello-wrld'"));
+
+        std::fs::write(&path, "[new]\ndefault_template = \"acme/templates#api\"\n").unwrap();
+        let config = Config::load_layers(&[&path]).unwrap();
+        assert_eq!(
+            config.new.default_template.as_deref(),
+            Some("acme/templates#api")
+        );
+    }
+
+    #[test]
+    fn test_relative_template_resolves_against_config_dir() {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join("templates/api")).unwrap();
+        let path = temp.path().join(".skilorc.toml");
+
+        std::fs::write(&path, "[new]\ndefault_template = \"templates/api\"\n").unwrap();
+        let config = Config::load_layers(&[&path]).unwrap();
+        assert_eq!(
+            config.new.default_template.map(PathBuf::from),
+            Some(temp.path().join("templates/api"))
+        );
+
+        std::fs::write(&path, "[new]\ndefault_template = \"./other\"\n").unwrap();
+        let config = Config::load_layers(&[&path]).unwrap();
+        assert_eq!(
+            config.new.default_template.map(PathBuf::from),
+            Some(temp.path().join("./other"))
+        );
+
+        // Built-in names and repositories are left alone
+        for spec in ["hello-world", "acme/templates#api"] {
+            std::fs::write(&path, format!("[new]\ndefault_template = \"{}\"\n", spec)).unwrap();
+            let config = Config::load_layers(&[&path]).unwrap();
+            assert_eq!(config.new.default_template.as_deref(), Some(spec));
+        }
+    }
+
+    #[test]
+    fn test_project_configs_walk_up_to_git_root() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().join("repo");
+        let nested = root.join("packages/api");
+        std::fs::create_dir_all(root.join(".git")).unwrap();
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(temp.path().join(".skilorc.toml"), "").unwrap();
+        std::fs::write(root.join(".skilorc.toml"), "[lint]\nstrict = true\n").unwrap();
+        std::fs::write(root.join("skilo.toml"), "").unwrap();
+        std::fs::write(
+            nested.join("skilo.toml"),
+            "[lint.rules]\nbody_length = 200\n",
+        )
+        .unwrap();
+
+        let paths = Config::project_config_paths(&nested);
+        assert_eq!(
+            paths,
+            vec![root.join(".skilorc.toml"), nested.join("skilo.toml")]
+        );
+
+        let entries = Config::entries(&paths).unwrap();
+        let entry = |key: &str| entries.iter().find(|e| e.key == key).unwrap();
+        assert_eq!(entry("lint.strict").value, toml::Value::Boolean(true));
+        assert_eq!(
+            entry("lint.strict").origin,
+            Some(root.join(".skilorc.toml"))
+        );
+        assert_eq!(
+            entry("lint.rules.body_length").value,
+            toml::Value::Integer(200)
+        );
+        assert_eq!(
+            entry("lint.rules.body_length").origin,
+            Some(nested.join("skilo.toml"))
+        );
+        assert_eq!(entry("fmt.indent_size").origin, None);
+    }
+
+    #[test]
+    fn test_project_configs_outside_git_repo() {
+        let temp = TempDir::new().unwrap();
+        let nested = temp.path().join("nested");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(temp.path().join("skilo.toml"), "").unwrap();
+
+        assert!(Config::project_config_paths(&nested).is_empty());
+        assert_eq!(
+            Config::project_config_paths(temp.path()),
+            vec![temp.path().join("skilo.toml")]
+        );
+    }
+
+    #[test]
+    fn test_custom_agents_merge_by_name() {
+        let temp = TempDir::new().unwrap();
+        let user = temp.path().join("user.toml");
+        let project = temp.path().join("project.toml");
+        std::fs::write(
+            &user,
+            "[[agents]]\nname = \"acme\"\nskills_dir = \".acme/skills\"\nglobal_skills_dir = \"~/.acme/skills\"\n\n[[agents]]\nname = \"other\"\nskills_dir = \".other/skills\"\nglobal_skills_dir = \"~/.other/skills\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            &project,
+            "[[agents]]\nname = \"acme\"\ndisplay_name = \"Acme\"\nskills_dir = \".acme/skills\"\nglobal_skills_dir = \"~/.acme/skills\"\nfeatures = { hooks = true }\n",
+        )
+        .unwrap();
+
+        let config = Config::load_layers(&[&user, &project]).unwrap();
+        let names: Vec<&str> = config.agents.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["other", "acme"]);
+        assert_eq!(config.agents[1].display_name.as_deref(), Some("Acme"));
+        assert!(config.agents[1].features.hooks && config.agents[1].features.scripts);
+    }
+
+    #[test]
+    fn test_registered_agents_resolve_by_name() {
+        // The only test that registers agents: the set is fixed per process
+        let agent = CustomAgent {
+            name: "acme".to_string(),
+            display_name: Some("Acme".to_string()),
+            skills_dir: ".acme/skills".to_string(),
+            global_skills_dir: "~/.acme/skills".to_string(),
+            ..Default::default()
+        };
+        Agent::register(vec![agent]).unwrap();
+        assert!(Agent::register(Vec::new()).is_err());
+
+        let config: Config = toml::from_str("[add]\ndefault_agent = \"acme\"\n").unwrap();
+        let acme = config.add.default_agent.unwrap();
+        assert_eq!(acme.display_name(), "Acme");
+        assert_eq!(acme.skills_dir(), ".acme/skills");
+        assert_eq!(Agent::from_name("acme"), Some(acme));
+        assert_eq!(Agent::all().last(), Some(&acme));
+    }
+
+    #[test]
+    fn test_custom_agent_dirs_stay_inside_their_base() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".skilorc.toml");
+        for (skills_dir, global_skills_dir) in [
+            ("", "~/.acme/skills"),
+            ("/etc/acme", "~/.acme/skills"),
+            ("../acme/skills", "~/.acme/skills"),
+            (".acme/../../skills", "~/.acme/skills"),
+            (".", "~/.acme/skills"),
+            (".acme/skills", "/opt/acme"),
+            (".acme/skills", "~"),
+            (".acme/skills", "~/../acme"),
+        ] {
+            std::fs::write(
+                &path,
+                format!(
+                    "[[agents]]\nname = \"acme\"\nskills_dir = \"{}\"\nglobal_skills_dir = \"{}\"\n",
+                    skills_dir, global_skills_dir
+                ),
+            )
+            .unwrap();
+            let err = Config::load_layers(&[&path]).unwrap_err().to_string();
+            assert!(err.contains("agent 'acme'"), "{}: {}", skills_dir, err);
+            assert_eq!(check_file(&path).unwrap().len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_custom_agents_cannot_shadow_builtin_agents() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".skilorc.toml");
+        std::fs::write(
+            &path,
+            "[[agents]]\nname = \"claude\"\nskills_dir = \"x\"\nglobal_skills_dir = \"~/x\"\n",
+        )
+        .unwrap();
+
+        let err = Config::load_layers(&[&path]).unwrap_err().to_string();
+        assert!(err.contains("already used by a built-in agent"));
+    }
+}
@@ -0,0 +1,748 @@
+//! JSON Schema for config files.
+//!
+//! The schema is built from the config types, with defaults taken from their
+//! `Default` implementations. Besides editor completion it is used to reject
+//! unknown keys and to parse values given to `skilo config set`.
+
+use super::{
+    AddConfig, CacheConfig, Config, DiscoveryConfig, FmtConfig, IndexConfig, LintConfig, NewConfig,
+    PromptConfig, RulesConfig, SyncConfig,
+};
+use crate::agent::{Agent, AgentFeatures, CustomAgent};
+use crate::cli::{ScriptLang, SyncMode, Template};
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+/// A config section that describes itself as JSON Schema.
+pub trait ConfigSchema {
+    /// JSON Schema of the section.
+    fn schema() -> Value;
+}
+
+impl ConfigSchema for Config {
+    fn schema() -> Value {
+        object::<Self>(vec![
+            ("lint", "Lint configuration.", LintConfig::schema()),
+            ("fmt", "Format configuration.", FmtConfig::schema()),
+            ("new", "New command configuration.", NewConfig::schema()),
+            ("add", "Add command configuration.", AddConfig::schema()),
+            (
+                "discovery",
+                "Discovery configuration.",
+                DiscoveryConfig::schema(),
+            ),
+            (
+                "prompt",
+                "To-prompt command configuration.",
+                PromptConfig::schema(),
+            ),
+            ("sync", "Sync command configuration.", SyncConfig::schema()),
+            (
+                "index",
+                "Sync-index command configuration, also used by `check`.",
+                IndexConfig::schema(),
+            ),
+            ("cache", "Git cache configuration.", CacheConfig::schema()),
+            (
+                "agents",
+                "Agents defined in config, in addition to the built-in ones.",
+                json!({ "type": "array", "items": CustomAgent::schema() }),
+            ),
+        ])
+    }
+}
+
+impl ConfigSchema for CustomAgent {
+    fn schema() -> Value {
+        let mut schema = object::<Self>(vec![
+            (
+                "name",
+                "Name used with `--agent` and in config.",
+                json!({ "type": "string", "pattern": "^[a-z0-9-]+$" }),
+            ),
+            (
+                "display_name",
+                "Name shown in listings (defaults to `name`).",
+                json!({ "type": "string" }),
+            ),
+            (
+                "skills_dir",
+                "Project-level skills directory, relative to the project root.",
+                json!({ "type": "string" }),
+            ),
+            (
+                "global_skills_dir",
+                "Global skills directory; `~/` expands to the home directory.",
+                json!({ "type": "string" }),
+            ),
+            (
+                "detection_dir",
+                "Directory whose presence in a project means the agent is used (defaults to `skills_dir`).",
+                json!({ "type": "string" }),
+            ),
+            (
+                "global_detection_dir",
+                "Directory whose presence means the agent is installed globally (defaults to `global_skills_dir`).",
+                json!({ "type": "string" }),
+            ),
+            ("features", "Supported skill features.", AgentFeatures::schema()),
+        ]);
+        schema["required"] = json!(["name", "skills_dir", "global_skills_dir"]);
+        schema
+    }
+}
+
+impl ConfigSchema for AgentFeatures {
+    fn schema() -> Value {
+        object::<Self>(vec![
+            (
+                "context_fork",
+                "Supports `context: fork` in SKILL.md.",
+                boolean(),
+            ),
+            ("hooks", "Supports hooks.", boolean()),
+            (
+                "allowed_tools",
+                "Supports the `allowed-tools` field.",
+                boolean(),
+            ),
+            ("scripts", "Supports scripts.", boolean()),
+            (
+                "script_langs",
+                "Script languages the agent can run. If unset, any language.",
+                json!({ "type": "array", "items": { "enum": variants(ScriptLang::value_variants()) } }),
+            ),
+        ])
+    }
+}
+
+impl ConfigSchema for LintConfig {
+    fn schema() -> Value {
+        object::<Self>(vec![
+            ("strict", "Treat warnings as errors.", boolean()),
+            (
+                "target_agents",
+                "Agents every skill should be compatible with (W005).",
+                json!({ "type": "array", "items": agent() }),
+            ),
+            (
+                "rules",
+                "Rule-specific configuration.",
+                RulesConfig::schema(),
+            ),
+        ])
+    }
+}
+
+impl ConfigSchema for RulesConfig {
+    fn schema() -> Value {
+        object::<Self>(vec![
+            (
+                "name_format",
+                "Enable name format validation (E001).",
+                boolean(),
+            ),
+            ("name_length", "Maximum name length (E002).", threshold()),
+            (
+                "name_directory",
+                "Enable name/directory match validation (E003).",
+                boolean(),
+            ),
+            (
+                "description_required",
+                "Require description (E004).",
+                boolean(),
+            ),
+            (
+                "description_length",
+                "Maximum description length (E005).",
+                threshold(),
+            ),
+            (
+                "compatibility_length",
+                "Maximum compatibility length (E006).",
+                threshold(),
+            ),
+            (
+                "references_exist",
+                "Validate referenced files exist (E009).",
+                boolean(),
+            ),
+            (
+                "body_length",
+                "Maximum body length in lines (W001).",
+                threshold(),
+            ),
+            (
+                "script_executable",
+                "Check scripts are executable (W002).",
+                boolean(),
+            ),
+            (
+                "script_shebang",
+                "Check scripts have shebang (W003).",
+                boolean(),
+            ),
+        ])
+    }
+}
+
+impl ConfigSchema for FmtConfig {
+    fn schema() -> Value {
+        object::<Self>(vec![
+            ("sort_frontmatter", "Sort frontmatter keys.", boolean()),
+            ("indent_size", "Indentation size.", integer()),
+            ("format_tables", "Format markdown tables.", boolean()),
+        ])
+    }
+}
+
+impl ConfigSchema for NewConfig {
+    fn schema() -> Value {
+        let builtin: Vec<Value> = Template::value_variants()
+            .iter()
+            .filter_map(|t| t.to_possible_value())
+            .map(|v| v.get_name().into())
+            .collect();
+        let templates = json!({ "enum": builtin });
+        let locations = json!({ "type": "string", "pattern": "[/.~]" });
+        object::<Self>(vec![
+            (
+                "default_license",
+                "Default license for new skills.",
+                json!({ "type": "string" }),
+            ),
+            (
+                "default_template",
+                "Default template for new skills: a built-in name, a directory or `owner/repo#dir`.",
+                json!({ "anyOf": [templates, locations] }),
+            ),
+            (
+                "default_lang",
+                "Default script language for new skills.",
+                json!({ "enum": variants(ScriptLang::value_variants()) }),
+            ),
+        ])
+    }
+}
+
+impl ConfigSchema for AddConfig {
+    fn schema() -> Value {
+        object::<Self>(vec![
+            (
+                "default_agent",
+                "Target agent for skill installation: a built-in agent or one from `[[agents]]`. If unset, installs to ./skills/ in the current directory.",
+                agent(),
+            ),
+            ("confirm", "Prompt before installing (false for CI).", boolean()),
+            ("validate", "Validate skills before installing.", boolean()),
+        ])
+    }
+}
+
+impl ConfigSchema for DiscoveryConfig {
+    fn schema() -> Value {
+        object::<Self>(vec![(
+            "ignore",
+            "Glob patterns for directories to ignore during skill discovery.",
+            strings(),
+        )])
+    }
+}
+
+impl ConfigSchema for PromptConfig {
+    fn schema() -> Value {
+        object::<Self>(vec![
+            (
+                "max_tokens",
+                "Token budget for generated prompts.",
+                integer(),
+            ),
+            (
+                "priority",
+                "Skills to keep first when trimming to the budget, most important first.",
+                strings(),
+            ),
+        ])
+    }
+}
+
+impl ConfigSchema for SyncConfig {
+    fn schema() -> Value {
+        object::<Self>(vec![
+            (
+                "from",
+                "Agent whose skills directory is the source of truth. If unset, syncs from ./skills/.",
+                agent(),
+            ),
+            (
+                "agents",
+                "Agents to keep in sync. If empty, syncs every detected agent.",
+                json!({ "type": "array", "items": agent() }),
+            ),
+            (
+                "mode",
+                "Copy skills or symlink them to the source.",
+                json!({ "enum": variants(SyncMode::value_variants()) }),
+            ),
+        ])
+    }
+}
+
+impl ConfigSchema for IndexConfig {
+    fn schema() -> Value {
+        object::<Self>(vec![
+            (
+                "agent",
+                "Only list skills installed for this agent. If unset, lists skills of every agent.",
+                agent(),
+            ),
+            (
+                "files",
+                "Instruction files holding the index, relative to the project. If empty, uses the existing AGENTS.md, CLAUDE.md and GEMINI.md.",
+                strings(),
+            ),
+        ])
+    }
+}
+
+impl ConfigSchema for CacheConfig {
+    fn schema() -> Value {
+        object::<Self>(vec![(
+            "max_size",
+            "Size budget for ~/.skilo/git/, in bytes or with a unit such as \"500MB\". Least recently used repositories and checkouts are evicted beyond it.",
+            json!({ "oneOf": [integer(), { "type": "string", "pattern": "^\\s*[0-9.]+\\s*([kKmMgG]([iI]?[bB])?|[bB])?\\s*$" }] }),
+        )])
+    }
+}
+
+/// The full schema document for config files.
+pub fn document() -> Value {
+    let mut schema = Config::schema();
+    if let Value::Object(root) = &mut schema {
+        let mut document = Map::new();
+        document.insert(
+            "$schema".into(),
+            "http://json-schema.org/draft-07/schema#".into(),
+        );
+        document.insert("title".into(), "skilo configuration".into());
+        document.append(root);
+        return Value::Object(document);
+    }
+    schema
+}
+
+/// Render the schema document as pretty-printed JSON.
+pub fn render() -> String {
+    format!(
+        "{}\n",
+        serde_json::to_string_pretty(&document()).unwrap_or_default()
+    )
+}
+
+/// A key that no config section accepts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownKey {
+    /// Dotted key, e.g. `lint.strcit`.
+    pub key: String,
+    /// The closest known key, if any is close enough.
+    pub suggestion: Option<String>,
+}
+
+impl fmt::Display for UnknownKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown key `{}`", self.key)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, ", did you mean `{}`?", suggestion)?;
+        }
+        Ok(())
+    }
+}
+
+/// Keys in `table` that the schema does not know, in key order.
+pub fn unknown_keys(table: &toml::Table) -> Vec<UnknownKey> {
+    let mut unknown = Vec::new();
+    collect_unknown(table, &Config::schema(), "", &mut unknown);
+    unknown
+}
+
+/// The schema of a dotted `key`, if it is a known section or value.
+pub fn field(key: &str) -> Option<Value> {
+    let mut schema = Config::schema();
+    for part in key.split('.') {
+        schema = schema.get("properties")?.get(part)?.clone();
+    }
+    Some(schema)
+}
+
+/// Check that `key` is known, suggesting the closest key when it is not.
+pub fn check_key(key: &str) -> Result<Value, UnknownKey> {
+    field(key).ok_or_else(|| UnknownKey {
+        key: key.to_string(),
+        suggestion: suggest(key),
+    })
+}
+
+/// Parse a command-line value for `key` into the type the schema expects.
+///
+/// TOML literals such as `true`, `200` or `["a", "b"]` are used as they are;
+/// anything else is taken as a string, or a comma-separated list for arrays.
+pub fn parse_value(key: &str, raw: &str) -> Result<toml::Value, String> {
+    let schema = check_key(key).map_err(|e| e.to_string())?;
+    if schema.get("properties").is_some() {
+        return Err(format!("`{}` is a table; set one of its keys instead", key));
+    }
+
+    let accepted = types(&schema);
+    let literal = toml::from_str::<toml::Table>(&format!("value = {}", raw))
+        .ok()
+        .and_then(|mut table| table.remove("value"));
+    match literal {
+        Some(value) if accepted.contains(&type_name(&value)) => Ok(value),
+        _ if accepted.contains(&"string") => Ok(toml::Value::String(raw.to_string())),
+        _ if accepted.contains(&"array") => Ok(toml::Value::Array(
+            raw.split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| toml::Value::String(item.to_string()))
+                .collect(),
+        )),
+        _ => Err(format!(
+            "invalid value '{}' for `{}`: expected {}",
+            raw,
+            key,
+            accepted.join(" or ")
+        )),
+    }
+}
+
+fn collect_unknown(table: &toml::Table, schema: &Value, prefix: &str, out: &mut Vec<UnknownKey>) {
+    for (name, value) in table {
+        let key = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+        match schema.get("properties").and_then(|p| p.get(name)) {
+            Some(child) => match value {
+                toml::Value::Table(table) if child.get("properties").is_some() => {
+                    collect_unknown(table, child, &key, out)
+                }
+                toml::Value::Array(items) => {
+                    if let Some(item_schema) = child.get("items") {
+                        for item in items {
+                            if let toml::Value::Table(table) = item {
+                                collect_unknown(table, item_schema, &key, out);
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            },
+            None => out.push(UnknownKey {
+                suggestion: suggest(&key),
+                key,
+            }),
+        }
+    }
+}
+
+/// The closest known key to `key`: one with the same last segment, or else
+/// one within a few edits.
+fn suggest(key: &str) -> Option<String> {
+    let known = known_keys(&Config::schema(), "");
+    let leaf = key.rsplit('.').next().unwrap_or(key);
+    if let Some(moved) = known
+        .iter()
+        .find(|k| k.rsplit('.').next() == Some(leaf) && k.as_str() != key)
+    {
+        return Some(moved.clone());
+    }
+
+    known
+        .into_iter()
+        .map(|k| (strsim::levenshtein(key, &k), k))
+        .filter(|(distance, _)| *distance <= 3.max(key.len() / 5))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, k)| k)
+}
+
+/// Every section and value key in `schema`, dotted.
+fn known_keys(schema: &Value, prefix: &str) -> Vec<String> {
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        return Vec::new();
+    };
+    let mut keys = Vec::new();
+    for (name, child) in properties {
+        let key = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+        keys.extend(known_keys(child, &key));
+        if let Some(items) = child.get("items") {
+            keys.extend(known_keys(items, &key));
+        }
+        keys.push(key);
+    }
+    keys
+}
+
+/// JSON types accepted by `schema`.
+fn types(schema: &Value) -> Vec<&'static str> {
+    let mut accepted = Vec::new();
+    if let Some(name) = schema.get("type").and_then(Value::as_str) {
+        accepted.extend(JSON_TYPES.iter().copied().filter(|t| *t == name));
+    }
+    if schema.get("enum").is_some() {
+        accepted.push("string");
+    }
+    for key in ["anyOf", "oneOf"] {
+        for option in schema
+            .get(key)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            accepted.extend(types(option));
+        }
+    }
+    accepted
+}
+
+/// JSON Schema type names.
+const JSON_TYPES: [&str; 6] = ["boolean", "integer", "number", "string", "array", "object"];
+
+/// JSON type name of a TOML value.
+fn type_name(value: &toml::Value) -> &'static str {
+    match value {
+        toml::Value::Boolean(_) => "boolean",
+        toml::Value::Integer(_) => "integer",
+        toml::Value::Float(_) => "number",
+        toml::Value::String(_) | toml::Value::Datetime(_) => "string",
+        toml::Value::Array(_) => "array",
+        toml::Value::Table(_) => "object",
+    }
+}
+
+/// Object schema with `fields` as (name, description, schema), taking
+/// defaults from `T::default()`.
+fn object<T: Default + Serialize>(fields: Vec<(&str, &str, Value)>) -> Value {
+    let defaults = serde_json::to_value(T::default()).unwrap_or_default();
+    let mut properties = Map::new();
+    for (name, description, mut schema) in fields {
+        if let Value::Object(schema) = &mut schema {
+            schema.insert("description".into(), description.into());
+            let default = defaults
+                .get(name)
+                .filter(|d| !d.is_null() && !d.is_object());
+            if let Some(default) = default {
+                schema.insert("default".into(), default.clone());
+            }
+        }
+        properties.insert(name.to_string(), schema);
+    }
+    json!({
+        "type": "object",
+        "additionalProperties": false,
+        "properties": properties,
+    })
+}
+
+fn boolean() -> Value {
+    json!({ "type": "boolean" })
+}
+
+fn integer() -> Value {
+    json!({ "type": "integer", "minimum": 0 })
+}
+
+fn strings() -> Value {
+    json!({ "type": "array", "items": { "type": "string" } })
+}
+
+/// A built-in agent, or one defined in `[[agents]]`.
+fn agent() -> Value {
+    json!({ "anyOf": [{ "enum": variants(Agent::builtin()) }, { "type": "string" }] })
+}
+
+/// `true` for the rule's default limit, `false` to disable it, or a number.
+fn threshold() -> Value {
+    json!({ "oneOf": [boolean(), integer()] })
+}
+
+/// Serialized names of enum `values`.
+fn variants<T: Serialize>(values: &[T]) -> Vec<Value> {
+    values
+        .iter()
+        .filter_map(|v| serde_json::to_value(v).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_covers_every_config_key() {
+        let defaults = toml::Table::try_from(Config::default()).unwrap();
+        assert!(unknown_keys(&defaults).is_empty());
+        for key in [
+            "new.default_license",
+            "add.default_agent",
+            "prompt.max_tokens",
+        ] {
+            assert!(field(key).is_some(), "{}", key);
+        }
+    }
+
+    /// A config with every optional value set, so that each field serializes.
+    fn full_config() -> Config {
+        let mut config = Config::default();
+        config.lint.target_agents = vec![Agent::Claude];
+        config.new.default_license = Some("MIT".to_string());
+        config.new.default_template = Some("hello-world".to_string());
+        config.new.default_lang = Some(ScriptLang::Python);
+        config.add.default_agent = Some(Agent::Claude);
+        config.discovery.ignore = vec!["target".to_string()];
+        config.prompt.max_tokens = Some(4000);
+        config.prompt.priority = vec!["pdf-tools".to_string()];
+        config.sync.from = Some(Agent::Claude);
+        config.sync.agents = vec![Agent::Codex];
+        config.index.agent = Some(Agent::Claude);
+        config.index.files = vec!["AGENTS.md".into()];
+        config.cache.max_size = Some(500_000_000);
+        config.agents = vec![CustomAgent {
+            name: "acme".to_string(),
+            display_name: Some("Acme Assistant".to_string()),
+            skills_dir: ".acme/skills".to_string(),
+            global_skills_dir: "~/.acme/skills".to_string(),
+            detection_dir: Some(".acme".to_string()),
+            global_detection_dir: Some("~/.acme".to_string()),
+            features: AgentFeatures {
+                script_langs: Some(vec![ScriptLang::Python]),
+                ..Default::default()
+            },
+        }];
+        config
+    }
+
+    /// Check that every field of `value` is in `schema` with a type it
+    /// accepts, collecting the dotted keys seen.
+    fn check_fields(value: &Value, schema: &Value, prefix: &str, keys: &mut Vec<String>) {
+        match value {
+            Value::Object(fields) if schema.get("properties").is_some() => {
+                for (name, field) in fields {
+                    let key = if prefix.is_empty() {
+                        name.clone()
+                    } else {
+                        format!("{}.{}", prefix, name)
+                    };
+                    let child = schema["properties"]
+                        .get(name)
+                        .unwrap_or_else(|| panic!("`{}` is missing from the schema", key));
+                    assert!(!field.is_null(), "`{}` is not set in full_config()", key);
+                    keys.push(key.clone());
+                    check_fields(field, child, &key, keys);
+                }
+            }
+            Value::Array(items) if schema.get("items").is_some() => {
+                for item in items {
+                    check_fields(item, &schema["items"], prefix, keys);
+                }
+            }
+            _ => {
+                let accepted = types(schema);
+                let json_type = match value {
+                    Value::Bool(_) => "boolean",
+                    Value::Number(n) if n.is_u64() => "integer",
+                    Value::Number(_) => "number",
+                    Value::String(_) => "string",
+                    _ => "object",
+                };
+                assert!(
+                    accepted.contains(&json_type),
+                    "`{}` is {} but the schema expects {:?}",
+                    prefix,
+                    json_type,
+                    accepted
+                );
+                if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+                    assert!(
+                        allowed.contains(value),
+                        "`{}` = {} is not allowed",
+                        prefix,
+                        value
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_schema_matches_every_config_field() {
+        let schema = Config::schema();
+        let value = serde_json::to_value(full_config()).unwrap();
+        let mut keys = Vec::new();
+        check_fields(&value, &schema, "", &mut keys);
+
+        // And the other way round: the schema has no key the config lacks
+        let mut known = known_keys(&schema, "");
+        known.sort();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys, known);
+    }
+
+    #[test]
+    fn test_unknown_keys_suggest_the_closest_key() {
+        let table: toml::Table =
+            toml::from_str("[lint]\nstrcit = true\nbody_length = 200\n[colors]\n").unwrap();
+        let unknown = unknown_keys(&table);
+        assert_eq!(
+            unknown.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec![
+                "unknown key `colors`",
+                "unknown key `lint.body_length`, did you mean `lint.rules.body_length`?",
+                "unknown key `lint.strcit`, did you mean `lint.strict`?",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(
+            parse_value("lint.strict", "true"),
+            Ok(toml::Value::Boolean(true))
+        );
+        assert_eq!(
+            parse_value("lint.rules.body_length", "false"),
+            Ok(toml::Value::Boolean(false))
+        );
+        assert_eq!(
+            parse_value("new.default_license", "MIT"),
+            Ok(toml::Value::String("MIT".into()))
+        );
+        assert_eq!(
+            parse_value("discovery.ignore", "target, dist"),
+            Ok(toml::Value::Array(vec![
+                toml::Value::String("target".into()),
+                toml::Value::String("dist".into()),
+            ]))
+        );
+        assert!(parse_value("fmt.indent_size", "wide").is_err());
+        assert!(parse_value("lint", "true").is_err());
+    }
+
+    #[test]
+    fn test_published_schema_is_up_to_date() {
+        let published = include_str!("../../docs/config.schema.json");
+        assert_eq!(
+            published,
+            render(),
+            "docs/config.schema.json is stale; regenerate it with `skilo config schema > docs/config.schema.json`"
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! Line-based unified diffs.
+//!
+//! Produces hunks with context lines and `@@` headers in the same layout as
+//! `diff -u`, so the rendered output can be applied with `git apply`.
+
+use serde::Serialize;
+use similar::{Algorithm, ChangeTag, TextDiff};
+
+/// Default number of unchanged lines shown around each change.
+pub const DEFAULT_CONTEXT: usize = 3;
+
+/// Kind of a line inside a hunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineKind {
+    /// Line present in both versions.
+    Context,
+    /// Line only present in the old version.
+    Delete,
+    /// Line only present in the new version.
+    Insert,
+}
+
+impl LineKind {
+    /// Returns the unified diff prefix for this kind of line.
+    pub fn prefix(&self) -> char {
+        match self {
+            Self::Context => ' ',
+            Self::Delete => '-',
+            Self::Insert => '+',
+        }
+    }
+}
+
+/// A single line inside a hunk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffLine {
+    /// Whether the line is context, deleted, or inserted.
+    pub kind: LineKind,
+    /// Line content without the trailing newline.
+    pub content: String,
+    /// True if this is the last line of a file that has no trailing newline.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub missing_newline: bool,
+}
+
+/// A contiguous group of changes with surrounding context.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Hunk {
+    /// First line of the hunk in the old file (1-based, 0 for an empty range).
+    pub old_start: usize,
+    /// Number of old lines covered by the hunk.
+    pub old_lines: usize,
+    /// First line of the hunk in the new file (1-based, 0 for an empty range).
+    pub new_start: usize,
+    /// Number of new lines covered by the hunk.
+    pub new_lines: usize,
+    /// Lines of the hunk in order.
+    pub lines: Vec<DiffLine>,
+}
+
+impl Hunk {
+    /// Returns the `@@ -a,b +c,d @@` header line.
+    pub fn header(&self) -> String {
+        format!(
+            "@@ -{},{} +{},{} @@",
+            self.old_start, self.old_lines, self.new_start, self.new_lines
+        )
+    }
+}
+
+/// The differences between two versions of a file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileDiff {
+    /// Path of the file, as shown in the `---`/`+++` headers.
+    pub path: String,
+    /// Hunks in file order; empty when both versions are identical.
+    pub hunks: Vec<Hunk>,
+}
+
+impl FileDiff {
+    /// Compute a diff between `old` and `new` with the given context size.
+    pub fn compute(path: impl Into<String>, old: &str, new: &str, context: usize) -> Self {
+        let diff = TextDiff::configure()
+            .algorithm(Algorithm::Patience)
+            .diff_lines(old, new);
+
+        let hunks = diff
+            .grouped_ops(context)
+            .into_iter()
+            .filter(|group| !group.is_empty())
+            .map(|group| {
+                let first = &group[0];
+                let last = &group[group.len() - 1];
+                let old_range = first.old_range().start..last.old_range().end;
+                let new_range = first.new_range().start..last.new_range().end;
+
+                let lines = group
+                    .iter()
+                    .flat_map(|op| diff.iter_changes(op))
+                    .map(|change| DiffLine {
+                        kind: match change.tag() {
+                            ChangeTag::Equal => LineKind::Context,
+                            ChangeTag::Delete => LineKind::Delete,
+                            ChangeTag::Insert => LineKind::Insert,
+                        },
+                        content: change.value().trim_end_matches(['\n', '\r']).to_string(),
+                        missing_newline: change.missing_newline(),
+                    })
+                    .collect();
+
+                Hunk {
+                    old_start: range_start(&old_range),
+                    old_lines: old_range.len(),
+                    new_start: range_start(&new_range),
+                    new_lines: new_range.len(),
+                    lines,
+                }
+            })
+            .collect();
+
+        Self {
+            path: path.into(),
+            hunks,
+        }
+    }
+
+    /// Returns true if the two versions are identical.
+    pub fn is_empty(&self) -> bool {
+        self.hunks.is_empty()
+    }
+
+    /// Render as a plain unified diff with `a/` and `b/` path prefixes.
+    pub fn to_unified(&self) -> String {
+        let mut output = String::new();
+        if self.is_empty() {
+            return output;
+        }
+
+        let (old_header, new_header) = self.file_headers();
+        output.push_str(&old_header);
+        output.push('\n');
+        output.push_str(&new_header);
+        output.push('\n');
+
+        for hunk in &self.hunks {
+            output.push_str(&hunk.header());
+            output.push('\n');
+            for line in &hunk.lines {
+                output.push(line.kind.prefix());
+                output.push_str(&line.content);
+                output.push('\n');
+                if line.missing_newline {
+                    output.push_str("\\ No newline at end of file\n");
+                }
+            }
+        }
+
+        output
+    }
+
+    /// Returns the `---` and `+++` header lines.
+    pub fn file_headers(&self) -> (String, String) {
+        let path = self.path.trim_start_matches("./");
+        (format!("--- a/{}", path), format!("+++ b/{}", path))
+    }
+}
+
+/// Convert a 0-based line range to the 1-based start used in hunk headers.
+///
+/// Empty ranges point at the line *before* the change, as `diff -u` does.
+fn range_start(range: &std::ops::Range<usize>) -> usize {
+    if range.is_empty() {
+        range.start
+    } else {
+        range.start + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_has_no_hunks() {
+        let diff = FileDiff::compute("SKILL.md", "a\nb\n", "a\nb\n", DEFAULT_CONTEXT);
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_unified(), "");
+    }
+
+    #[test]
+    fn test_inserted_line_keeps_following_lines_as_context() {
+        let old = "one\ntwo\nthree\nfour\n";
+        let new = "one\ninserted\ntwo\nthree\nfour\n";
+        let diff = FileDiff::compute("skill/SKILL.md", old, new, DEFAULT_CONTEXT);
+
+        assert_eq!(diff.hunks.len(), 1);
+        let hunk = &diff.hunks[0];
+        assert_eq!(hunk.header(), "@@ -1,4 +1,5 @@");
+        let changed: Vec<_> = hunk
+            .lines
+            .iter()
+            .filter(|l| l.kind != LineKind::Context)
+            .collect();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].kind, LineKind::Insert);
+        assert_eq!(changed[0].content, "inserted");
+    }
+
+    #[test]
+    fn test_distant_changes_produce_separate_hunks() {
+        let old: String = (1..=20).map(|i| format!("line {}\n", i)).collect();
+        let new = old
+            .replace("line 2\n", "line two\n")
+            .replace("line 19\n", "line nineteen\n");
+        let diff = FileDiff::compute("SKILL.md", &old, &new, DEFAULT_CONTEXT);
+
+        assert_eq!(diff.hunks.len(), 2);
+        assert_eq!(diff.hunks[0].header(), "@@ -1,5 +1,5 @@");
+        assert_eq!(diff.hunks[1].header(), "@@ -16,5 +16,5 @@");
+    }
+
+    #[test]
+    fn test_unified_output_format() {
+        let diff = FileDiff::compute("./s/SKILL.md", "a\nb\n", "a\nc", DEFAULT_CONTEXT);
+        assert_eq!(
+            diff.to_unified(),
+            "--- a/s/SKILL.md\n+++ b/s/SKILL.md\n@@ -1,2 +1,2 @@\n a\n-b\n+c\n\\ No newline at end of file\n"
+        );
+    }
+}
@@ -31,6 +31,22 @@ pub enum SkiloError {
         path: String,
     },
 
+    /// No importable files were found at the given path.
+    #[error("No Cursor rules, Claude commands or Copilot prompts found in {path}")]
+    #[diagnostic(code(skilo::nothing_to_import))]
+    NothingToImport {
+        /// The path that was searched.
+        path: String,
+    },
+
+    /// No built-in or configured agent has the given name.
+    #[error("Unknown agent '{0}' (available: {1})")]
+    #[diagnostic(
+        code(skilo::unknown_agent),
+        help("Define other agents with [[agents]] entries in .skilorc.toml")
+    )]
+    UnknownAgent(String, String),
+
     /// A configuration error occurred.
     #[error("Configuration error: {0}")]
     #[diagnostic(code(skilo::config))]
@@ -85,6 +101,44 @@ pub enum SkiloError {
         message: String,
     },
 
+    /// Watching the filesystem for changes failed.
+    #[error("Watch error: {0}")]
+    #[diagnostic(code(skilo::watch))]
+    Watch(String),
+
+    /// The language server failed to communicate with the client.
+    #[error("Language server error: {0}")]
+    #[diagnostic(code(skilo::lsp))]
+    Lsp(String),
+
+    /// A prompt template could not be parsed.
+    #[error("Invalid prompt template: {0}")]
+    #[diagnostic(code(skilo::template))]
+    Template(String),
+
+    /// A skill template could not be loaded.
+    #[error("Invalid skill template: {0}")]
+    #[diagnostic(code(skilo::skill_template))]
+    SkillTemplate(String),
+
+    /// The MCP server failed to communicate with the client.
+    #[error("MCP server error: {0}")]
+    #[diagnostic(code(skilo::mcp))]
+    Mcp(String),
+
+    /// No lint rule matches the given code or name.
+    #[error("Unknown rule '{0}'")]
+    #[diagnostic(
+        code(skilo::unknown_rule),
+        help("Run `skilo explain` to list all rules")
+    )]
+    UnknownRule(String),
+
+    /// Skills could not be synced across agents.
+    #[error("Sync error: {0}")]
+    #[diagnostic(code(skilo::sync))]
+    Sync(String),
+
     /// User cancelled the operation.
     #[error("Operation cancelled by user")]
     #[diagnostic(code(skilo::cancelled))]
@@ -0,0 +1,86 @@
+//! Delimited blocks that skilo owns inside otherwise hand-written files.
+//!
+//! A block with id `export pdf-tools` looks like:
+//!
+//! ```text
+//! <!-- skilo:export pdf-tools:begin -->
+//! ...
+//! <!-- skilo:export pdf-tools:end -->
+//! ```
+//!
+//! An empty id gives the plain `<!-- skilo:begin -->` / `<!-- skilo:end -->` pair.
+
+/// Begin and end markers for the block with `id`.
+pub fn markers(id: &str) -> (String, String) {
+    if id.is_empty() {
+        (
+            "<!-- skilo:begin -->".to_string(),
+            "<!-- skilo:end -->".to_string(),
+        )
+    } else {
+        (
+            format!("<!-- skilo:{}:begin -->", id),
+            format!("<!-- skilo:{}:end -->", id),
+        )
+    }
+}
+
+/// Contents of the block with `id`, without markers, if present.
+pub fn extract<'a>(text: &'a str, id: &str) -> Option<&'a str> {
+    let (begin, end) = markers(id);
+    let start = text.find(&begin)? + begin.len();
+    let stop = start + text[start..].find(&end)?;
+    Some(text[start..stop].trim_matches('\n'))
+}
+
+/// Replace the block with `id` in `text`, or append it if missing.
+pub fn upsert(text: &str, id: &str, content: &str) -> String {
+    let (begin, end) = markers(id);
+    let block = format!("{}\n{}\n{}", begin, content.trim_matches('\n'), end);
+
+    if let Some(start) = text.find(&begin) {
+        if let Some(stop) = text[start..].find(&end) {
+            let stop = start + stop + end.len();
+            return format!("{}{}{}", &text[..start], block, &text[stop..]);
+        }
+    }
+
+    if text.trim().is_empty() {
+        format!("{}\n", block)
+    } else {
+        format!("{}\n\n{}\n", text.trim_end(), block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upsert_appends_then_replaces_in_place() {
+        let text = upsert("# Notes\n", "", "one");
+        assert_eq!(
+            text,
+            "# Notes\n\n<!-- skilo:begin -->\none\n<!-- skilo:end -->\n"
+        );
+
+        let text = format!("{}\nTrailing text.\n", text);
+        let text = upsert(&text, "", "two");
+        assert_eq!(
+            text,
+            "# Notes\n\n<!-- skilo:begin -->\ntwo\n<!-- skilo:end -->\n\nTrailing text.\n"
+        );
+        assert_eq!(extract(&text, ""), Some("two"));
+    }
+
+    #[test]
+    fn test_blocks_with_ids_are_independent() {
+        let text = upsert("", "export a", "A");
+        let text = upsert(&text, "export b", "B");
+        let text = upsert(&text, "export a", "A2");
+
+        assert_eq!(extract(&text, "export a"), Some("A2"));
+        assert_eq!(extract(&text, "export b"), Some("B"));
+        assert_eq!(extract(&text, ""), None);
+    }
+}
@@ -0,0 +1,149 @@
+//! Skills index blocks in agent instruction files.
+//!
+//! Agents that do not discover skills on their own need them listed in
+//! `AGENTS.md`, `CLAUDE.md` or `GEMINI.md`. The list lives between
+//! `<!-- skilo:begin -->` and `<!-- skilo:end -->` so the rest of the file
+//! stays hand-written.
+
+use super::{block, Output};
+use crate::agent::Agent;
+use crate::prompt::location::Location;
+use crate::scope::{list_skills, list_skills_from_path, InstalledSkill, Scope};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Instruction files that may hold a skills index, in order of preference.
+pub const INDEX_FILES: [&str; 3] = ["AGENTS.md", "CLAUDE.md", "GEMINI.md"];
+
+/// Block id of the skills index (the plain `skilo:begin`/`skilo:end` pair).
+pub const INDEX_BLOCK: &str = "";
+
+/// Project skills installed for `agents` and in `./skills/`, unique by name.
+pub fn installed_skills(project_root: &Path, agents: &[Agent]) -> Vec<InstalledSkill> {
+    let mut skills: Vec<InstalledSkill> = agents
+        .iter()
+        .flat_map(|agent| list_skills(*agent, Scope::Project, project_root))
+        .collect();
+    skills.extend(list_skills_from_path(
+        &project_root.join("skills"),
+        None,
+        Scope::Project,
+    ));
+
+    let mut seen = HashSet::new();
+    skills.retain(|skill| seen.insert(skill.name.clone()));
+    skills.sort_by(|a, b| a.name.cmp(&b.name));
+    skills
+}
+
+/// Render the index block contents for `skills`.
+pub fn render(skills: &[InstalledSkill], project_root: &Path) -> String {
+    let mut out = String::from(
+        "## Skills\n\n<!-- Generated by `skilo sync-index`. Do not edit by hand. -->\n\n",
+    );
+    if skills.is_empty() {
+        out.push_str("No skills are installed.");
+        return out;
+    }
+
+    out.push_str("Read a skill's SKILL.md before using it.\n");
+    let relative = Location::Relative(project_root.to_path_buf());
+    for skill in skills {
+        out.push_str(&format!(
+            "\n- **{}**: {} (`{}`)",
+            skill.name,
+            skill.description,
+            relative.resolve(&skill.path.join("SKILL.md"), &skill.name)
+        ));
+    }
+    out
+}
+
+/// The index block output for one instruction file.
+pub fn output(file: &Path, content: &str) -> Output {
+    Output::Block {
+        path: file.to_path_buf(),
+        id: INDEX_BLOCK.to_string(),
+        content: content.to_string(),
+    }
+}
+
+/// Instruction files that may hold the index: `files` if given, otherwise
+/// [`INDEX_FILES`].
+pub fn index_files(files: &[PathBuf]) -> Vec<PathBuf> {
+    if files.is_empty() {
+        INDEX_FILES.iter().map(PathBuf::from).collect()
+    } else {
+        files.to_vec()
+    }
+}
+
+/// Instruction `files` under `project_root` whose index block differs from `content`.
+///
+/// Files without an index block are not considered stale.
+pub fn stale_files(project_root: &Path, files: &[PathBuf], content: &str) -> Vec<PathBuf> {
+    files
+        .iter()
+        .map(|name| project_root.join(name))
+        .filter(|path| {
+            std::fs::read_to_string(path)
+                .ok()
+                .and_then(|text| block::extract(&text, INDEX_BLOCK).map(|b| b != content.trim()))
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn install(root: &Path, dir: &str, name: &str) {
+        let skill = root.join(dir).join(name);
+        std::fs::create_dir_all(&skill).unwrap();
+        std::fs::write(
+            skill.join("SKILL.md"),
+            format!(
+                "---\nname: {}\ndescription: The {} skill\n---\n",
+                name, name
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_installed_skills_are_unique_and_sorted() {
+        let temp = TempDir::new().unwrap();
+        install(temp.path(), ".claude/skills", "pdf-tools");
+        install(temp.path(), ".codex/skills", "pdf-tools");
+        install(temp.path(), "skills", "api-docs");
+
+        let skills = installed_skills(temp.path(), &[Agent::Claude, Agent::Codex]);
+        let names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["api-docs", "pdf-tools"]);
+
+        let content = render(&skills, temp.path());
+        assert!(content.contains("- **api-docs**: The api-docs skill (`skills/api-docs/SKILL.md`)"));
+        assert!(content.contains("(`.claude/skills/pdf-tools/SKILL.md`)"));
+    }
+
+    #[test]
+    fn test_stale_files() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("CLAUDE.md"), "# Notes\n").unwrap();
+        std::fs::write(
+            temp.path().join("AGENTS.md"),
+            block::upsert("# Agents\n", INDEX_BLOCK, "old list"),
+        )
+        .unwrap();
+
+        let files = index_files(&[]);
+        assert_eq!(
+            stale_files(temp.path(), &files, "new list"),
+            vec![temp.path().join("AGENTS.md")]
+        );
+        assert!(stale_files(temp.path(), &files, "old list").is_empty());
+        assert!(stale_files(temp.path(), &[PathBuf::from("CLAUDE.md")], "new list").is_empty());
+    }
+}
@@ -0,0 +1,434 @@
+//! Conversion of skills into agent-native rule files.
+//!
+//! Some agents read their own rules formats instead of (or alongside)
+//! SKILL.md. Exported files carry a generated-file marker, and shared files
+//! such as `GEMINI.md` get one delimited block per skill, so re-exporting
+//! updates them in place without touching hand-written content.
+
+pub mod block;
+pub mod index;
+
+use crate::cli::ExportTarget;
+use crate::prompt::location::Location;
+use crate::skill::rules::{NameFormatRule, ReferencesExistRule};
+use crate::skill::Manifest;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Marker identifying files written by `skilo export`.
+pub const GENERATED_MARKER: &str = "<!-- skilo:generated";
+
+/// Metadata key with comma-separated globs the rule applies to.
+pub const GLOBS_KEY: &str = "globs";
+
+/// Metadata key that, when `true`, applies the rule to every request.
+pub const ALWAYS_APPLY_KEY: &str = "always-apply";
+
+/// A file produced for one skill and target.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Output {
+    /// A file owned entirely by skilo.
+    File {
+        /// Path relative to the output directory.
+        path: PathBuf,
+        /// Full file contents.
+        content: String,
+    },
+    /// A delimited block inside a shared file.
+    Block {
+        /// Path relative to the output directory.
+        path: PathBuf,
+        /// Block identifier, unique within the file.
+        id: String,
+        /// Block contents, without markers.
+        content: String,
+    },
+}
+
+impl Output {
+    /// Path of the written file, relative to the output directory.
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::File { path, .. } | Self::Block { path, .. } => path,
+        }
+    }
+}
+
+/// What happened to an exported file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase", tag = "status", content = "reason")]
+pub enum WriteStatus {
+    /// The file did not exist.
+    Created,
+    /// The file or block changed.
+    Updated,
+    /// The file or block was already up to date.
+    Unchanged,
+    /// The file was left alone.
+    Skipped(String),
+}
+
+/// Result of exporting one skill to one target.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedFile {
+    /// Skill name.
+    pub skill: String,
+    /// Target format.
+    pub target: ExportTarget,
+    /// Written path.
+    pub path: PathBuf,
+    /// What happened to the file.
+    #[serde(flatten)]
+    pub status: WriteStatus,
+}
+
+/// Results of an export run.
+#[derive(Debug, Default)]
+pub struct ExportReport {
+    /// Every file considered, in export order.
+    pub files: Vec<ExportedFile>,
+    /// Whether files were left untouched.
+    pub dry_run: bool,
+}
+
+impl ExportReport {
+    /// Returns true if no file had to be skipped.
+    pub fn is_ok(&self) -> bool {
+        !self
+            .files
+            .iter()
+            .any(|f| matches!(f.status, WriteStatus::Skipped(_)))
+    }
+}
+
+/// Render the output for `manifest` in the `target` format.
+///
+/// References such as `` `scripts/run.sh` `` are rewritten relative to
+/// `output_dir`, where the rule file's reader resolves them. Fails if the
+/// skill's name is not valid, since it names the output file.
+pub fn render(
+    manifest: &Manifest,
+    target: ExportTarget,
+    output_dir: &Path,
+) -> Result<Output, String> {
+    let frontmatter = &manifest.frontmatter;
+    let name = &frontmatter.name;
+    if !NameFormatRule::is_valid(name) {
+        return Err(format!(
+            "invalid skill name '{}': must be lowercase alphanumeric with single hyphens",
+            name
+        ));
+    }
+    let description = &frontmatter.description;
+    let metadata = frontmatter.metadata.clone().unwrap_or_default();
+    let globs: Vec<&str> = metadata
+        .get(GLOBS_KEY)
+        .map(|g| {
+            g.split(',')
+                .map(str::trim)
+                .filter(|g| !g.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+    let always_apply = metadata
+        .get(ALWAYS_APPLY_KEY)
+        .is_some_and(|v| v.trim() == "true");
+
+    let body = rewrite_references(manifest, output_dir);
+    let marker = format!(
+        "{} from {}; re-run `skilo export` to update -->",
+        GENERATED_MARKER,
+        Location::Relative(output_dir.to_path_buf()).resolve(&manifest.path, name)
+    );
+
+    Ok(match target {
+        ExportTarget::Cursor => Output::File {
+            path: Path::new(".cursor/rules").join(format!("{}.mdc", name)),
+            content: format!(
+                "---\ndescription: {}\nglobs:{}\nalwaysApply: {}\n---\n\n{}\n\n{}\n",
+                yaml_string(description),
+                if globs.is_empty() {
+                    String::new()
+                } else {
+                    format!(" {}", globs.join(","))
+                },
+                always_apply,
+                marker,
+                body
+            ),
+        },
+        ExportTarget::Copilot => {
+            let apply_to = if globs.is_empty() || always_apply {
+                "**".to_string()
+            } else {
+                globs.join(",")
+            };
+            Output::File {
+                path: Path::new(".github/instructions").join(format!("{}.instructions.md", name)),
+                content: format!(
+                    "---\napplyTo: {}\ndescription: {}\n---\n\n{}\n\n{}\n",
+                    yaml_string(&apply_to),
+                    yaml_string(description),
+                    marker,
+                    body
+                ),
+            }
+        }
+        ExportTarget::Windsurf => {
+            let trigger = if always_apply {
+                "trigger: always_on".to_string()
+            } else if !globs.is_empty() {
+                format!("trigger: glob\nglobs: {}", globs.join(","))
+            } else {
+                "trigger: model_decision".to_string()
+            };
+            Output::File {
+                path: Path::new(".windsurf/rules").join(format!("{}.md", name)),
+                content: format!(
+                    "---\n{}\ndescription: {}\n---\n\n{}\n\n{}\n",
+                    trigger,
+                    yaml_string(description),
+                    marker,
+                    body
+                ),
+            }
+        }
+        ExportTarget::Gemini => Output::Block {
+            path: PathBuf::from("GEMINI.md"),
+            id: format!("export {}", name),
+            content: format!("## {}\n\n{}\n\n{}", name, description, body),
+        },
+    })
+}
+
+/// Write `output` under `output_dir`, refusing to clobber hand-written files.
+pub fn write(
+    output: &Output,
+    output_dir: &Path,
+    force: bool,
+    dry_run: bool,
+) -> std::io::Result<WriteStatus> {
+    let inside = output
+        .path()
+        .components()
+        .all(|c| matches!(c, std::path::Component::Normal(_)));
+    if !inside {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!(
+                "{} is outside the output directory",
+                output.path().display()
+            ),
+        ));
+    }
+    let path = output_dir.join(output.path());
+    let existing = match std::fs::read_to_string(&path) {
+        Ok(existing) => Some(existing),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e),
+    };
+
+    let content = match output {
+        Output::File { content, .. } => {
+            if let Some(existing) = &existing {
+                if !force && !existing.contains(GENERATED_MARKER) {
+                    return Ok(WriteStatus::Skipped(
+                        "not generated by skilo; use --force to overwrite".to_string(),
+                    ));
+                }
+            }
+            content.clone()
+        }
+        Output::Block { id, content, .. } => {
+            block::upsert(existing.as_deref().unwrap_or_default(), id, content)
+        }
+    };
+
+    let status = match &existing {
+        None => WriteStatus::Created,
+        Some(existing) if *existing == content => return Ok(WriteStatus::Unchanged),
+        Some(_) => WriteStatus::Updated,
+    };
+
+    if !dry_run {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, content)?;
+    }
+
+    Ok(status)
+}
+
+/// The skill body with bundled file references made relative to `output_dir`.
+fn rewrite_references(manifest: &Manifest, output_dir: &Path) -> String {
+    let mut body = manifest.body.trim().to_string();
+    let Some(skill_dir) = manifest.path.parent() else {
+        return body;
+    };
+
+    let relative = Location::Relative(output_dir.to_path_buf());
+    for reference in ReferencesExistRule::referenced_paths(&manifest.body) {
+        let rewritten = relative.resolve(&skill_dir.join(reference), "");
+        body = body.replace(&format!("`{}`", reference), &format!("`{}`", rewritten));
+    }
+    body
+}
+
+/// Quote a YAML scalar when it would otherwise be misread.
+fn yaml_string(value: &str) -> String {
+    let plain = !value.is_empty()
+        && !value.starts_with(|c: char| "!&*-?[]{}|>@`\"'%#,".contains(c) || c.is_whitespace())
+        && !value.ends_with(char::is_whitespace)
+        && !value.contains(": ")
+        && !value.contains(" #");
+    if plain {
+        value.to_string()
+    } else {
+        serde_json::to_string(value).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn manifest(root: &Path, metadata: &str) -> Manifest {
+        let dir = root.join("skills/pdf-tools");
+        std::fs::create_dir_all(dir.join("scripts")).unwrap();
+        std::fs::write(dir.join("scripts/run.sh"), "#!/bin/sh\n").unwrap();
+        let content = format!(
+            "---\nname: pdf-tools\ndescription: Work with PDFs\n{}---\n\n# PDF Tools\n\nRun `scripts/run.sh`.\n",
+            metadata
+        );
+        std::fs::write(dir.join("SKILL.md"), content).unwrap();
+        Manifest::parse(dir.join("SKILL.md")).unwrap()
+    }
+
+    #[test]
+    fn test_render_cursor_rule() {
+        let temp = TempDir::new().unwrap();
+        let manifest = manifest(temp.path(), "metadata:\n  globs: \"*.pdf, docs/**\"\n");
+
+        let Output::File { path, content } =
+            render(&manifest, ExportTarget::Cursor, temp.path()).unwrap()
+        else {
+            panic!("expected a file");
+        };
+        assert_eq!(path, Path::new(".cursor/rules/pdf-tools.mdc"));
+        assert!(content.starts_with(
+            "---\ndescription: Work with PDFs\nglobs: *.pdf,docs/**\nalwaysApply: false\n---\n"
+        ));
+        assert!(content.contains("<!-- skilo:generated from skills/pdf-tools/SKILL.md;"));
+        assert!(content.contains("Run `skills/pdf-tools/scripts/run.sh`."));
+    }
+
+    #[test]
+    fn test_render_windsurf_and_copilot_triggers() {
+        let temp = TempDir::new().unwrap();
+        let manifest = manifest(temp.path(), "metadata:\n  always-apply: \"true\"\n");
+
+        let windsurf = render(&manifest, ExportTarget::Windsurf, temp.path()).unwrap();
+        assert_eq!(windsurf.path(), Path::new(".windsurf/rules/pdf-tools.md"));
+        let Output::File { content, .. } = windsurf else {
+            panic!("expected a file");
+        };
+        assert!(content.starts_with("---\ntrigger: always_on\n"));
+
+        let Output::File { content, .. } =
+            render(&manifest, ExportTarget::Copilot, temp.path()).unwrap()
+        else {
+            panic!("expected a file");
+        };
+        assert!(content.starts_with("---\napplyTo: \"**\"\n"));
+    }
+
+    #[test]
+    fn test_write_refuses_hand_written_files() {
+        let temp = TempDir::new().unwrap();
+        let manifest = manifest(temp.path(), "");
+        let output = render(&manifest, ExportTarget::Cursor, temp.path()).unwrap();
+
+        assert_eq!(
+            write(&output, temp.path(), false, false).unwrap(),
+            WriteStatus::Created
+        );
+        assert_eq!(
+            write(&output, temp.path(), false, false).unwrap(),
+            WriteStatus::Unchanged
+        );
+
+        let path = temp.path().join(output.path());
+        std::fs::write(&path, "my own rules\n").unwrap();
+        assert!(matches!(
+            write(&output, temp.path(), false, false).unwrap(),
+            WriteStatus::Skipped(_)
+        ));
+        assert_eq!(
+            write(&output, temp.path(), true, false).unwrap(),
+            WriteStatus::Updated
+        );
+    }
+
+    #[test]
+    fn test_write_gemini_block_keeps_other_content() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("GEMINI.md"),
+            "# Project\n\nHand-written.\n",
+        )
+        .unwrap();
+        let manifest = manifest(temp.path(), "");
+        let output = render(&manifest, ExportTarget::Gemini, temp.path()).unwrap();
+
+        assert_eq!(
+            write(&output, temp.path(), false, false).unwrap(),
+            WriteStatus::Updated
+        );
+        let content = std::fs::read_to_string(temp.path().join("GEMINI.md")).unwrap();
+        assert!(content.starts_with("# Project\n\nHand-written.\n"));
+        assert!(content.contains("<!-- skilo:export pdf-tools:begin -->\n## pdf-tools\n"));
+        assert_eq!(
+            write(&output, temp.path(), false, false).unwrap(),
+            WriteStatus::Unchanged
+        );
+    }
+
+    #[test]
+    fn test_names_cannot_escape_the_output_dir() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("skills/pwned");
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in ["../../../pwned", "pwned -->"] {
+            std::fs::write(
+                dir.join("SKILL.md"),
+                format!(
+                    "---\nname: \"{}\"\ndescription: Escape\n---\n\nBody\n",
+                    name
+                ),
+            )
+            .unwrap();
+            let manifest = Manifest::parse(dir.join("SKILL.md")).unwrap();
+            for target in [ExportTarget::Cursor, ExportTarget::Gemini] {
+                let err = render(&manifest, target, temp.path()).unwrap_err();
+                assert!(err.contains("invalid skill name"), "{}", err);
+            }
+        }
+
+        let output = Output::File {
+            path: PathBuf::from(".cursor/rules/../../../pwned.mdc"),
+            content: String::new(),
+        };
+        let out = temp.path().join("out");
+        assert!(write(&output, &out, true, false).is_err());
+        assert!(!temp.path().join("pwned.mdc").exists());
+    }
+
+    #[test]
+    fn test_yaml_string() {
+        assert_eq!(yaml_string("Work with PDFs"), "Work with PDFs");
+        assert_eq!(yaml_string("Note: careful"), "\"Note: careful\"");
+        assert_eq!(yaml_string("**"), "\"**\"");
+    }
+}
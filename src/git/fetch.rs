@@ -6,6 +6,7 @@
 
 use crate::cache::{
     checkout_name, checkouts_dir, db_dir, db_name, ensure_dir, is_offline, parse_owner_repo,
+    record_use,
 };
 use crate::git::source::GitSource;
 use crate::SkiloError;
@@ -84,12 +85,14 @@ fn fetch_cached(source: &GitSource, owner: &str, repo: &str) -> Result<FetchResu
     let short_commit = &commit_id[..7.min(commit_id.len())];
 
     // Check if we already have this checkout
-    let checkout_path = checkouts.join(checkout_name(owner, repo, &commit_id));
+    let checkout = checkout_name(owner, repo, &commit_id);
+    let checkout_path = checkouts.join(&checkout);
 
     if !checkout_path.exists() {
         // Create the checkout from the bare repo
         checkout_from_bare(&bare_repo, &commit_id, &checkout_path)?;
     }
+    record_use(&db_name(owner, repo), &checkout);
 
     // Determine the root path (may be a subdirectory)
     let root = if let Some(ref subdir) = source.subdir {
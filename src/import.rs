@@ -0,0 +1,529 @@
+//! Conversion of agent-specific prompt files into skills.
+//!
+//! Cursor rules (`.cursor/rules/*.mdc`), Claude slash commands
+//! (`.claude/commands/*.md`) and Copilot prompt files
+//! (`.github/prompts/*.prompt.md`) carry the same kind of instructions as a
+//! skill. Each one becomes a skill directory named after the file, with its
+//! frontmatter mapped onto the skill spec and long bodies split into
+//! `references/`.
+
+use crate::skill::{Formatter, FormatterConfig, Frontmatter, Manifest};
+use serde_yaml::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Maximum length of a skill name.
+const MAX_NAME_LENGTH: usize = 64;
+
+/// Maximum length of a skill description.
+const MAX_DESCRIPTION_LENGTH: usize = 1024;
+
+/// A file format that can be imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SourceFormat {
+    /// A Cursor rule (`.cursor/rules/*.mdc`).
+    CursorRule,
+    /// A Claude Code slash command (`.claude/commands/*.md`).
+    ClaudeCommand,
+    /// A GitHub Copilot prompt file (`.github/prompts/*.prompt.md`).
+    CopilotPrompt,
+}
+
+impl SourceFormat {
+    /// Detect the format of `path` from its name and location.
+    pub fn detect(path: &Path) -> Option<Self> {
+        let file_name = path.file_name()?.to_str()?;
+        if file_name.ends_with(".mdc") {
+            Some(Self::CursorRule)
+        } else if file_name.ends_with(".prompt.md") {
+            Some(Self::CopilotPrompt)
+        } else if file_name.ends_with(".md") && Self::ClaudeCommand.root_of(path).is_some() {
+            Some(Self::ClaudeCommand)
+        } else {
+            None
+        }
+    }
+
+    /// Directory name that holds files of this format.
+    fn root_dir(&self) -> &'static str {
+        match self {
+            Self::CursorRule => "rules",
+            Self::ClaudeCommand => "commands",
+            Self::CopilotPrompt => "prompts",
+        }
+    }
+
+    /// File name suffix of this format.
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::CursorRule => ".mdc",
+            Self::ClaudeCommand => ".md",
+            Self::CopilotPrompt => ".prompt.md",
+        }
+    }
+
+    /// The nearest ancestor of `path` named after [`Self::root_dir`].
+    fn root_of<'a>(&self, path: &'a Path) -> Option<&'a Path> {
+        path.ancestors()
+            .skip(1)
+            .find(|dir| dir.file_name().is_some_and(|n| n == self.root_dir()))
+    }
+}
+
+impl fmt::Display for SourceFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CursorRule => write!(f, "Cursor rule"),
+            Self::ClaudeCommand => write!(f, "Claude command"),
+            Self::CopilotPrompt => write!(f, "Copilot prompt"),
+        }
+    }
+}
+
+/// Importable files under `path`, or `path` itself if it is one.
+pub fn find_sources(path: &Path) -> Vec<(PathBuf, SourceFormat)> {
+    if path.is_file() {
+        return SourceFormat::detect(path)
+            .map(|format| vec![(path.to_path_buf(), format)])
+            .unwrap_or_default();
+    }
+
+    let mut sources: Vec<(PathBuf, SourceFormat)> = WalkDir::new(path)
+        .into_iter()
+        .filter_entry(|e| !matches!(e.file_name().to_str(), Some("node_modules" | ".git")))
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let format = SourceFormat::detect(e.path())?;
+            Some((e.into_path(), format))
+        })
+        .collect();
+    sources.sort();
+    sources
+}
+
+/// A skill converted from an imported file.
+#[derive(Debug)]
+pub struct ImportedSkill {
+    /// The file the skill was converted from.
+    pub source: PathBuf,
+    /// Format of the source file.
+    pub format: SourceFormat,
+    /// Skill frontmatter.
+    pub frontmatter: Frontmatter,
+    /// SKILL.md body.
+    pub body: String,
+    /// Files to write under `references/`, as (file name, content).
+    pub references: Vec<(String, String)>,
+    /// Frontmatter fields that could not be mapped.
+    pub warnings: Vec<String>,
+}
+
+impl ImportedSkill {
+    /// Skill name.
+    pub fn name(&self) -> &str {
+        &self.frontmatter.name
+    }
+
+    /// Write the skill directory under `output_dir`, returning its SKILL.md path.
+    pub fn write(&self, output_dir: &Path) -> std::io::Result<PathBuf> {
+        let skill_dir = output_dir.join(self.name());
+        std::fs::create_dir_all(&skill_dir)?;
+
+        if !self.references.is_empty() {
+            let references_dir = skill_dir.join("references");
+            std::fs::create_dir_all(&references_dir)?;
+            for (file_name, content) in &self.references {
+                std::fs::write(references_dir.join(file_name), content)?;
+            }
+        }
+
+        let path = skill_dir.join("SKILL.md");
+        let manifest = Manifest {
+            path: path.clone(),
+            frontmatter: self.frontmatter.clone(),
+            frontmatter_raw: String::new(),
+            body: self.body.clone(),
+            body_start_line: 0,
+        };
+        let content = Formatter::new(FormatterConfig::default())
+            .format(&manifest)
+            .map_err(std::io::Error::other)?;
+        std::fs::write(&path, content)?;
+
+        Ok(path)
+    }
+}
+
+/// Convert the file at `path` into a skill.
+///
+/// Bodies longer than `max_body_lines` are split into `references/`.
+pub fn convert(
+    path: &Path,
+    format: SourceFormat,
+    max_body_lines: usize,
+) -> std::io::Result<ImportedSkill> {
+    let content = std::fs::read_to_string(path)?;
+    let (fields, body) = split_frontmatter(&content);
+
+    let name = skill_name(path, format);
+    let mut metadata = BTreeMap::new();
+    let mut description = None;
+    let mut allowed_tools = None;
+    let mut warnings = Vec::new();
+
+    for (key, value) in fields {
+        let Some(text) = scalar_string(&value, if key == "tools" { " " } else { "," }) else {
+            warnings.push(format!("{}: ignored field '{}'", path.display(), key));
+            continue;
+        };
+        match (format, key.as_str()) {
+            (_, "description") => description = Some(text),
+            (_, "name") => {}
+            (SourceFormat::CursorRule, "alwaysApply") => {
+                metadata.insert("always-apply".to_string(), text);
+            }
+            (SourceFormat::ClaudeCommand, "allowed-tools")
+            | (SourceFormat::CopilotPrompt, "tools") => allowed_tools = Some(text),
+            _ => {
+                metadata.insert(kebab_case(&key), text);
+            }
+        }
+    }
+    metadata.retain(|_, value| !value.is_empty());
+
+    let description = description
+        .filter(|d| !d.trim().is_empty())
+        .or_else(|| first_sentence(body))
+        .unwrap_or_else(|| format!("Imported from {}", file_name(path)));
+    let description: String = description
+        .trim()
+        .chars()
+        .take(MAX_DESCRIPTION_LENGTH)
+        .collect();
+
+    let (body, references) = split_body(body.trim(), &name, max_body_lines);
+
+    Ok(ImportedSkill {
+        source: path.to_path_buf(),
+        format,
+        frontmatter: Frontmatter {
+            name,
+            description,
+            license: None,
+            compatibility: None,
+            metadata: (!metadata.is_empty()).then_some(metadata),
+            allowed_tools,
+        },
+        body,
+        references,
+        warnings,
+    })
+}
+
+/// Derive a valid skill name from the file's path below its format directory.
+///
+/// `.claude/commands/frontend/newComponent.md` becomes `frontend-new-component`.
+pub fn skill_name(path: &Path, format: SourceFormat) -> String {
+    let relative = format
+        .root_of(path)
+        .and_then(|root| path.strip_prefix(root).ok())
+        .unwrap_or_else(|| Path::new(path.file_name().unwrap_or_default()));
+    let relative = relative.to_string_lossy();
+    let stem = relative
+        .strip_suffix(format.extension())
+        .unwrap_or(&relative);
+
+    let name = kebab_case(stem);
+    if name.is_empty() {
+        "imported-skill".to_string()
+    } else {
+        name
+    }
+}
+
+/// Lowercase `text` and join its words with single hyphens.
+fn kebab_case(text: &str) -> String {
+    let mut name = String::new();
+    let mut prev: Option<char> = None;
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            let word_start = c.is_ascii_uppercase()
+                && prev.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit());
+            if word_start && !name.is_empty() && !name.ends_with('-') {
+                name.push('-');
+            }
+            name.push(c.to_ascii_lowercase());
+        } else if !name.is_empty() && !name.ends_with('-') {
+            name.push('-');
+        }
+        prev = Some(c);
+    }
+
+    let mut name = name.trim_end_matches('-').to_string();
+    if name.len() > MAX_NAME_LENGTH {
+        name.truncate(MAX_NAME_LENGTH);
+        name = name.trim_end_matches('-').to_string();
+    }
+    name
+}
+
+/// Split optional frontmatter from `content`.
+///
+/// Cursor rules often contain values such as `globs: *.ts` that are not
+/// valid YAML, so unparsable frontmatter is read as plain `key: value` lines.
+fn split_frontmatter(content: &str) -> (Vec<(String, Value)>, &str) {
+    let trimmed = content.trim_start();
+    let Some(after_open) = trimmed.strip_prefix("---") else {
+        return (Vec::new(), content);
+    };
+    let Some(close) = after_open.find("\n---") else {
+        return (Vec::new(), content);
+    };
+    let raw = &after_open[..close];
+    let body = after_open[close + 4..].trim_start_matches('-');
+
+    let fields = match serde_yaml::from_str::<Value>(raw) {
+        Ok(Value::Mapping(mapping)) => mapping
+            .into_iter()
+            .filter_map(|(k, v)| Some((k.as_str()?.to_string(), v)))
+            .collect(),
+        Ok(_) => Vec::new(),
+        Err(_) => raw
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .map(|(k, v)| {
+                (
+                    k.trim().to_string(),
+                    Value::String(v.trim().trim_matches(['"', '\'']).to_string()),
+                )
+            })
+            .collect(),
+    };
+    (fields, body)
+}
+
+/// `value` as a string, joining lists with `separator`.
+fn scalar_string(value: &Value, separator: &str) -> Option<String> {
+    match value {
+        Value::Null => Some(String::new()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::String(s) => Some(s.trim().to_string()),
+        Value::Sequence(items) => items
+            .iter()
+            .map(|item| scalar_string(item, separator))
+            .collect::<Option<Vec<_>>>()
+            .map(|items| items.join(separator)),
+        _ => None,
+    }
+}
+
+/// The first line of prose in `body`, used when a file has no description.
+fn first_sentence(body: &str) -> Option<String> {
+    let mut in_fence = false;
+    for line in body.lines() {
+        let line = line.trim();
+        if line.starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence || line.is_empty() || line.starts_with('#') || line.starts_with("<!--") {
+            continue;
+        }
+        let line = line.trim_start_matches(['-', '*', '>', ' ']);
+        if !line.is_empty() {
+            return Some(line.to_string());
+        }
+    }
+    None
+}
+
+/// Keep short bodies as they are; move long ones into `references/`.
+///
+/// Each `## ` section becomes its own reference file, linked from the
+/// introduction. Bodies without sections move to `references/<name>.md`.
+fn split_body(body: &str, name: &str, max_lines: usize) -> (String, Vec<(String, String)>) {
+    if body.lines().count() <= max_lines {
+        return (format!("{}\n", body), Vec::new());
+    }
+
+    let mut intro = Vec::new();
+    let mut sections: Vec<(String, Vec<&str>)> = Vec::new();
+    let mut in_fence = false;
+    for line in body.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+        match line.strip_prefix("## ") {
+            Some(title) if !in_fence => sections.push((title.trim().to_string(), vec![line])),
+            _ => match sections.last_mut() {
+                Some((_, lines)) => lines.push(line),
+                None => intro.push(line),
+            },
+        }
+    }
+
+    if sections.is_empty() {
+        let file_name = format!("{}.md", name);
+        let title = intro
+            .first()
+            .and_then(|line| line.strip_prefix("# "))
+            .map(|title| format!("# {}\n\n", title.trim()))
+            .unwrap_or_default();
+        let skill_body = format!(
+            "{}Read `references/{}` for the full instructions.\n",
+            title, file_name
+        );
+        return (skill_body, vec![(file_name, format!("{}\n", body))]);
+    }
+
+    let mut skill_body = intro.join("\n").trim().to_string();
+    if !skill_body.is_empty() {
+        skill_body.push_str("\n\n");
+    }
+    skill_body.push_str("## References\n");
+
+    let mut references: Vec<(String, String)> = Vec::new();
+    for (title, lines) in sections {
+        let mut stem = kebab_case(&title);
+        if stem.is_empty() {
+            stem = "section".to_string();
+        }
+        let mut file_name = format!("{}.md", stem);
+        let mut n = 2;
+        while references.iter().any(|(f, _)| *f == file_name) {
+            file_name = format!("{}-{}.md", stem, n);
+            n += 1;
+        }
+        skill_body.push_str(&format!("\n- {}: `references/{}`", title, file_name));
+        references.push((file_name, format!("{}\n", lines.join("\n").trim())));
+    }
+    skill_body.push('\n');
+
+    (skill_body, references)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, relative: &str, content: &str) -> PathBuf {
+        let path = root.join(relative);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_detect_and_name() {
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), ".cursor/rules/React_Components.mdc", "");
+        write(temp.path(), ".claude/commands/frontend/newComponent.md", "");
+        write(temp.path(), ".github/prompts/review-pr.prompt.md", "");
+        write(temp.path(), "docs/README.md", "");
+
+        let names: Vec<(String, SourceFormat)> = find_sources(temp.path())
+            .into_iter()
+            .map(|(path, format)| (skill_name(&path, format), format))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                (
+                    "frontend-new-component".to_string(),
+                    SourceFormat::ClaudeCommand
+                ),
+                ("react-components".to_string(), SourceFormat::CursorRule),
+                ("review-pr".to_string(), SourceFormat::CopilotPrompt),
+            ]
+        );
+        assert_eq!(kebab_case("--API  v2__Client--"), "api-v2-client");
+    }
+
+    #[test]
+    fn test_convert_cursor_rule_with_non_yaml_globs() {
+        let temp = TempDir::new().unwrap();
+        let path = write(
+            temp.path(),
+            ".cursor/rules/typescript.mdc",
+            "---\ndescription: TypeScript conventions\nglobs: *.ts,*.tsx\nalwaysApply: false\n---\n\n# TypeScript\n\nUse strict mode.\n",
+        );
+
+        let skill = convert(&path, SourceFormat::CursorRule, 500).unwrap();
+        assert_eq!(skill.name(), "typescript");
+        assert_eq!(skill.frontmatter.description, "TypeScript conventions");
+        let metadata = skill.frontmatter.metadata.unwrap();
+        assert_eq!(metadata["globs"], "*.ts,*.tsx");
+        assert_eq!(metadata["always-apply"], "false");
+        assert_eq!(skill.body, "# TypeScript\n\nUse strict mode.\n");
+    }
+
+    #[test]
+    fn test_convert_commands_and_prompts() {
+        let temp = TempDir::new().unwrap();
+        let command = write(
+            temp.path(),
+            ".claude/commands/commit.md",
+            "---\nallowed-tools: Bash(git add:*), Bash(git commit:*)\nargument-hint: [message]\n---\n\nCreate a commit for the staged changes.\n",
+        );
+        let skill = convert(&command, SourceFormat::ClaudeCommand, 500).unwrap();
+        assert_eq!(
+            skill.frontmatter.description,
+            "Create a commit for the staged changes."
+        );
+        assert_eq!(
+            skill.frontmatter.allowed_tools.as_deref(),
+            Some("Bash(git add:*), Bash(git commit:*)")
+        );
+        assert_eq!(
+            skill.frontmatter.metadata.unwrap()["argument-hint"],
+            "message"
+        );
+
+        let prompt = write(
+            temp.path(),
+            ".github/prompts/review.prompt.md",
+            "---\nmode: agent\ntools: ['codebase', 'githubRepo']\n---\nReview the PR.\n",
+        );
+        let skill = convert(&prompt, SourceFormat::CopilotPrompt, 500).unwrap();
+        assert_eq!(
+            skill.frontmatter.allowed_tools.as_deref(),
+            Some("codebase githubRepo")
+        );
+        assert_eq!(skill.frontmatter.metadata.unwrap()["mode"], "agent");
+    }
+
+    #[test]
+    fn test_long_body_moves_sections_to_references() {
+        let temp = TempDir::new().unwrap();
+        let path = write(
+            temp.path(),
+            ".claude/commands/deploy.md",
+            "Deploy the app.\n\n## Setup\n\nInstall tools.\n\n## Release Steps\n\n```sh\n## not a heading\n```\n",
+        );
+
+        let skill = convert(&path, SourceFormat::ClaudeCommand, 4).unwrap();
+        assert_eq!(
+            skill.body,
+            "Deploy the app.\n\n## References\n\n- Setup: `references/setup.md`\n- Release Steps: `references/release-steps.md`\n"
+        );
+        assert_eq!(skill.references[0].1, "## Setup\n\nInstall tools.\n");
+        assert!(skill.references[1].1.contains("## not a heading"));
+
+        let skill_md = skill.write(&temp.path().join("skills")).unwrap();
+        let manifest = Manifest::parse(skill_md).unwrap();
+        assert!(crate::skill::Validator::default()
+            .validate(&manifest)
+            .is_ok());
+    }
+}
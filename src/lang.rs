@@ -2,34 +2,96 @@
 
 use crate::cli::ScriptLang;
 
+/// Dependency manifests and lock files that may sit next to scripts.
+///
+/// These are not scripts themselves and are skipped by the script rules.
+pub const MANIFEST_FILES: &[&str] = &[
+    "requirements.txt",
+    "pyproject.toml",
+    "uv.lock",
+    "package.json",
+    "package-lock.json",
+    "tsconfig.json",
+    "deno.json",
+    "deno.lock",
+    "go.mod",
+    "go.sum",
+    "Cargo.toml",
+    "Cargo.lock",
+    "Gemfile",
+    "Gemfile.lock",
+];
+
 impl ScriptLang {
+    /// Returns the language for a file extension, if known.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "py" => Some(Self::Python),
+            "sh" => Some(Self::Bash),
+            "js" => Some(Self::Javascript),
+            "ts" => Some(Self::Typescript),
+            "go" => Some(Self::Go),
+            "rs" => Some(Self::Rust),
+            "rb" => Some(Self::Ruby),
+            "ps1" => Some(Self::Powershell),
+            _ => None,
+        }
+    }
+
     /// Returns the file extension for this language.
     pub fn extension(&self) -> &'static str {
         match self {
-            Self::Python => "py",
+            Self::Python | Self::Uv => "py",
             Self::Bash => "sh",
             Self::Javascript => "js",
-            Self::Typescript => "ts",
+            Self::Typescript | Self::Deno => "ts",
+            Self::Go => "go",
+            Self::Rust => "rs",
+            Self::Ruby => "rb",
+            Self::Powershell => "ps1",
         }
     }
 
     /// Returns the shebang line for this language.
+    ///
+    /// Go does not allow `#!`, so Go programs start with a `//` line that
+    /// the shell runs instead when the file has no shebang.
     pub fn shebang(&self) -> &'static str {
         match self {
             Self::Python => "#!/usr/bin/env python3",
+            Self::Uv => "#!/usr/bin/env -S uv run --script",
             Self::Bash => "#!/usr/bin/env bash",
             Self::Javascript => "#!/usr/bin/env node",
-            Self::Typescript => "#!/usr/bin/env -S npx ts-node",
+            Self::Typescript => "#!/usr/bin/env -S npx --yes tsx",
+            Self::Deno => "#!/usr/bin/env -S deno run",
+            Self::Go => "//usr/bin/env go run \"$0\" \"$@\"; exit",
+            Self::Rust => "#!/usr/bin/env rust-script",
+            Self::Ruby => "#!/usr/bin/env ruby",
+            Self::Powershell => "#!/usr/bin/env pwsh",
+        }
+    }
+
+    /// Returns the lines a new script starts with: the shebang, followed by
+    /// inline dependency metadata for languages that support it.
+    pub fn header(&self) -> String {
+        match self {
+            Self::Uv => format!(
+                "{}\n# /// script\n# requires-python = \">=3.10\"\n# dependencies = []\n# ///",
+                self.shebang()
+            ),
+            Self::Rust => format!(
+                "{}\n//! ```cargo\n//! [dependencies]\n//! ```",
+                self.shebang()
+            ),
+            _ => self.shebang().to_string(),
         }
     }
 
     /// Returns the comment prefix for this language.
     pub fn comment_prefix(&self) -> &'static str {
         match self {
-            Self::Python => "#",
-            Self::Bash => "#",
-            Self::Javascript => "//",
-            Self::Typescript => "//",
+            Self::Python | Self::Uv | Self::Bash | Self::Ruby | Self::Powershell => "#",
+            Self::Javascript | Self::Typescript | Self::Deno | Self::Go | Self::Rust => "//",
         }
     }
 
@@ -37,4 +99,115 @@ impl ScriptLang {
     pub fn file_name(&self, name: &str) -> String {
         format!("{}.{}", name, self.extension())
     }
+
+    /// Returns the dependency manifest to create next to the scripts, as
+    /// (file name, content), for languages without inline dependencies.
+    pub fn manifest(&self, name: &str) -> Option<(&'static str, String)> {
+        match self {
+            Self::Python => Some((
+                "requirements.txt",
+                format!("# Dependencies for the {} scripts\n", name),
+            )),
+            Self::Javascript => Some((
+                "package.json",
+                format!(
+                    "{{\n  \"name\": \"{}\",\n  \"private\": true,\n  \"dependencies\": {{}}\n}}\n",
+                    name
+                ),
+            )),
+            Self::Typescript => Some((
+                "package.json",
+                format!(
+                    "{{\n  \"name\": \"{}\",\n  \"private\": true,\n  \"dependencies\": {{}},\n  \"devDependencies\": {{\n    \"tsx\": \"^4\"\n  }}\n}}\n",
+                    name
+                ),
+            )),
+            Self::Deno => Some(("deno.json", "{\n  \"imports\": {}\n}\n".to_string())),
+            Self::Go => Some(("go.mod", format!("module {}\n\ngo 1.22\n", name))),
+            Self::Ruby => Some((
+                "Gemfile",
+                "source \"https://rubygems.org\"\n".to_string(),
+            )),
+            Self::Uv | Self::Rust | Self::Bash | Self::Powershell => None,
+        }
+    }
+
+    /// Interpreters that can run scripts with this language's extension.
+    pub fn interpreters(&self) -> &'static [&'static str] {
+        match self {
+            Self::Python | Self::Uv => &["python", "pypy", "uv"],
+            Self::Bash => &["bash", "sh", "zsh", "dash", "ksh"],
+            Self::Javascript => &["node", "bun", "deno"],
+            Self::Typescript | Self::Deno => &["npx", "tsx", "ts-node", "bun", "deno"],
+            Self::Go => &["go", "gorun"],
+            Self::Rust => &["rust-script", "cargo"],
+            Self::Ruby => &["ruby"],
+            Self::Powershell => &["pwsh", "powershell"],
+        }
+    }
+
+    /// Returns true if `interpreter` (e.g. `python3.12`) runs this language.
+    pub fn runs_with(&self, interpreter: &str) -> bool {
+        self.interpreters().iter().any(|known| {
+            interpreter
+                .strip_prefix(known)
+                .is_some_and(|version| version.chars().all(|c| c.is_ascii_digit() || c == '.'))
+        })
+    }
+
+    /// The interpreter named by a script's first line, if it is a shebang.
+    ///
+    /// Looks through `/usr/bin/env` (and its flags) and also recognizes the
+    /// `//usr/bin/env go run` header used by Go programs.
+    pub fn interpreter(first_line: &str) -> Option<String> {
+        let command = first_line
+            .strip_prefix("#!")
+            .or_else(|| first_line.strip_prefix("//"))?;
+        let mut words = command.split_whitespace();
+        let program = words.next()?;
+        if first_line.starts_with("//") && !program.ends_with("/env") {
+            return None;
+        }
+
+        let program = if program.ends_with("/env") {
+            words.find(|word| !word.starts_with('-'))?
+        } else {
+            program
+        };
+        program.rsplit('/').next().map(str::to_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interpreter() {
+        let interpreter = |line| ScriptLang::interpreter(line);
+        assert_eq!(
+            interpreter("#!/usr/bin/env python3").as_deref(),
+            Some("python3")
+        );
+        assert_eq!(interpreter("#!/bin/bash -e").as_deref(), Some("bash"));
+        assert_eq!(
+            interpreter("#!/usr/bin/env -S uv run --script").as_deref(),
+            Some("uv")
+        );
+        assert_eq!(interpreter(ScriptLang::Go.shebang()).as_deref(), Some("go"));
+        assert_eq!(interpreter("// Package main does things."), None);
+        assert_eq!(interpreter("import sys"), None);
+    }
+
+    #[test]
+    fn test_every_language_runs_with_its_own_shebang() {
+        for lang in <ScriptLang as clap::ValueEnum>::value_variants() {
+            let interpreter = ScriptLang::interpreter(lang.shebang()).unwrap();
+            let by_extension = ScriptLang::from_extension(lang.extension()).unwrap();
+            assert!(by_extension.runs_with(&interpreter), "{:?}", lang);
+        }
+        assert!(ScriptLang::Python.runs_with("python3.12"));
+        assert!(!ScriptLang::Python.runs_with("pythonista"));
+        assert!(!ScriptLang::Ruby.runs_with("node"));
+    }
 }
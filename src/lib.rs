@@ -8,12 +8,19 @@ pub mod cache;
 pub mod cli;
 pub mod commands;
 pub mod config;
+pub mod diff;
 pub mod error;
+pub mod export;
 pub mod git;
+pub mod import;
 pub mod lang;
+pub mod lsp;
+pub mod mcp;
 pub mod output;
+pub mod prompt;
 pub mod scope;
 pub mod skill;
+pub mod sync;
 pub mod templates;
 
 pub use error::{Result, SkiloError};
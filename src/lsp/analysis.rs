@@ -0,0 +1,666 @@
+//! Editor features computed from the text of an open SKILL.md.
+//!
+//! Everything here is a pure function of the document text and the files
+//! next to it, so it can be tested without a running server.
+
+use crate::cli::ScriptLang;
+use crate::skill::manifest::ManifestError;
+use crate::skill::rules::{registry, ScriptShebangRule};
+use crate::skill::{DiagnosticCode, Formatter, Frontmatter, Manifest, Validator};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeDescription, CompletionItem, CompletionItemKind, Diagnostic,
+    DiagnosticRelatedInformation, DiagnosticSeverity, Hover, HoverContents, Location,
+    MarkupContent, MarkupKind, NumberOrString, Position, Range, TextEdit, Url, WorkspaceEdit,
+};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Tools commonly granted through `allowed-tools`.
+const KNOWN_TOOLS: &[&str] = &[
+    "Bash",
+    "Edit",
+    "Glob",
+    "Grep",
+    "NotebookEdit",
+    "Read",
+    "Task",
+    "WebFetch",
+    "WebSearch",
+    "Write",
+];
+
+/// Directories whose files can be referenced from the skill body.
+const REFERENCE_DIRS: &[&str] = &["scripts", "references", "assets"];
+
+/// Diagnostic codes such as `E009` or `W001`.
+static CODE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[EW]\d{3}$").unwrap());
+
+/// An open SKILL.md document.
+pub struct Document {
+    /// Path of the file on disk.
+    pub path: PathBuf,
+    /// Current, possibly unsaved, contents.
+    pub text: String,
+}
+
+impl Document {
+    fn lines(&self) -> Vec<&str> {
+        self.text.split('\n').collect()
+    }
+
+    fn skill_dir(&self) -> &Path {
+        self.path.parent().unwrap_or(Path::new("."))
+    }
+
+    /// Line indices of the opening and closing `---` of the frontmatter.
+    fn frontmatter_bounds(&self) -> Option<(usize, usize)> {
+        let lines = self.lines();
+        let open = lines.iter().position(|l| !l.trim().is_empty())?;
+        if lines[open].trim_end() != "---" {
+            return None;
+        }
+        let close = lines[open + 1..]
+            .iter()
+            .position(|l| l.trim_end() == "---")?;
+        Some((open, open + 1 + close))
+    }
+
+    /// Range of the value of a top-level frontmatter key.
+    fn frontmatter_value_range(&self, key: &str) -> Option<Range> {
+        let (open, close) = self.frontmatter_bounds()?;
+        let prefix = format!("{}:", key);
+        self.lines()[open + 1..close]
+            .iter()
+            .enumerate()
+            .find(|(_, line)| line.starts_with(&prefix))
+            .map(|(i, line)| {
+                let value = line[prefix.len()..].trim();
+                let start = line.len() - line[prefix.len()..].trim_start().len();
+                let line_no = (open + 1 + i) as u32;
+                Range::new(
+                    Position::new(line_no, char_index(line, start)),
+                    Position::new(line_no, char_index(line, start + value.len())),
+                )
+            })
+    }
+}
+
+/// Validate the document and convert the findings into LSP diagnostics.
+pub fn diagnostics(doc: &Document, validator: &Validator) -> Vec<Diagnostic> {
+    let manifest = match Manifest::parse_content(doc.path.clone(), &doc.text) {
+        Ok(manifest) => manifest,
+        Err(e) => return vec![parse_error(doc, &e)],
+    };
+
+    let result = validator.validate(&manifest);
+    result
+        .errors
+        .iter()
+        .chain(result.warnings.iter())
+        .map(|diag| {
+            let meta = diag.code.meta();
+            let mut message = diag.message.clone();
+            if let Some(hint) = &diag.fix_hint {
+                message.push_str(&format!("\nhint: {}", hint));
+            }
+
+            let mut lsp_diag = Diagnostic {
+                range: locate(doc, diag.code, diag.line, diag.column, &diag.message),
+                severity: Some(if diag.code.is_error() {
+                    DiagnosticSeverity::ERROR
+                } else {
+                    DiagnosticSeverity::WARNING
+                }),
+                code: Some(NumberOrString::String(diag.code.to_string())),
+                code_description: Url::parse(&meta.help_uri())
+                    .ok()
+                    .map(|href| CodeDescription { href }),
+                source: Some("skilo".to_string()),
+                message,
+                ..Default::default()
+            };
+
+            // Script diagnostics are about another file; point at it
+            let diag_path = Path::new(&diag.path);
+            if diag_path != doc.path {
+                if let Ok(uri) = Url::from_file_path(diag_path) {
+                    let relative = diag_path.strip_prefix(doc.skill_dir()).unwrap_or(diag_path);
+                    lsp_diag.message = format!("{}: {}", relative.display(), lsp_diag.message);
+                    lsp_diag.related_information = Some(vec![DiagnosticRelatedInformation {
+                        location: Location::new(uri.clone(), Range::default()),
+                        message: diag.message.clone(),
+                    }]);
+                    lsp_diag.data = Some(serde_json::json!({ "uri": uri }));
+                }
+            }
+
+            lsp_diag
+        })
+        .collect()
+}
+
+fn parse_error(doc: &Document, error: &ManifestError) -> Diagnostic {
+    // YAML errors are located within the frontmatter, which starts after the opening ---
+    let line = match (error, doc.frontmatter_bounds()) {
+        (ManifestError::InvalidYaml(e), Some((open, _))) => e
+            .location()
+            .map(|loc| (open + loc.line()) as u32)
+            .unwrap_or(0),
+        _ => 0,
+    };
+
+    Diagnostic {
+        range: line_range(doc, line),
+        severity: Some(DiagnosticSeverity::ERROR),
+        code: Some(NumberOrString::String(DiagnosticCode::E007.to_string())),
+        source: Some("skilo".to_string()),
+        message: error.to_string(),
+        ..Default::default()
+    }
+}
+
+/// Find the range a diagnostic applies to.
+fn locate(
+    doc: &Document,
+    code: DiagnosticCode,
+    line: Option<usize>,
+    column: Option<usize>,
+    message: &str,
+) -> Range {
+    // Name and description rules report fixed positions; prefer the actual key
+    let key = match code {
+        DiagnosticCode::E001 | DiagnosticCode::E002 | DiagnosticCode::E003 => Some("name"),
+        DiagnosticCode::E004 | DiagnosticCode::E005 => Some("description"),
+        DiagnosticCode::E006 => Some("compatibility"),
+        _ => None,
+    };
+    if let Some(range) = key.and_then(|k| doc.frontmatter_value_range(k)) {
+        if range.start != range.end {
+            return range;
+        }
+        return line_range(doc, range.start.line);
+    }
+
+    // Missing references are reported without a position; find the backticked path
+    if code == DiagnosticCode::E009 {
+        if let Some(reference) = message.rsplit(": ").next() {
+            let needle = format!("`{}`", reference);
+            for (i, text) in doc.lines().iter().enumerate() {
+                if let Some(start) = text.find(&needle) {
+                    return Range::new(
+                        Position::new(i as u32, char_index(text, start + 1)),
+                        Position::new(i as u32, char_index(text, start + needle.len() - 1)),
+                    );
+                }
+            }
+        }
+    }
+
+    match line {
+        Some(line) => {
+            let mut range = line_range(doc, line.saturating_sub(1) as u32);
+            if let Some(col) = column {
+                let lines = doc.lines();
+                let text = lines.get(line.saturating_sub(1)).copied().unwrap_or("");
+                let byte = text
+                    .char_indices()
+                    .nth(col.saturating_sub(1))
+                    .map(|(i, _)| i)
+                    .unwrap_or(text.len());
+                range.start.character = char_index(text, byte);
+            }
+            range
+        }
+        None => Range::default(),
+    }
+}
+
+/// Range covering the whole of line `line`.
+fn line_range(doc: &Document, line: u32) -> Range {
+    let lines = doc.lines();
+    let len = lines
+        .get(line as usize)
+        .map(|l| char_index(l, l.len()))
+        .unwrap_or(0);
+    Range::new(Position::new(line, 0), Position::new(line, len))
+}
+
+/// Convert a byte offset within `line` into an LSP character offset.
+///
+/// LSP positions count UTF-16 code units, so characters outside the basic
+/// multilingual plane (such as emoji) take two.
+fn char_index(line: &str, byte: usize) -> u32 {
+    line[..byte.min(line.len())].encode_utf16().count() as u32
+}
+
+/// Convert an LSP character offset within `line` into a byte offset.
+fn byte_index(line: &str, character: u32) -> usize {
+    let mut units = 0;
+    for (i, c) in line.char_indices() {
+        if units >= character as usize {
+            return i;
+        }
+        units += c.len_utf16();
+    }
+    line.len()
+}
+
+/// Code actions that fix the given diagnostics.
+pub fn code_actions(doc: &Document, uri: &Url, diagnostics: &[Diagnostic]) -> Vec<CodeAction> {
+    let mut actions = Vec::new();
+
+    for diag in diagnostics {
+        let Some(NumberOrString::String(code)) = &diag.code else {
+            continue;
+        };
+
+        match code.as_str() {
+            "E001" => {
+                if let Some(range) = doc.frontmatter_value_range("name") {
+                    let current = &doc.lines()[range.start.line as usize];
+                    let value = current.split_once(':').map(|(_, v)| v.trim()).unwrap_or("");
+                    let fixed = sanitize_name(value);
+                    if !fixed.is_empty() && fixed != value {
+                        actions.push(replace_action(
+                            format!("Rename skill to '{}'", fixed),
+                            uri,
+                            range,
+                            fixed,
+                            diag,
+                        ));
+                    }
+                }
+            }
+            "E003" => {
+                let dir_name = doc
+                    .skill_dir()
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .map(str::to_string);
+                if let (Some(dir_name), Some(range)) =
+                    (dir_name, doc.frontmatter_value_range("name"))
+                {
+                    actions.push(replace_action(
+                        format!("Rename skill to match directory '{}'", dir_name),
+                        uri,
+                        range,
+                        dir_name,
+                        diag,
+                    ));
+                }
+            }
+            "W003" => {
+                let script_uri = diag
+                    .data
+                    .as_ref()
+                    .and_then(|d| d.get("uri"))
+                    .and_then(|u| u.as_str())
+                    .and_then(|u| Url::parse(u).ok());
+                let lang = script_uri
+                    .as_ref()
+                    .and_then(|u| u.to_file_path().ok())
+                    .and_then(|p| p.extension().and_then(|e| e.to_str()).map(str::to_string))
+                    .and_then(|ext| ScriptLang::from_extension(&ext));
+                if let (Some(script_uri), Some(lang)) = (script_uri, lang) {
+                    // A wrong shebang is replaced, a missing one inserted
+                    let missing = diag.message == ScriptShebangRule::MISSING_MESSAGE;
+                    let (verb, range) = if missing {
+                        ("Add", Range::default())
+                    } else {
+                        ("Use", Range::new(Position::new(0, 0), Position::new(1, 0)))
+                    };
+                    actions.push(CodeAction {
+                        title: format!("{} '{}'", verb, lang.shebang()),
+                        kind: Some(CodeActionKind::QUICKFIX),
+                        diagnostics: Some(vec![diag.clone()]),
+                        edit: Some(WorkspaceEdit {
+                            changes: Some(HashMap::from([(
+                                script_uri,
+                                vec![TextEdit::new(range, format!("{}\n", lang.shebang()))],
+                            )])),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    actions
+}
+
+fn replace_action(
+    title: String,
+    uri: &Url,
+    range: Range,
+    new_text: String,
+    diag: &Diagnostic,
+) -> CodeAction {
+    CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diag.clone()]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(
+                uri.clone(),
+                vec![TextEdit::new(range, new_text)],
+            )])),
+            ..Default::default()
+        }),
+        is_preferred: Some(true),
+        ..Default::default()
+    }
+}
+
+/// Turn an arbitrary string into a valid skill name.
+fn sanitize_name(name: &str) -> String {
+    let mapped: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    mapped
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Edits that replace the document with its formatted version.
+pub fn format(doc: &Document, formatter: &Formatter) -> Option<Vec<TextEdit>> {
+    let manifest = Manifest::parse_content(doc.path.clone(), &doc.text).ok()?;
+    let formatted = formatter.format(&manifest).ok()?;
+    if formatted == doc.text {
+        return Some(Vec::new());
+    }
+
+    let lines = doc.lines();
+    let last = lines.len().saturating_sub(1);
+    let end = Position::new(last as u32, char_index(lines[last], lines[last].len()));
+    Some(vec![TextEdit::new(
+        Range::new(Position::new(0, 0), end),
+        formatted,
+    )])
+}
+
+/// Completion items at `position`.
+pub fn completions(doc: &Document, position: Position) -> Vec<CompletionItem> {
+    let lines = doc.lines();
+    let Some(line) = lines.get(position.line as usize) else {
+        return Vec::new();
+    };
+    let before = line[..byte_index(line, position.character)].to_string();
+
+    if let Some((open, close)) = doc.frontmatter_bounds() {
+        let line_no = position.line as usize;
+        if line_no > open && line_no < close {
+            return frontmatter_completions(&lines[open + 1..close], &before);
+        }
+    }
+
+    path_completions(doc, &before)
+}
+
+fn frontmatter_completions(frontmatter: &[&str], before: &str) -> Vec<CompletionItem> {
+    if let Some(value) = before.strip_prefix("allowed-tools:") {
+        let used: Vec<&str> = value.split_whitespace().collect();
+        return KNOWN_TOOLS
+            .iter()
+            .filter(|tool| !used.contains(tool))
+            .map(|tool| CompletionItem {
+                label: tool.to_string(),
+                kind: Some(CompletionItemKind::ENUM_MEMBER),
+                ..Default::default()
+            })
+            .collect();
+    }
+
+    // Keys are only completed at the start of a top-level line
+    if before.contains(':') || before.starts_with(' ') {
+        return Vec::new();
+    }
+
+    Frontmatter::KEY_ORDER
+        .iter()
+        .filter(|key| {
+            let prefix = format!("{}:", key);
+            !frontmatter.iter().any(|l| l.starts_with(&prefix))
+        })
+        .map(|key| CompletionItem {
+            label: key.to_string(),
+            kind: Some(CompletionItemKind::PROPERTY),
+            insert_text: Some(format!("{}: ", key)),
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn path_completions(doc: &Document, before: &str) -> Vec<CompletionItem> {
+    // The partial path runs from the last backtick or whitespace to the cursor
+    let start = before
+        .rfind(|c: char| c == '`' || c.is_whitespace() || c == '(')
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let partial = &before[start..];
+
+    let Some(dir) = REFERENCE_DIRS
+        .iter()
+        .find(|dir| partial.starts_with(&format!("{}/", dir)))
+    else {
+        return Vec::new();
+    };
+
+    let skill_dir = doc.skill_dir();
+    let mut items: Vec<CompletionItem> = WalkDir::new(skill_dir.join(dir))
+        .min_depth(1)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let relative = e.path().strip_prefix(skill_dir).ok()?;
+            let label = relative.to_string_lossy().replace('\\', "/");
+            label.starts_with(partial).then(|| CompletionItem {
+                label: label.clone(),
+                kind: Some(CompletionItemKind::FILE),
+                // Replace what was typed so far, since it contains a '/'
+                filter_text: Some(label.clone()),
+                insert_text: Some(label[partial.len()..].to_string()),
+                ..Default::default()
+            })
+        })
+        .collect();
+    items.sort_by(|a, b| a.label.cmp(&b.label));
+    items
+}
+
+/// Rule documentation for a diagnostic code under the cursor.
+pub fn hover(doc: &Document, position: Position, diagnostics: &[Diagnostic]) -> Option<Hover> {
+    let line = doc.lines().get(position.line as usize)?.to_string();
+    let chars: Vec<char> = line.chars().collect();
+    let col = line[..byte_index(&line, position.character)]
+        .chars()
+        .count();
+    let is_word = |c: &char| c.is_ascii_alphanumeric();
+    let start = chars[..col]
+        .iter()
+        .rposition(|c| !is_word(c))
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let end = chars[col..]
+        .iter()
+        .position(|c| !is_word(c))
+        .map(|i| col + i)
+        .unwrap_or(chars.len());
+    let word: String = chars[start..end].iter().collect();
+
+    // A code written in the document, or the code of a diagnostic at the cursor
+    let meta = if CODE_REGEX.is_match(&word) {
+        registry::find(&word)
+    } else {
+        diagnostics
+            .iter()
+            .find(|d| contains(&d.range, position))
+            .and_then(|d| match &d.code {
+                Some(NumberOrString::String(code)) => registry::find(code),
+                _ => None,
+            })
+    }?;
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: format!(
+                "{}\n[Documentation]({})",
+                meta.to_markdown(),
+                meta.help_uri()
+            ),
+        }),
+        range: None,
+    })
+}
+
+fn contains(range: &Range, position: Position) -> bool {
+    range.start <= position && position <= range.end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skill::FormatterConfig;
+    use tempfile::TempDir;
+
+    fn doc(dir: &Path, text: &str) -> Document {
+        Document {
+            path: dir.join("SKILL.md"),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_diagnostics_point_at_frontmatter_value() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("my-skill");
+        std::fs::create_dir(&dir).unwrap();
+        let doc = doc(
+            &dir,
+            "---\nname: My_Skill\ndescription: Test\n---\n\nBody\n",
+        );
+
+        let diags = diagnostics(&doc, &Validator::default());
+        let e001 = diags
+            .iter()
+            .find(|d| d.code == Some(NumberOrString::String("E001".into())))
+            .unwrap();
+        assert_eq!(
+            e001.range,
+            Range::new(Position::new(1, 6), Position::new(1, 14))
+        );
+    }
+
+    #[test]
+    fn test_parse_error_is_reported() {
+        let doc = doc(Path::new("/tmp/x"), "no frontmatter");
+        let diags = diagnostics(&doc, &Validator::default());
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].code, Some(NumberOrString::String("E007".into())));
+    }
+
+    #[test]
+    fn test_sanitize_name() {
+        assert_eq!(sanitize_name("My_Skill"), "my-skill");
+        assert_eq!(sanitize_name("--a  b--"), "a-b");
+    }
+
+    #[test]
+    fn test_name_quick_fix() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("my-skill");
+        std::fs::create_dir(&dir).unwrap();
+        let doc = doc(&dir, "---\nname: My_Skill\ndescription: Test\n---\n");
+        let uri = Url::from_file_path(&doc.path).unwrap();
+
+        let diags = diagnostics(&doc, &Validator::default());
+        let actions = code_actions(&doc, &uri, &diags);
+        let titles: Vec<&str> = actions.iter().map(|a| a.title.as_str()).collect();
+        assert!(titles.contains(&"Rename skill to 'my-skill'"));
+        assert!(titles.contains(&"Rename skill to match directory 'my-skill'"));
+    }
+
+    #[test]
+    fn test_complete_missing_keys() {
+        let doc = doc(Path::new("/tmp/x"), "---\nname: x\n\n---\n");
+        let labels: Vec<String> = completions(&doc, Position::new(2, 0))
+            .into_iter()
+            .map(|c| c.label)
+            .collect();
+        assert!(!labels.contains(&"name".to_string()));
+        assert!(labels.contains(&"description".to_string()));
+    }
+
+    #[test]
+    fn test_complete_allowed_tools() {
+        let doc = doc(Path::new("/tmp/x"), "---\nallowed-tools: Read \n---\n");
+        let labels: Vec<String> = completions(&doc, Position::new(1, 20))
+            .into_iter()
+            .map(|c| c.label)
+            .collect();
+        assert!(labels.contains(&"Bash".to_string()));
+        assert!(!labels.contains(&"Read".to_string()));
+    }
+
+    #[test]
+    fn test_complete_reference_paths() {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir(temp.path().join("scripts")).unwrap();
+        std::fs::write(temp.path().join("scripts/run.sh"), "").unwrap();
+        let doc = doc(temp.path(), "---\nname: x\n---\n\nRun `scripts/");
+
+        let items = completions(&doc, Position::new(4, 13));
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].label, "scripts/run.sh");
+        assert_eq!(items[0].insert_text.as_deref(), Some("run.sh"));
+    }
+
+    #[test]
+    fn test_hover_on_code() {
+        let doc = doc(Path::new("/tmp/x"), "See E009 for details");
+        let hover = hover(&doc, Position::new(0, 5), &[]).unwrap();
+        let HoverContents::Markup(content) = hover.contents else {
+            panic!("expected markup");
+        };
+        assert!(content.value.contains("references-exist"));
+    }
+
+    #[test]
+    fn test_positions_count_utf16_units() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("my-skill");
+        std::fs::create_dir(&dir).unwrap();
+        let doc = doc(
+            &dir,
+            "---\ndescription: Ship it\nname: My_😀\n---\n\n😀 See E009",
+        );
+
+        let diags = diagnostics(&doc, &Validator::default());
+        let e001 = diags
+            .iter()
+            .find(|d| d.code == Some(NumberOrString::String("E001".into())))
+            .unwrap();
+        assert_eq!(
+            e001.range,
+            Range::new(Position::new(2, 6), Position::new(2, 11))
+        );
+
+        let edits = format(&doc, &Formatter::new(FormatterConfig::default())).unwrap();
+        assert_eq!(edits[0].range.end, Position::new(5, 11));
+
+        // "E009" starts after the emoji's two UTF-16 units and a space
+        assert!(hover(&doc, Position::new(5, 8), &[]).is_some());
+        assert!(hover(&doc, Position::new(5, 2), &[]).is_none());
+    }
+}
@@ -0,0 +1,252 @@
+//! Language server for SKILL.md files.
+//!
+//! Speaks JSON-RPC over stdio and provides:
+//! - Diagnostics from the [`Validator`] as documents change
+//! - Quick fixes for invalid names and missing shebangs
+//! - Formatting through the [`Formatter`]
+//! - Completion of frontmatter keys, `allowed-tools` and referenced paths
+//! - Rule documentation on hover
+
+pub mod analysis;
+
+use crate::config::Config;
+use crate::error::SkiloError;
+use crate::skill::{Formatter, FormatterConfig, Validator};
+use analysis::Document;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
+    Notification as _, PublishDiagnostics,
+};
+use lsp_types::request::{CodeActionRequest, Completion, Formatting, HoverRequest, Request as _};
+use lsp_types::{
+    CodeActionOrCommand, CodeActionProviderCapability, CompletionOptions, Diagnostic,
+    HoverProviderCapability, OneOf, PublishDiagnosticsParams, ServerCapabilities, ServerInfo,
+    TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+
+/// State of a running language server.
+struct Server {
+    validator: Validator,
+    formatter: Formatter,
+    documents: HashMap<Url, Document>,
+    diagnostics: HashMap<Url, Vec<Diagnostic>>,
+}
+
+/// Run the language server on stdin/stdout until the client shuts it down.
+pub fn run(config: &Config) -> Result<(), SkiloError> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec!["/".into(), ":".into(), " ".into()]),
+            ..Default::default()
+        }),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        ..Default::default()
+    };
+    let init = serde_json::json!({
+        "capabilities": capabilities,
+        "serverInfo": ServerInfo {
+            name: "skilo".into(),
+            version: Some(env!("CARGO_PKG_VERSION").into()),
+        },
+    });
+
+    // Finish the handshake manually to report server info along with the capabilities
+    let (id, _params) = connection.initialize_start().map_err(lsp_error)?;
+    connection.initialize_finish(id, init).map_err(lsp_error)?;
+
+    let mut server = Server {
+        validator: Validator::new(&config.lint),
+        formatter: Formatter::new(FormatterConfig::from(&config.fmt)),
+        documents: HashMap::new(),
+        diagnostics: HashMap::new(),
+    };
+
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request).map_err(lsp_error)? {
+                    break;
+                }
+                let response = server.handle_request(request);
+                connection
+                    .sender
+                    .send(Message::Response(response))
+                    .map_err(lsp_error)?;
+            }
+            Message::Notification(notification) => {
+                for outgoing in server.handle_notification(notification) {
+                    connection
+                        .sender
+                        .send(Message::Notification(outgoing))
+                        .map_err(lsp_error)?;
+                }
+            }
+            Message::Response(_) => {}
+        }
+    }
+
+    drop(connection);
+    io_threads.join().map_err(lsp_error)?;
+
+    Ok(())
+}
+
+fn lsp_error(e: impl std::fmt::Display) -> SkiloError {
+    SkiloError::Lsp(e.to_string())
+}
+
+impl Server {
+    fn handle_request(&self, request: Request) -> Response {
+        let id = request.id.clone();
+        let result = match request.method.as_str() {
+            Formatting::METHOD => self.with_params(
+                request,
+                |server, params: lsp_types::DocumentFormattingParams| {
+                    server
+                        .documents
+                        .get(&params.text_document.uri)
+                        .and_then(|doc| analysis::format(doc, &server.formatter))
+                },
+            ),
+            CodeActionRequest::METHOD => {
+                self.with_params(request, |server, params: lsp_types::CodeActionParams| {
+                    let uri = &params.text_document.uri;
+                    server.documents.get(uri).map(|doc| {
+                        analysis::code_actions(doc, uri, &params.context.diagnostics)
+                            .into_iter()
+                            .map(CodeActionOrCommand::CodeAction)
+                            .collect::<Vec<_>>()
+                    })
+                })
+            }
+            Completion::METHOD => {
+                self.with_params(request, |server, params: lsp_types::CompletionParams| {
+                    let position = params.text_document_position;
+                    server
+                        .documents
+                        .get(&position.text_document.uri)
+                        .map(|doc| analysis::completions(doc, position.position))
+                })
+            }
+            HoverRequest::METHOD => {
+                self.with_params(request, |server, params: lsp_types::HoverParams| {
+                    let position = params.text_document_position_params;
+                    let uri = &position.text_document.uri;
+                    let diagnostics = server.diagnostics.get(uri).map(Vec::as_slice);
+                    server.documents.get(uri).and_then(|doc| {
+                        analysis::hover(doc, position.position, diagnostics.unwrap_or_default())
+                    })
+                })
+            }
+            _ => Err((
+                ErrorCode::MethodNotFound,
+                format!("Unhandled method: {}", request.method),
+            )),
+        };
+
+        match result {
+            Ok(value) => Response::new_ok(id, value),
+            Err((code, message)) => Response::new_err(id, code as i32, message),
+        }
+    }
+
+    /// Deserialize request parameters and serialize the handler's result.
+    fn with_params<P, R>(
+        &self,
+        request: Request,
+        handler: impl FnOnce(&Self, P) -> R,
+    ) -> Result<serde_json::Value, (ErrorCode, String)>
+    where
+        P: DeserializeOwned,
+        R: serde::Serialize,
+    {
+        let params: P = serde_json::from_value(request.params)
+            .map_err(|e| (ErrorCode::InvalidParams, e.to_string()))?;
+        serde_json::to_value(handler(self, params))
+            .map_err(|e| (ErrorCode::InternalError, e.to_string()))
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> Vec<Notification> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                if let Some(params) =
+                    parse::<lsp_types::DidOpenTextDocumentParams>(notification.params)
+                {
+                    let doc = params.text_document;
+                    return self.update(doc.uri, doc.text);
+                }
+            }
+            DidChangeTextDocument::METHOD => {
+                if let Some(params) =
+                    parse::<lsp_types::DidChangeTextDocumentParams>(notification.params)
+                {
+                    // Full sync: the last change holds the whole document
+                    if let Some(change) = params.content_changes.into_iter().last() {
+                        return self.update(params.text_document.uri, change.text);
+                    }
+                }
+            }
+            DidSaveTextDocument::METHOD => {
+                // Files next to the skill may have changed; re-check on save
+                if let Some(params) =
+                    parse::<lsp_types::DidSaveTextDocumentParams>(notification.params)
+                {
+                    let uri = params.text_document.uri;
+                    if let Some(text) = self.documents.get(&uri).map(|d| d.text.clone()) {
+                        return self.update(uri, text);
+                    }
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                if let Some(params) =
+                    parse::<lsp_types::DidCloseTextDocumentParams>(notification.params)
+                {
+                    let uri = params.text_document.uri;
+                    self.documents.remove(&uri);
+                    self.diagnostics.remove(&uri);
+                    return vec![publish(uri, Vec::new())];
+                }
+            }
+            _ => {}
+        }
+
+        Vec::new()
+    }
+
+    /// Store new document contents and publish fresh diagnostics.
+    fn update(&mut self, uri: Url, text: String) -> Vec<Notification> {
+        let Ok(path) = uri.to_file_path() else {
+            return Vec::new();
+        };
+
+        let doc = Document { path, text };
+        let diagnostics = analysis::diagnostics(&doc, &self.validator);
+        self.documents.insert(uri.clone(), doc);
+        self.diagnostics.insert(uri.clone(), diagnostics.clone());
+
+        vec![publish(uri, diagnostics)]
+    }
+}
+
+fn parse<P: DeserializeOwned>(params: serde_json::Value) -> Option<P> {
+    serde_json::from_value(params).ok()
+}
+
+fn publish(uri: Url, diagnostics: Vec<Diagnostic>) -> Notification {
+    Notification::new(
+        PublishDiagnostics::METHOD.to_string(),
+        PublishDiagnosticsParams {
+            uri,
+            diagnostics,
+            version: None,
+        },
+    )
+}
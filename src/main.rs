@@ -2,6 +2,7 @@
 
 use clap::Parser;
 use miette::Result;
+use skilo::agent::Agent;
 use skilo::cli::{Cli, Command, SelfCommand};
 use skilo::commands;
 use skilo::config::Config;
@@ -9,8 +10,16 @@ use skilo::config::Config;
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    let config = Config::load(cli.config.as_ref())
-        .map_err(|e| miette::miette!("Failed to load config: {}", e))?;
+    // Agents from `[[agents]]` are registered before anything refers to them
+    let config = match Config::custom_agents(cli.config.as_ref()).and_then(|agents| {
+        Agent::register(agents).map_err(std::io::Error::other)?;
+        Config::load(cli.config.as_ref())
+    }) {
+        Ok(config) => config,
+        // `config` subcommands report and repair invalid files themselves
+        Err(_) if matches!(cli.command, Command::Config(_)) => Config::default(),
+        Err(e) => return Err(miette::miette!("Failed to load config: {}", e)),
+    };
 
     let exit_code = match &cli.command {
         Command::Add(args) => commands::add::run(args.clone(), &config, &cli)?,
@@ -23,14 +32,23 @@ fn main() -> Result<()> {
             args.strict = true;
             commands::lint::run(args, &config, &cli)?
         }
+        Command::Watch(args) => commands::watch::run(args.clone(), &config, &cli)?,
+        Command::Lsp => commands::lsp::run(&config)?,
+        Command::Mcp(args) => commands::mcp::run(args.clone(), &config, &cli)?,
+        Command::Explain(args) => commands::explain::run(args.clone(), &config, &cli)?,
         Command::ReadProperties(args) => {
             commands::read_properties::run(args.clone(), &config, &cli)?
         }
         Command::ToPrompt(args) => commands::to_prompt::run(args.clone(), &config, &cli)?,
+        Command::Export(args) => commands::export::run(args.clone(), &config, &cli)?,
+        Command::Import(args) => commands::import::run(args.clone(), &config, &cli)?,
+        Command::Sync(args) => commands::sync::run(args.clone(), &config, &cli)?,
+        Command::SyncIndex(args) => commands::sync_index::run(args.clone(), &config, &cli)?,
         Command::List(args) => commands::list::run(args.clone(), &config, &cli)?,
         Command::Remove(args) => commands::remove::run(args.clone(), &config, &cli)?,
         Command::Agents(args) => commands::agents::run(args.clone(), &config, &cli)?,
         Command::Cache(args) => commands::cache::run(args.clone(), &config, &cli)?,
+        Command::Config(args) => commands::config::run(args.clone(), &config, &cli)?,
         Command::SelfCmd(args) => match &args.command {
             SelfCommand::Update(update_args) => {
                 commands::self_update::run(update_args.clone(), &config, &cli)?
@@ -0,0 +1,254 @@
+//! The set of skills served over MCP and the resources they expose.
+
+use crate::agent::Agent;
+use crate::scope::{list_all_skills, Scope};
+use crate::skill::{Discovery, Manifest};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+
+/// URI scheme of skill resources.
+pub const SCHEME: &str = "skill://";
+
+/// A skill available to MCP clients.
+#[derive(Debug, Clone, Serialize)]
+pub struct CatalogSkill {
+    /// Skill name, unique within the catalog.
+    pub name: String,
+    /// Skill description.
+    pub description: String,
+    /// License, if declared.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    /// Skill directory.
+    pub path: PathBuf,
+    /// Where the skill was found (e.g., "project/claude" or "path").
+    pub source: String,
+}
+
+impl CatalogSkill {
+    /// URI of the skill's metadata resource.
+    pub fn uri(&self) -> String {
+        format!("{}{}", SCHEME, self.name)
+    }
+
+    /// Files in the skill directory, relative to it, with SKILL.md first.
+    pub fn files(&self) -> Vec<String> {
+        let mut files: Vec<String> = WalkDir::new(&self.path)
+            .min_depth(1)
+            .into_iter()
+            .filter_entry(|e| !e.file_name().to_string_lossy().starts_with('.'))
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .filter_map(|e| {
+                let relative = e.path().strip_prefix(&self.path).ok()?;
+                Some(relative.to_string_lossy().replace('\\', "/"))
+            })
+            .filter(|f| f != "SKILL.md")
+            .collect();
+        files.sort();
+        files.insert(0, "SKILL.md".to_string());
+        files
+    }
+
+    /// Resolve a file inside the skill directory, refusing paths that escape it
+    /// and hidden files that [`CatalogSkill::files`] does not list.
+    pub fn resolve(&self, relative: &str) -> Option<PathBuf> {
+        let relative = Path::new(relative);
+        let listed = relative.components().all(|c| match c {
+            Component::Normal(name) => !name.to_string_lossy().starts_with('.'),
+            _ => false,
+        });
+        if !listed {
+            return None;
+        }
+
+        let path = self.path.join(relative);
+        let root = self.path.canonicalize().ok()?;
+        let resolved = path.canonicalize().ok()?;
+        (resolved.starts_with(&root) && resolved.is_file()).then_some(resolved)
+    }
+}
+
+/// Skills served by the MCP server.
+#[derive(Debug, Default)]
+pub struct Catalog {
+    /// Skills ordered by name.
+    pub skills: Vec<CatalogSkill>,
+}
+
+impl Catalog {
+    /// Collect installed skills for `agents` and skills discovered under `paths`.
+    ///
+    /// Project skills shadow global skills, and installed skills shadow
+    /// discovered ones with the same name.
+    pub fn load(
+        agents: &[Agent],
+        project_root: &Path,
+        paths: &[PathBuf],
+        ignore_patterns: &[String],
+    ) -> Self {
+        let mut skills: Vec<CatalogSkill> = Vec::new();
+        let mut names = HashSet::new();
+
+        let mut installed: Vec<(Scope, Agent, _)> = Vec::new();
+        for agent in agents {
+            for skill in list_all_skills(*agent, project_root) {
+                installed.push((skill.scope, *agent, skill));
+            }
+        }
+        // Project skills first so they win over global ones
+        installed.sort_by_key(|(scope, _, _)| scope.is_global());
+
+        for (scope, agent, skill) in installed {
+            if !names.insert(skill.name.clone()) {
+                continue;
+            }
+            let license = Manifest::parse(skill.path.join("SKILL.md"))
+                .ok()
+                .and_then(|m| m.frontmatter.license);
+            skills.push(CatalogSkill {
+                name: skill.name,
+                description: skill.description,
+                license,
+                path: skill.path,
+                source: format!("{}/{}", scope.display_name(), agent.cli_name()),
+            });
+        }
+
+        for root in paths {
+            for manifest_path in Discovery::find_skills(root, ignore_patterns) {
+                let Ok(manifest) = Manifest::parse(manifest_path.clone()) else {
+                    continue;
+                };
+                let Some(dir) = manifest_path.parent() else {
+                    continue;
+                };
+                if !names.insert(manifest.frontmatter.name.clone()) {
+                    continue;
+                }
+                skills.push(CatalogSkill {
+                    name: manifest.frontmatter.name,
+                    description: manifest.frontmatter.description,
+                    license: manifest.frontmatter.license,
+                    path: dir.to_path_buf(),
+                    source: "path".to_string(),
+                });
+            }
+        }
+
+        skills.sort_by(|a, b| a.name.cmp(&b.name));
+        Self { skills }
+    }
+
+    /// Find a skill by name.
+    pub fn get(&self, name: &str) -> Option<&CatalogSkill> {
+        self.skills.iter().find(|s| s.name == name)
+    }
+
+    /// Split a resource URI into the skill and an optional file within it.
+    pub fn parse_uri<'a>(&self, uri: &'a str) -> Option<(&CatalogSkill, Option<&'a str>)> {
+        let rest = uri.strip_prefix(SCHEME)?;
+        let (name, file) = match rest.split_once('/') {
+            Some((name, file)) if !file.is_empty() => (name, Some(file)),
+            Some((name, _)) => (name, None),
+            None => (rest, None),
+        };
+        Some((self.get(name)?, file))
+    }
+}
+
+/// MIME type for a file, based on its extension.
+pub fn mime_type(path: &str) -> &'static str {
+    match Path::new(path).extension().and_then(|e| e.to_str()) {
+        Some("md") => "text/markdown",
+        Some("json") => "application/json",
+        Some("yaml" | "yml") => "application/yaml",
+        Some("toml") => "application/toml",
+        Some("py") => "text/x-python",
+        Some("sh") => "text/x-shellscript",
+        Some("js") => "text/javascript",
+        Some("ts") => "text/typescript",
+        Some("html") => "text/html",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("svg") => "image/svg+xml",
+        Some("pdf") => "application/pdf",
+        _ => "text/plain",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn catalog_with_skill(temp: &TempDir) -> Catalog {
+        let dir = temp.path().join("pdf-tools");
+        std::fs::create_dir_all(dir.join("scripts")).unwrap();
+        std::fs::write(
+            dir.join("SKILL.md"),
+            "---\nname: pdf-tools\ndescription: Work with PDFs\n---\n\nRun `scripts/run.sh`.\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("scripts/run.sh"), "#!/bin/sh\n").unwrap();
+
+        Catalog::load(&[], temp.path(), &[temp.path().to_path_buf()], &[])
+    }
+
+    #[test]
+    fn test_load_discovered_skills() {
+        let temp = TempDir::new().unwrap();
+        let catalog = catalog_with_skill(&temp);
+
+        assert_eq!(catalog.skills.len(), 1);
+        let skill = &catalog.skills[0];
+        assert_eq!(skill.uri(), "skill://pdf-tools");
+        assert_eq!(skill.files(), vec!["SKILL.md", "scripts/run.sh"]);
+    }
+
+    #[test]
+    fn test_parse_uri() {
+        let temp = TempDir::new().unwrap();
+        let catalog = catalog_with_skill(&temp);
+
+        let (skill, file) = catalog.parse_uri("skill://pdf-tools").unwrap();
+        assert_eq!(skill.name, "pdf-tools");
+        assert!(file.is_none());
+
+        let (_, file) = catalog
+            .parse_uri("skill://pdf-tools/scripts/run.sh")
+            .unwrap();
+        assert_eq!(file, Some("scripts/run.sh"));
+
+        assert!(catalog.parse_uri("skill://missing").is_none());
+        assert!(catalog.parse_uri("file:///etc/passwd").is_none());
+    }
+
+    #[test]
+    fn test_resolve_rejects_escapes() {
+        let temp = TempDir::new().unwrap();
+        let catalog = catalog_with_skill(&temp);
+        let skill = &catalog.skills[0];
+
+        assert!(skill.resolve("scripts/run.sh").is_some());
+        assert!(skill.resolve("../pdf-tools/SKILL.md").is_none());
+        assert!(skill.resolve("/etc/passwd").is_none());
+        assert!(skill.resolve("missing.md").is_none());
+    }
+
+    #[test]
+    fn test_resolve_rejects_hidden_files() {
+        let temp = TempDir::new().unwrap();
+        let catalog = catalog_with_skill(&temp);
+        let skill = &catalog.skills[0];
+        std::fs::write(skill.path.join(".env"), "TOKEN=secret").unwrap();
+        std::fs::create_dir_all(skill.path.join(".git")).unwrap();
+        std::fs::write(skill.path.join(".git/config"), "[core]").unwrap();
+
+        assert!(skill.resolve(".env").is_none());
+        assert!(skill.resolve(".git/config").is_none());
+        assert!(!skill.files().contains(&".env".to_string()));
+    }
+}
@@ -0,0 +1,362 @@
+//! Model Context Protocol server exposing skills to MCP clients.
+//!
+//! Speaks JSON-RPC over stdio (one message per line) and provides:
+//! - A metadata resource per skill (`skill://<name>`)
+//! - SKILL.md and every bundled file as readable resources
+//!   (`skill://<name>/<path>`)
+//! - Optionally, each skill as a prompt carrying its instructions
+
+pub mod catalog;
+
+use crate::error::SkiloError;
+use base64::Engine;
+use catalog::{mime_type, Catalog, CatalogSkill};
+use serde_json::{json, Value};
+use std::io::{BufRead, Write};
+
+/// Protocol revisions the server speaks, newest first.
+const PROTOCOL_VERSIONS: [&str; 3] = ["2025-06-18", "2025-03-26", "2024-11-05"];
+
+const INVALID_PARAMS: i64 = -32602;
+const METHOD_NOT_FOUND: i64 = -32601;
+const RESOURCE_NOT_FOUND: i64 = -32002;
+
+/// A JSON-RPC error returned to the client.
+type RpcError = (i64, String);
+
+/// State of a running MCP server.
+pub struct Server {
+    catalog: Catalog,
+    prompts: bool,
+}
+
+impl Server {
+    /// Create a server for `catalog`, optionally exposing skills as prompts.
+    pub fn new(catalog: Catalog, prompts: bool) -> Self {
+        Self { catalog, prompts }
+    }
+
+    /// Handle one incoming message, returning the response for requests.
+    pub fn handle(&self, message: Value) -> Option<Value> {
+        // Notifications have no id and get no response
+        let id = message.get("id")?.clone();
+        let method = message.get("method").and_then(Value::as_str).unwrap_or("");
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        let result = match method {
+            "initialize" => Ok(self.initialize(&params)),
+            "ping" => Ok(json!({})),
+            "resources/list" => Ok(self.list_resources()),
+            "resources/read" => self.read_resource(&params),
+            "prompts/list" if self.prompts => Ok(self.list_prompts()),
+            "prompts/get" if self.prompts => self.get_prompt(&params),
+            _ => Err((METHOD_NOT_FOUND, format!("Unhandled method: {}", method))),
+        };
+
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err((code, message)) => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": code, "message": message },
+            }),
+        })
+    }
+
+    fn initialize(&self, params: &Value) -> Value {
+        // Agree to the client's revision if supported, otherwise offer the newest
+        let requested = params.get("protocolVersion").and_then(Value::as_str);
+        let version = PROTOCOL_VERSIONS
+            .into_iter()
+            .find(|v| Some(*v) == requested)
+            .unwrap_or(PROTOCOL_VERSIONS[0]);
+
+        let mut capabilities = json!({ "resources": {} });
+        if self.prompts {
+            capabilities["prompts"] = json!({});
+        }
+
+        json!({
+            "protocolVersion": version,
+            "capabilities": capabilities,
+            "serverInfo": {
+                "name": "skilo",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+        })
+    }
+
+    fn list_resources(&self) -> Value {
+        let mut resources = Vec::new();
+        for skill in &self.catalog.skills {
+            resources.push(json!({
+                "uri": skill.uri(),
+                "name": skill.name,
+                "description": skill.description,
+                "mimeType": "application/json",
+            }));
+            for file in skill.files() {
+                resources.push(json!({
+                    "uri": format!("{}/{}", skill.uri(), file),
+                    "name": format!("{}/{}", skill.name, file),
+                    "mimeType": mime_type(&file),
+                }));
+            }
+        }
+
+        json!({ "resources": resources })
+    }
+
+    fn read_resource(&self, params: &Value) -> Result<Value, RpcError> {
+        let uri = string_param(params, "uri")?;
+        let not_found = || (RESOURCE_NOT_FOUND, format!("Resource not found: {}", uri));
+        let (skill, file) = self.catalog.parse_uri(uri).ok_or_else(not_found)?;
+
+        let mut contents = json!({ "uri": uri });
+        match file {
+            None => {
+                contents["mimeType"] = json!("application/json");
+                contents["text"] = json!(metadata(skill).to_string());
+            }
+            Some(file) => {
+                let path = skill.resolve(file).ok_or_else(not_found)?;
+                let bytes = std::fs::read(&path).map_err(|_| not_found())?;
+                contents["mimeType"] = json!(mime_type(file));
+                match String::from_utf8(bytes) {
+                    Ok(text) => contents["text"] = json!(text),
+                    Err(e) => {
+                        let blob = base64::engine::general_purpose::STANDARD.encode(e.as_bytes());
+                        contents["blob"] = json!(blob);
+                    }
+                }
+            }
+        }
+
+        Ok(json!({ "contents": [contents] }))
+    }
+
+    fn list_prompts(&self) -> Value {
+        let prompts: Vec<Value> = self
+            .catalog
+            .skills
+            .iter()
+            .map(|skill| json!({ "name": skill.name, "description": skill.description }))
+            .collect();
+
+        json!({ "prompts": prompts })
+    }
+
+    fn get_prompt(&self, params: &Value) -> Result<Value, RpcError> {
+        let name = string_param(params, "name")?;
+        let skill = self
+            .catalog
+            .get(name)
+            .ok_or_else(|| (INVALID_PARAMS, format!("Unknown prompt: {}", name)))?;
+        let text = std::fs::read_to_string(skill.path.join("SKILL.md"))
+            .map_err(|e| (INVALID_PARAMS, e.to_string()))?;
+
+        Ok(json!({
+            "description": skill.description,
+            "messages": [{
+                "role": "user",
+                "content": { "type": "text", "text": text },
+            }],
+        }))
+    }
+}
+
+/// Metadata served for a skill's root resource.
+fn metadata(skill: &CatalogSkill) -> Value {
+    let mut value = serde_json::to_value(skill).unwrap_or_default();
+    value["uri"] = json!(skill.uri());
+    value["files"] = json!(skill.files());
+    value
+}
+
+fn string_param<'a>(params: &'a Value, key: &str) -> Result<&'a str, RpcError> {
+    params
+        .get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| (INVALID_PARAMS, format!("Missing '{}' parameter", key)))
+}
+
+/// Run the MCP server on stdin/stdout until the client closes the input.
+pub fn run(server: &Server) -> Result<(), SkiloError> {
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout();
+
+    for line in stdin.lock().lines() {
+        let line = line.map_err(mcp_error)?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match serde_json::from_str::<Value>(&line) {
+            Ok(message) => server.handle(message),
+            Err(e) => Some(json!({
+                "jsonrpc": "2.0",
+                "id": null,
+                "error": { "code": -32700, "message": e.to_string() },
+            })),
+        };
+
+        if let Some(response) = response {
+            writeln!(stdout, "{}", response).map_err(mcp_error)?;
+            stdout.flush().map_err(mcp_error)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn mcp_error(e: impl std::fmt::Display) -> SkiloError {
+    SkiloError::Mcp(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn server(temp: &TempDir, prompts: bool) -> Server {
+        let dir = temp.path().join("pdf-tools");
+        std::fs::create_dir_all(dir.join("assets")).unwrap();
+        std::fs::write(
+            dir.join("SKILL.md"),
+            "---\nname: pdf-tools\ndescription: Work with PDFs\n---\n\n# PDF Tools\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("assets/logo.png"), [0x89, b'P', b'N', b'G', 0xff]).unwrap();
+
+        let catalog = Catalog::load(&[], temp.path(), &[temp.path().to_path_buf()], &[]);
+        Server::new(catalog, prompts)
+    }
+
+    fn request(server: &Server, method: &str, params: Value) -> Value {
+        server
+            .handle(json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_initialize_advertises_prompts_only_when_enabled() {
+        let temp = TempDir::new().unwrap();
+
+        let response = request(&server(&temp, false), "initialize", json!({}));
+        assert!(response["result"]["capabilities"]["prompts"].is_null());
+        assert_eq!(response["result"]["serverInfo"]["name"], "skilo");
+
+        let response = request(&server(&temp, true), "initialize", json!({}));
+        assert!(response["result"]["capabilities"]["prompts"].is_object());
+    }
+
+    #[test]
+    fn test_initialize_negotiates_protocol_version() {
+        let temp = TempDir::new().unwrap();
+        let server = server(&temp, false);
+
+        let response = request(
+            &server,
+            "initialize",
+            json!({ "protocolVersion": "2024-11-05" }),
+        );
+        assert_eq!(response["result"]["protocolVersion"], "2024-11-05");
+
+        let response = request(
+            &server,
+            "initialize",
+            json!({ "protocolVersion": "1999-01-01" }),
+        );
+        assert_eq!(response["result"]["protocolVersion"], PROTOCOL_VERSIONS[0]);
+    }
+
+    #[test]
+    fn test_notifications_get_no_response() {
+        let temp = TempDir::new().unwrap();
+        let server = server(&temp, false);
+        let notification = json!({ "jsonrpc": "2.0", "method": "notifications/initialized" });
+
+        assert!(server.handle(notification).is_none());
+    }
+
+    #[test]
+    fn test_list_and_read_resources() {
+        let temp = TempDir::new().unwrap();
+        let server = server(&temp, false);
+
+        let response = request(&server, "resources/list", json!({}));
+        let uris: Vec<&str> = response["result"]["resources"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|r| r["uri"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            uris,
+            vec![
+                "skill://pdf-tools",
+                "skill://pdf-tools/SKILL.md",
+                "skill://pdf-tools/assets/logo.png"
+            ]
+        );
+
+        let response = request(
+            &server,
+            "resources/read",
+            json!({ "uri": "skill://pdf-tools" }),
+        );
+        let text = response["result"]["contents"][0]["text"].as_str().unwrap();
+        let meta: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(meta["description"], "Work with PDFs");
+
+        let response = request(
+            &server,
+            "resources/read",
+            json!({ "uri": "skill://pdf-tools/SKILL.md" }),
+        );
+        let contents = &response["result"]["contents"][0];
+        assert_eq!(contents["mimeType"], "text/markdown");
+        assert!(contents["text"].as_str().unwrap().contains("# PDF Tools"));
+
+        let response = request(
+            &server,
+            "resources/read",
+            json!({ "uri": "skill://pdf-tools/assets/logo.png" }),
+        );
+        assert!(response["result"]["contents"][0]["blob"].is_string());
+    }
+
+    #[test]
+    fn test_read_rejects_unknown_and_escaping_uris() {
+        let temp = TempDir::new().unwrap();
+        let server = server(&temp, false);
+
+        for uri in ["skill://missing", "skill://pdf-tools/../pdf-tools/SKILL.md"] {
+            let response = request(&server, "resources/read", json!({ "uri": uri }));
+            assert_eq!(response["error"]["code"], RESOURCE_NOT_FOUND);
+        }
+
+        let response = request(&server, "resources/read", json!({}));
+        assert_eq!(response["error"]["code"], INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_prompts() {
+        let temp = TempDir::new().unwrap();
+
+        let response = request(&server(&temp, false), "prompts/list", json!({}));
+        assert_eq!(response["error"]["code"], METHOD_NOT_FOUND);
+
+        let server = server(&temp, true);
+        let response = request(&server, "prompts/list", json!({}));
+        assert_eq!(response["result"]["prompts"][0]["name"], "pdf-tools");
+
+        let response = request(&server, "prompts/get", json!({ "name": "pdf-tools" }));
+        let message = &response["result"]["messages"][0];
+        assert_eq!(message["role"], "user");
+        assert!(message["content"]["text"]
+            .as_str()
+            .unwrap()
+            .contains("# PDF Tools"));
+    }
+}
@@ -0,0 +1,246 @@
+//! Checkstyle XML output formatter for code review integrations.
+
+use super::{render_xml, JsonFormatter, OutputFormatter};
+use crate::agent::DetectedAgent;
+use crate::cache::{CacheStats, CleanReport};
+use crate::config::ConfigEntry;
+use crate::diff::FileDiff;
+use crate::export::ExportReport;
+use crate::scope::{InstalledSkill, RemovalReport};
+use crate::skill::{RuleMeta, ValidationResult};
+use crate::sync::SyncReport;
+use serde::Serialize;
+use std::path::Path;
+
+/// Formatter that outputs Checkstyle XML.
+pub struct CheckstyleFormatter {
+    quiet: bool,
+}
+
+impl CheckstyleFormatter {
+    /// Create a new Checkstyle formatter.
+    pub fn new(quiet: bool) -> Self {
+        Self { quiet }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename = "checkstyle")]
+struct Checkstyle {
+    #[serde(rename = "@version")]
+    version: &'static str,
+    #[serde(rename = "file")]
+    files: Vec<CheckstyleFile>,
+}
+
+#[derive(Serialize)]
+struct CheckstyleFile {
+    #[serde(rename = "@name")]
+    name: String,
+    #[serde(rename = "error")]
+    errors: Vec<CheckstyleError>,
+}
+
+#[derive(Serialize)]
+struct CheckstyleError {
+    #[serde(rename = "@line", skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(rename = "@column", skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+    #[serde(rename = "@severity")]
+    severity: &'static str,
+    #[serde(rename = "@message")]
+    message: String,
+    #[serde(rename = "@source")]
+    source: String,
+}
+
+impl Checkstyle {
+    fn new() -> Self {
+        Self {
+            version: "4.3",
+            files: Vec::new(),
+        }
+    }
+
+    /// Add an error to the entry for `path`, creating it on first use.
+    fn push(&mut self, path: &str, error: CheckstyleError) {
+        match self.files.iter_mut().find(|f| f.name == path) {
+            Some(file) => file.errors.push(error),
+            None => self.files.push(CheckstyleFile {
+                name: path.to_string(),
+                errors: vec![error],
+            }),
+        }
+    }
+}
+
+impl OutputFormatter for CheckstyleFormatter {
+    fn format_validation(&self, results: &[(String, ValidationResult)]) -> String {
+        let mut report = Checkstyle::new();
+
+        for (path, result) in results {
+            // Every skill gets an entry, so consumers also see clean skills as checked
+            report.files.push(CheckstyleFile {
+                name: path.clone(),
+                errors: Vec::new(),
+            });
+
+            for diag in result.errors.iter().chain(result.warnings.iter()) {
+                let mut message = match &diag.fix_hint {
+                    Some(hint) => format!("{} (hint: {})", diag.message, hint),
+                    None => diag.message.clone(),
+                };
+                // Findings in other files of the skill (e.g. scripts) name that file
+                let same_file = diag.path == *path;
+                if !same_file {
+                    message = format!("{}: {}", diag.path, message);
+                }
+
+                report.push(
+                    path,
+                    CheckstyleError {
+                        line: diag.line.filter(|_| same_file),
+                        column: diag.column.filter(|_| same_file),
+                        severity: if diag.code.is_error() {
+                            "error"
+                        } else {
+                            "warning"
+                        },
+                        message,
+                        source: format!("skilo.{}", diag.code),
+                    },
+                );
+            }
+        }
+
+        render_xml(&report)
+    }
+
+    fn format_diffs(&self, diffs: &[FileDiff], _files_checked: usize) -> String {
+        let mut report = Checkstyle::new();
+
+        for diff in diffs {
+            for hunk in &diff.hunks {
+                report.push(
+                    &diff.path,
+                    CheckstyleError {
+                        line: Some(hunk.old_start.max(1)),
+                        column: None,
+                        severity: "warning",
+                        message: format!("Formatting differs: {}", hunk.header()),
+                        source: "skilo.fmt".to_string(),
+                    },
+                );
+            }
+        }
+
+        render_xml(&report)
+    }
+
+    // Checkstyle only describes findings; other listings fall back to JSON.
+
+    fn format_installed_skills(&self, skills: &[InstalledSkill]) -> String {
+        JsonFormatter::new(self.quiet).format_installed_skills(skills)
+    }
+
+    fn format_agents(&self, agents: &[DetectedAgent], verbose: bool) -> String {
+        JsonFormatter::new(self.quiet).format_agents(agents, verbose)
+    }
+
+    fn format_cache_stats(&self, stats: &CacheStats, cache_dir: &Path) -> String {
+        JsonFormatter::new(self.quiet).format_cache_stats(stats, cache_dir)
+    }
+
+    fn format_cache_clean(&self, report: &CleanReport) -> String {
+        JsonFormatter::new(self.quiet).format_cache_clean(report)
+    }
+
+    fn format_removal(&self, report: &RemovalReport) -> String {
+        JsonFormatter::new(self.quiet).format_removal(report)
+    }
+
+    fn format_rules(&self, rules: &[&RuleMeta], long: bool) -> String {
+        JsonFormatter::new(self.quiet).format_rules(rules, long)
+    }
+
+    fn format_config(&self, entries: &[ConfigEntry], origin: bool) -> String {
+        JsonFormatter::new(self.quiet).format_config(entries, origin)
+    }
+
+    fn format_export(&self, report: &ExportReport) -> String {
+        JsonFormatter::new(self.quiet).format_export(report)
+    }
+
+    fn format_sync(&self, report: &SyncReport) -> String {
+        JsonFormatter::new(self.quiet).format_sync(report)
+    }
+
+    fn format_message(&self, message: &str) {
+        if !self.quiet {
+            eprintln!("{}", message);
+        }
+    }
+
+    fn format_error(&self, message: &str) {
+        eprintln!("error: {}", message);
+    }
+
+    fn format_success(&self, message: &str) {
+        if !self.quiet {
+            eprintln!("{}", message);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skill::{Diagnostic, DiagnosticCode};
+
+    fn diagnostic(path: &str, code: DiagnosticCode, message: &str) -> Diagnostic {
+        Diagnostic {
+            path: path.to_string(),
+            line: Some(2),
+            column: Some(1),
+            message: message.to_string(),
+            code,
+            fix_hint: None,
+        }
+    }
+
+    #[test]
+    fn test_validation_report() {
+        let results = vec![
+            ("good/SKILL.md".to_string(), ValidationResult::default()),
+            (
+                "bad/SKILL.md".to_string(),
+                ValidationResult {
+                    errors: vec![diagnostic(
+                        "bad/SKILL.md",
+                        DiagnosticCode::E001,
+                        "Name <Bad> & \"wrong\"",
+                    )],
+                    warnings: vec![diagnostic(
+                        "bad/scripts/run.sh",
+                        DiagnosticCode::W003,
+                        "Missing shebang",
+                    )],
+                },
+            ),
+        ];
+
+        let expected = r#"<?xml version="1.0" encoding="UTF-8"?>
+<checkstyle version="4.3">
+  <file name="good/SKILL.md"/>
+  <file name="bad/SKILL.md">
+    <error line="2" column="1" severity="error" message="Name &lt;Bad&gt; &amp; &quot;wrong&quot;" source="skilo.E001"/>
+    <error severity="warning" message="bad/scripts/run.sh: Missing shebang" source="skilo.W003"/>
+  </file>
+</checkstyle>"#;
+        assert_eq!(
+            CheckstyleFormatter::new(false).format_validation(&results),
+            expected
+        );
+    }
+}
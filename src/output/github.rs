@@ -0,0 +1,212 @@
+//! GitHub Actions workflow command formatter for inline annotations.
+//!
+//! Emits `::error file=…,line=…::message` lines that the Actions runner
+//! turns into annotations on the pull request diff.
+
+use super::{JsonFormatter, OutputFormatter};
+use crate::agent::DetectedAgent;
+use crate::cache::{CacheStats, CleanReport};
+use crate::config::ConfigEntry;
+use crate::diff::FileDiff;
+use crate::export::ExportReport;
+use crate::scope::{InstalledSkill, RemovalReport};
+use crate::skill::{RuleMeta, ValidationResult};
+use crate::sync::SyncReport;
+use std::path::Path;
+
+/// Formatter that outputs GitHub Actions workflow commands.
+pub struct GithubFormatter {
+    quiet: bool,
+}
+
+impl GithubFormatter {
+    /// Create a new GitHub Actions formatter.
+    pub fn new(quiet: bool) -> Self {
+        Self { quiet }
+    }
+}
+
+/// Escape the message part of a workflow command.
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a `key=value` property of a workflow command.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Build a single workflow command line.
+fn command(level: &str, properties: &[(&str, String)], message: &str) -> String {
+    let properties: Vec<String> = properties
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, escape_property(value)))
+        .collect();
+
+    if properties.is_empty() {
+        format!("::{}::{}\n", level, escape_data(message))
+    } else {
+        format!(
+            "::{} {}::{}\n",
+            level,
+            properties.join(","),
+            escape_data(message)
+        )
+    }
+}
+
+impl OutputFormatter for GithubFormatter {
+    fn format_validation(&self, results: &[(String, ValidationResult)]) -> String {
+        let mut output = String::new();
+
+        for (_, result) in results {
+            for diag in result.errors.iter().chain(result.warnings.iter()) {
+                let mut properties = vec![("file", diag.path.clone())];
+                if let Some(line) = diag.line {
+                    properties.push(("line", line.to_string()));
+                }
+                if let Some(col) = diag.column {
+                    properties.push(("col", col.to_string()));
+                }
+                properties.push(("title", format!("skilo {}", diag.code)));
+
+                let message = match &diag.fix_hint {
+                    Some(hint) => format!("{}\nhint: {}", diag.message, hint),
+                    None => diag.message.clone(),
+                };
+
+                let level = if diag.code.is_error() {
+                    "error"
+                } else {
+                    "warning"
+                };
+                output.push_str(&command(level, &properties, &message));
+            }
+        }
+
+        let total_errors: usize = results.iter().map(|(_, r)| r.errors.len()).sum();
+        let total_warnings: usize = results.iter().map(|(_, r)| r.warnings.len()).sum();
+        output.push_str(&format!(
+            "{} skill(s) checked: {} error(s), {} warning(s)\n",
+            results.len(),
+            total_errors,
+            total_warnings
+        ));
+
+        output
+    }
+
+    fn format_diffs(&self, diffs: &[FileDiff], files_checked: usize) -> String {
+        let mut output = String::new();
+
+        for diff in diffs {
+            for hunk in &diff.hunks {
+                let line = hunk.old_start.max(1);
+                let end_line = (hunk.old_start + hunk.old_lines)
+                    .saturating_sub(1)
+                    .max(line);
+                let properties = [
+                    ("file", diff.path.clone()),
+                    ("line", line.to_string()),
+                    ("endLine", end_line.to_string()),
+                    ("title", "skilo fmt".to_string()),
+                ];
+                output.push_str(&command(
+                    "warning",
+                    &properties,
+                    &format!("File needs formatting ({})", hunk.header()),
+                ));
+            }
+        }
+
+        output.push_str(&format!(
+            "{} file(s) checked, {} need formatting\n",
+            files_checked,
+            diffs.len()
+        ));
+
+        output
+    }
+
+    // Annotations only describe findings; other listings fall back to JSON.
+
+    fn format_installed_skills(&self, skills: &[InstalledSkill]) -> String {
+        JsonFormatter::new(self.quiet).format_installed_skills(skills)
+    }
+
+    fn format_agents(&self, agents: &[DetectedAgent], verbose: bool) -> String {
+        JsonFormatter::new(self.quiet).format_agents(agents, verbose)
+    }
+
+    fn format_cache_stats(&self, stats: &CacheStats, cache_dir: &Path) -> String {
+        JsonFormatter::new(self.quiet).format_cache_stats(stats, cache_dir)
+    }
+
+    fn format_cache_clean(&self, report: &CleanReport) -> String {
+        JsonFormatter::new(self.quiet).format_cache_clean(report)
+    }
+
+    fn format_removal(&self, report: &RemovalReport) -> String {
+        JsonFormatter::new(self.quiet).format_removal(report)
+    }
+
+    fn format_rules(&self, rules: &[&RuleMeta], long: bool) -> String {
+        JsonFormatter::new(self.quiet).format_rules(rules, long)
+    }
+
+    fn format_config(&self, entries: &[ConfigEntry], origin: bool) -> String {
+        JsonFormatter::new(self.quiet).format_config(entries, origin)
+    }
+
+    fn format_export(&self, report: &ExportReport) -> String {
+        JsonFormatter::new(self.quiet).format_export(report)
+    }
+
+    fn format_sync(&self, report: &SyncReport) -> String {
+        JsonFormatter::new(self.quiet).format_sync(report)
+    }
+
+    fn format_message(&self, message: &str) {
+        if !self.quiet {
+            println!("{}", message);
+        }
+    }
+
+    fn format_error(&self, message: &str) {
+        print!("{}", command("error", &[], message));
+    }
+
+    fn format_success(&self, message: &str) {
+        if !self.quiet {
+            println!("{}", message);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_data() {
+        assert_eq!(escape_data("50%\nnext"), "50%25%0Anext");
+    }
+
+    #[test]
+    fn test_escape_property() {
+        assert_eq!(escape_property("a:b,c"), "a%3Ab%2Cc");
+    }
+
+    #[test]
+    fn test_command_with_properties() {
+        let line = command(
+            "error",
+            &[("file", "s/SKILL.md".into()), ("line", "2".into())],
+            "Invalid name",
+        );
+        assert_eq!(line, "::error file=s/SKILL.md,line=2::Invalid name\n");
+    }
+}
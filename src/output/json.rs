@@ -1,8 +1,18 @@
 //! JSON output formatter.
 
 use super::OutputFormatter;
-use crate::skill::{Diagnostic, ValidationResult};
+use crate::agent::{Agent, AgentFeatures, DetectedAgent};
+use crate::cache::{CacheStats, CleanReport};
+use crate::cli::ScriptLang;
+use crate::config::ConfigEntry;
+use crate::diff::{FileDiff, Hunk};
+use crate::export::{ExportReport, ExportedFile};
+use crate::scope::{InstalledSkill, RemovalReport};
+use crate::skill::{Diagnostic, RuleMeta, ValidationResult};
+use crate::sync::{SyncChange, SyncReport};
 use serde::Serialize;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Formatter that outputs JSON.
 pub struct JsonFormatter {
@@ -32,6 +42,7 @@ struct SkillResult {
 #[derive(Serialize)]
 struct JsonDiagnostic {
     code: String,
+    rule: &'static str,
     message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     line: Option<usize>,
@@ -39,6 +50,7 @@ struct JsonDiagnostic {
     column: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     fix_hint: Option<String>,
+    help_uri: String,
 }
 
 #[derive(Serialize)]
@@ -49,18 +61,254 @@ struct Summary {
     success: bool,
 }
 
+#[derive(Serialize)]
+struct JsonDiffOutput<'a> {
+    files: Vec<JsonFileDiff<'a>>,
+    summary: DiffSummary,
+}
+
+#[derive(Serialize)]
+struct JsonFileDiff<'a> {
+    path: &'a str,
+    hunks: Vec<JsonHunk<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonHunk<'a> {
+    header: String,
+    #[serde(flatten)]
+    hunk: &'a Hunk,
+}
+
+#[derive(Serialize)]
+struct DiffSummary {
+    files_checked: usize,
+    files_need_formatting: usize,
+    success: bool,
+}
+
+#[derive(Serialize)]
+struct JsonSkillList {
+    skills: Vec<JsonInstalledSkill>,
+    total: usize,
+}
+
+#[derive(Serialize)]
+struct JsonInstalledSkill {
+    name: String,
+    description: String,
+    path: String,
+    agent: Option<&'static str>,
+    scope: &'static str,
+}
+
+#[derive(Serialize)]
+struct JsonAgentList {
+    agents: Vec<JsonDetectedAgent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    feature_matrix: Option<Vec<JsonAgentFeatures>>,
+}
+
+#[derive(Serialize)]
+struct JsonDetectedAgent {
+    agent: &'static str,
+    display_name: &'static str,
+    skills_path: String,
+    skill_count: usize,
+    scope: &'static str,
+    features: JsonFeatures,
+}
+
+#[derive(Serialize)]
+struct JsonAgentFeatures {
+    agent: &'static str,
+    display_name: &'static str,
+    features: JsonFeatures,
+}
+
+#[derive(Serialize)]
+struct JsonFeatures {
+    context_fork: bool,
+    hooks: bool,
+    allowed_tools: bool,
+    scripts: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    script_langs: Option<Vec<ScriptLang>>,
+}
+
+#[derive(Serialize)]
+struct JsonCacheStats {
+    path: String,
+    db: JsonCacheSection,
+    checkouts: JsonCacheSection,
+    total_size: u64,
+}
+
+#[derive(Serialize)]
+struct JsonCacheSection {
+    size: u64,
+    entries: Vec<JsonCacheEntry>,
+}
+
+#[derive(Serialize)]
+struct JsonCacheEntry {
+    name: String,
+    path: String,
+    size: u64,
+    /// Last modification time in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    modified: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct JsonCacheClean {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_age_days: Option<u32>,
+    repos_removed: usize,
+    checkouts_removed: usize,
+    freed: u64,
+}
+
+#[derive(Serialize)]
+struct JsonRemoval {
+    removed: Vec<JsonRemovedSkill>,
+    failed: Vec<JsonFailedRemoval>,
+    not_found: Vec<String>,
+    success: bool,
+}
+
+#[derive(Serialize)]
+struct JsonRemovedSkill {
+    name: String,
+    path: String,
+}
+
+#[derive(Serialize)]
+struct JsonFailedRemoval {
+    name: String,
+    path: String,
+    error: String,
+}
+
+#[derive(Serialize)]
+struct JsonRuleList {
+    rules: Vec<JsonRule>,
+}
+
+#[derive(Serialize)]
+struct JsonRule {
+    code: String,
+    name: &'static str,
+    severity: &'static str,
+    summary: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    config_key: Option<&'static str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    examples: Vec<JsonRuleExample>,
+    help_uri: String,
+}
+
+#[derive(Serialize)]
+struct JsonRuleExample {
+    title: &'static str,
+    lang: &'static str,
+    code: &'static str,
+}
+
+impl From<&InstalledSkill> for JsonInstalledSkill {
+    fn from(skill: &InstalledSkill) -> Self {
+        Self {
+            name: skill.name.clone(),
+            description: skill.description.clone(),
+            path: skill.path.display().to_string(),
+            agent: skill.agent.map(|a| a.cli_name()),
+            scope: skill.scope.display_name(),
+        }
+    }
+}
+
+impl From<AgentFeatures> for JsonFeatures {
+    fn from(features: AgentFeatures) -> Self {
+        Self {
+            context_fork: features.context_fork,
+            hooks: features.hooks,
+            allowed_tools: features.allowed_tools,
+            scripts: features.scripts,
+            script_langs: features.script_langs,
+        }
+    }
+}
+
+impl From<&DetectedAgent> for JsonDetectedAgent {
+    fn from(detected: &DetectedAgent) -> Self {
+        Self {
+            agent: detected.agent.cli_name(),
+            display_name: detected.agent.display_name(),
+            skills_path: detected.skills_path.display().to_string(),
+            skill_count: detected.skill_count,
+            scope: if detected.is_global {
+                "global"
+            } else {
+                "project"
+            },
+            features: detected.agent.features().into(),
+        }
+    }
+}
+
+fn unix_seconds(time: Option<SystemTime>) -> Option<u64> {
+    time?.duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs())
+}
+
 impl From<&Diagnostic> for JsonDiagnostic {
     fn from(diag: &Diagnostic) -> Self {
         Self {
             code: diag.code.to_string(),
+            rule: diag.code.meta().name,
             message: diag.message.clone(),
             line: diag.line,
             column: diag.column,
             fix_hint: diag.fix_hint.clone(),
+            help_uri: diag.code.meta().help_uri(),
         }
     }
 }
 
+/// JSON representation of an export run.
+#[derive(Serialize)]
+struct JsonExport<'a> {
+    files: &'a [ExportedFile],
+    dry_run: bool,
+    success: bool,
+}
+
+/// JSON representation of a sync run.
+#[derive(Serialize)]
+struct JsonSync<'a> {
+    source: &'a Path,
+    agents: &'a [Agent],
+    changes: &'a [SyncChange],
+    dry_run: bool,
+    in_sync: bool,
+}
+
+/// JSON representation of effective config values.
+#[derive(Serialize)]
+struct JsonConfig {
+    entries: Vec<JsonConfigEntry>,
+}
+
+/// JSON representation of one config value.
+#[derive(Serialize)]
+struct JsonConfigEntry {
+    key: String,
+    value: toml::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    origin: Option<String>,
+}
+
 impl OutputFormatter for JsonFormatter {
     fn format_validation(&self, results: &[(String, ValidationResult)]) -> String {
         let skills: Vec<SkillResult> = results
@@ -88,6 +336,203 @@ impl OutputFormatter for JsonFormatter {
         serde_json::to_string_pretty(&output).unwrap_or_else(|_| "{}".to_string())
     }
 
+    fn format_diffs(&self, diffs: &[FileDiff], files_checked: usize) -> String {
+        let files: Vec<JsonFileDiff> = diffs
+            .iter()
+            .map(|diff| JsonFileDiff {
+                path: &diff.path,
+                hunks: diff
+                    .hunks
+                    .iter()
+                    .map(|hunk| JsonHunk {
+                        header: hunk.header(),
+                        hunk,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let output = JsonDiffOutput {
+            summary: DiffSummary {
+                files_checked,
+                files_need_formatting: files.len(),
+                success: files.is_empty(),
+            },
+            files,
+        };
+
+        serde_json::to_string_pretty(&output).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    fn format_installed_skills(&self, skills: &[InstalledSkill]) -> String {
+        let output = JsonSkillList {
+            skills: skills.iter().map(Into::into).collect(),
+            total: skills.len(),
+        };
+
+        serde_json::to_string_pretty(&output).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    fn format_agents(&self, agents: &[DetectedAgent], verbose: bool) -> String {
+        let feature_matrix = verbose.then(|| {
+            Agent::all()
+                .iter()
+                .map(|agent| JsonAgentFeatures {
+                    agent: agent.cli_name(),
+                    display_name: agent.display_name(),
+                    features: agent.features().into(),
+                })
+                .collect()
+        });
+
+        let output = JsonAgentList {
+            agents: agents.iter().map(Into::into).collect(),
+            feature_matrix,
+        };
+
+        serde_json::to_string_pretty(&output).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    fn format_cache_stats(&self, stats: &CacheStats, cache_dir: &Path) -> String {
+        let output = JsonCacheStats {
+            path: cache_dir.display().to_string(),
+            db: JsonCacheSection {
+                size: stats.db_size,
+                entries: stats
+                    .repos
+                    .iter()
+                    .map(|repo| JsonCacheEntry {
+                        name: repo.name.clone(),
+                        path: repo.path.display().to_string(),
+                        size: repo.size,
+                        modified: None,
+                    })
+                    .collect(),
+            },
+            checkouts: JsonCacheSection {
+                size: stats.checkouts_size,
+                entries: stats
+                    .checkouts
+                    .iter()
+                    .map(|checkout| JsonCacheEntry {
+                        name: checkout.name.clone(),
+                        path: checkout.path.display().to_string(),
+                        size: checkout.size,
+                        modified: unix_seconds(checkout.modified),
+                    })
+                    .collect(),
+            },
+            total_size: stats.total_size(),
+        };
+
+        serde_json::to_string_pretty(&output).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    fn format_cache_clean(&self, report: &CleanReport) -> String {
+        let output = JsonCacheClean {
+            max_age_days: report.max_age_days,
+            repos_removed: report.repos_removed,
+            checkouts_removed: report.checkouts_removed,
+            freed: report.freed,
+        };
+
+        serde_json::to_string_pretty(&output).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    fn format_removal(&self, report: &RemovalReport) -> String {
+        let output = JsonRemoval {
+            removed: report
+                .removed
+                .iter()
+                .map(|(name, path)| JsonRemovedSkill {
+                    name: name.clone(),
+                    path: path.display().to_string(),
+                })
+                .collect(),
+            failed: report
+                .failed
+                .iter()
+                .map(|(name, path, error)| JsonFailedRemoval {
+                    name: name.clone(),
+                    path: path.display().to_string(),
+                    error: error.clone(),
+                })
+                .collect(),
+            not_found: report.not_found.clone(),
+            success: report.is_ok(),
+        };
+
+        serde_json::to_string_pretty(&output).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    fn format_config(&self, entries: &[ConfigEntry], origin: bool) -> String {
+        let output = JsonConfig {
+            entries: entries
+                .iter()
+                .map(|entry| JsonConfigEntry {
+                    key: entry.key.clone(),
+                    value: entry.value.clone(),
+                    origin: origin.then(|| entry.origin_label()),
+                })
+                .collect(),
+        };
+
+        serde_json::to_string_pretty(&output).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    fn format_export(&self, report: &ExportReport) -> String {
+        let output = JsonExport {
+            files: &report.files,
+            dry_run: report.dry_run,
+            success: report.is_ok(),
+        };
+
+        serde_json::to_string_pretty(&output).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    fn format_sync(&self, report: &SyncReport) -> String {
+        let output = JsonSync {
+            source: &report.source,
+            agents: &report.agents,
+            changes: &report.changes,
+            dry_run: report.dry_run,
+            in_sync: report.is_in_sync(),
+        };
+
+        serde_json::to_string_pretty(&output).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    fn format_rules(&self, rules: &[&RuleMeta], long: bool) -> String {
+        let output = JsonRuleList {
+            rules: rules
+                .iter()
+                .map(|meta| JsonRule {
+                    code: meta.code.to_string(),
+                    name: meta.name,
+                    severity: meta.severity.as_str(),
+                    summary: meta.summary,
+                    description: long.then_some(meta.description),
+                    config_key: meta.config_key,
+                    examples: if long {
+                        meta.examples
+                            .iter()
+                            .map(|example| JsonRuleExample {
+                                title: example.title,
+                                lang: example.lang,
+                                code: example.code,
+                            })
+                            .collect()
+                    } else {
+                        Vec::new()
+                    },
+                    help_uri: meta.help_uri(),
+                })
+                .collect(),
+        };
+
+        serde_json::to_string_pretty(&output).unwrap_or_else(|_| "{}".to_string())
+    }
+
     fn format_message(&self, message: &str) {
         if !self.quiet {
             let obj = serde_json::json!({ "message": message });
@@ -107,3 +552,167 @@ impl OutputFormatter for JsonFormatter {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::{CachedCheckout, CachedRepo};
+    use crate::scope::Scope;
+    use serde_json::{json, Value};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn parse(output: String) -> Value {
+        serde_json::from_str(&output).unwrap()
+    }
+
+    #[test]
+    fn test_installed_skills_shape() {
+        let skills = vec![InstalledSkill {
+            name: "pdf".to_string(),
+            description: "Read PDFs".to_string(),
+            path: PathBuf::from(".claude/skills/pdf"),
+            agent: Some(Agent::Claude),
+            scope: Scope::Project,
+        }];
+
+        assert_eq!(
+            parse(JsonFormatter::new(false).format_installed_skills(&skills)),
+            json!({
+                "skills": [{
+                    "name": "pdf",
+                    "description": "Read PDFs",
+                    "path": ".claude/skills/pdf",
+                    "agent": "claude",
+                    "scope": "project"
+                }],
+                "total": 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_agents_shape() {
+        let agents = vec![DetectedAgent {
+            agent: Agent::Claude,
+            skills_path: PathBuf::from("/home/u/.claude/skills"),
+            skill_count: 2,
+            is_global: true,
+        }];
+
+        let output = parse(JsonFormatter::new(false).format_agents(&agents, false));
+        assert_eq!(
+            output,
+            json!({
+                "agents": [{
+                    "agent": "claude",
+                    "display_name": "Claude Code",
+                    "skills_path": "/home/u/.claude/skills",
+                    "skill_count": 2,
+                    "scope": "global",
+                    "features": {
+                        "context_fork": true,
+                        "hooks": true,
+                        "allowed_tools": true,
+                        "scripts": true
+                    }
+                }]
+            })
+        );
+
+        let verbose = parse(JsonFormatter::new(false).format_agents(&agents, true));
+        assert_eq!(verbose["feature_matrix"][0]["agent"], "opencode");
+        assert!(verbose["feature_matrix"][0]["features"].is_object());
+    }
+
+    #[test]
+    fn test_cache_stats_shape() {
+        let stats = CacheStats {
+            repos: vec![CachedRepo {
+                name: "owner-repo".to_string(),
+                path: PathBuf::from("/cache/db/owner-repo"),
+                size: 100,
+            }],
+            checkouts: vec![CachedCheckout {
+                name: "owner-repo-abc".to_string(),
+                path: PathBuf::from("/cache/checkouts/owner-repo-abc"),
+                size: 50,
+                modified: Some(UNIX_EPOCH + Duration::from_secs(60)),
+            }],
+            db_size: 100,
+            checkouts_size: 50,
+        };
+
+        assert_eq!(
+            parse(JsonFormatter::new(false).format_cache_stats(&stats, Path::new("/cache"))),
+            json!({
+                "path": "/cache",
+                "db": {
+                    "size": 100,
+                    "entries": [{
+                        "name": "owner-repo",
+                        "path": "/cache/db/owner-repo",
+                        "size": 100
+                    }]
+                },
+                "checkouts": {
+                    "size": 50,
+                    "entries": [{
+                        "name": "owner-repo-abc",
+                        "path": "/cache/checkouts/owner-repo-abc",
+                        "size": 50,
+                        "modified": 60
+                    }]
+                },
+                "total_size": 150
+            })
+        );
+    }
+
+    #[test]
+    fn test_cache_clean_shape() {
+        let report = CleanReport {
+            max_age_days: Some(30),
+            repos_removed: 0,
+            checkouts_removed: 2,
+            freed: 2048,
+        };
+
+        assert_eq!(
+            parse(JsonFormatter::new(false).format_cache_clean(&report)),
+            json!({
+                "max_age_days": 30,
+                "repos_removed": 0,
+                "checkouts_removed": 2,
+                "freed": 2048
+            })
+        );
+    }
+
+    #[test]
+    fn test_removal_shape() {
+        let report = RemovalReport {
+            removed: vec![("pdf".to_string(), PathBuf::from("skills/pdf"))],
+            failed: vec![(
+                "docx".to_string(),
+                PathBuf::from("skills/docx"),
+                "permission denied".to_string(),
+            )],
+            not_found: vec!["xlsx".to_string()],
+        };
+
+        assert_eq!(
+            parse(JsonFormatter::new(false).format_removal(&report)),
+            json!({
+                "removed": [{ "name": "pdf", "path": "skills/pdf" }],
+                "failed": [{
+                    "name": "docx",
+                    "path": "skills/docx",
+                    "error": "permission denied"
+                }],
+                "not_found": ["xlsx"],
+                "success": false
+            })
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! JUnit XML output formatter for CI test report integrations.
+
+use super::{render_xml, JsonFormatter, OutputFormatter};
+use crate::agent::DetectedAgent;
+use crate::cache::{CacheStats, CleanReport};
+use crate::config::ConfigEntry;
+use crate::diff::FileDiff;
+use crate::export::ExportReport;
+use crate::scope::{InstalledSkill, RemovalReport};
+use crate::skill::{Diagnostic, RuleMeta, ValidationResult};
+use crate::sync::SyncReport;
+use serde::Serialize;
+use std::path::Path;
+
+/// Formatter that outputs JUnit XML, reporting each skill as a test case.
+pub struct JunitFormatter {
+    quiet: bool,
+}
+
+impl JunitFormatter {
+    /// Create a new JUnit formatter.
+    pub fn new(quiet: bool) -> Self {
+        Self { quiet }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename = "testsuites")]
+struct TestSuites {
+    #[serde(rename = "@name")]
+    name: &'static str,
+    #[serde(rename = "@tests")]
+    tests: usize,
+    #[serde(rename = "@failures")]
+    failures: usize,
+    #[serde(rename = "testsuite")]
+    suites: Vec<TestSuite>,
+}
+
+#[derive(Serialize)]
+struct TestSuite {
+    #[serde(rename = "@name")]
+    name: &'static str,
+    #[serde(rename = "@tests")]
+    tests: usize,
+    #[serde(rename = "@failures")]
+    failures: usize,
+    #[serde(rename = "@errors")]
+    errors: usize,
+    #[serde(rename = "testcase")]
+    cases: Vec<TestCase>,
+}
+
+#[derive(Serialize)]
+struct TestCase {
+    #[serde(rename = "@name")]
+    name: String,
+    #[serde(rename = "@classname")]
+    classname: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    failure: Option<Failure>,
+    #[serde(rename = "system-out", skip_serializing_if = "Option::is_none")]
+    system_out: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Failure {
+    #[serde(rename = "@message")]
+    message: String,
+    #[serde(rename = "@type")]
+    kind: String,
+    #[serde(rename = "$text")]
+    text: String,
+}
+
+/// Render diagnostics as `CODE path:line:col: message` lines with hints.
+fn describe(diagnostics: &[Diagnostic]) -> String {
+    let mut text = String::new();
+
+    for diag in diagnostics {
+        let location = match (diag.line, diag.column) {
+            (Some(line), Some(col)) => format!("{}:{}:{}", diag.path, line, col),
+            (Some(line), None) => format!("{}:{}", diag.path, line),
+            _ => diag.path.clone(),
+        };
+        text.push_str(&format!("{} {}: {}\n", diag.code, location, diag.message));
+        if let Some(hint) = &diag.fix_hint {
+            text.push_str(&format!("  hint: {}\n", hint));
+        }
+    }
+
+    text
+}
+
+fn render_suite(suite: TestSuite) -> String {
+    render_xml(&TestSuites {
+        name: "skilo",
+        tests: suite.tests,
+        failures: suite.failures,
+        suites: vec![suite],
+    })
+}
+
+impl OutputFormatter for JunitFormatter {
+    fn format_validation(&self, results: &[(String, ValidationResult)]) -> String {
+        let cases: Vec<TestCase> = results
+            .iter()
+            .map(|(path, result)| TestCase {
+                name: path.clone(),
+                classname: "skilo.lint",
+                failure: result.errors.first().map(|first| Failure {
+                    message: format!("{} error(s)", result.errors.len()),
+                    kind: first.code.to_string(),
+                    text: describe(&result.errors),
+                }),
+                system_out: (!result.warnings.is_empty()).then(|| describe(&result.warnings)),
+            })
+            .collect();
+
+        let failures = cases.iter().filter(|c| c.failure.is_some()).count();
+
+        render_suite(TestSuite {
+            name: "lint",
+            tests: cases.len(),
+            failures,
+            errors: 0,
+            cases,
+        })
+    }
+
+    fn format_diffs(&self, diffs: &[FileDiff], files_checked: usize) -> String {
+        let cases: Vec<TestCase> = diffs
+            .iter()
+            .map(|diff| TestCase {
+                name: diff.path.clone(),
+                classname: "skilo.fmt",
+                failure: Some(Failure {
+                    message: "File needs formatting".to_string(),
+                    kind: "fmt".to_string(),
+                    text: diff.to_unified(),
+                }),
+                system_out: None,
+            })
+            .collect();
+
+        render_suite(TestSuite {
+            name: "fmt",
+            tests: files_checked,
+            failures: cases.len(),
+            errors: 0,
+            cases,
+        })
+    }
+
+    // JUnit only describes test results; other listings fall back to JSON.
+
+    fn format_installed_skills(&self, skills: &[InstalledSkill]) -> String {
+        JsonFormatter::new(self.quiet).format_installed_skills(skills)
+    }
+
+    fn format_agents(&self, agents: &[DetectedAgent], verbose: bool) -> String {
+        JsonFormatter::new(self.quiet).format_agents(agents, verbose)
+    }
+
+    fn format_cache_stats(&self, stats: &CacheStats, cache_dir: &Path) -> String {
+        JsonFormatter::new(self.quiet).format_cache_stats(stats, cache_dir)
+    }
+
+    fn format_cache_clean(&self, report: &CleanReport) -> String {
+        JsonFormatter::new(self.quiet).format_cache_clean(report)
+    }
+
+    fn format_removal(&self, report: &RemovalReport) -> String {
+        JsonFormatter::new(self.quiet).format_removal(report)
+    }
+
+    fn format_rules(&self, rules: &[&RuleMeta], long: bool) -> String {
+        JsonFormatter::new(self.quiet).format_rules(rules, long)
+    }
+
+    fn format_config(&self, entries: &[ConfigEntry], origin: bool) -> String {
+        JsonFormatter::new(self.quiet).format_config(entries, origin)
+    }
+
+    fn format_export(&self, report: &ExportReport) -> String {
+        JsonFormatter::new(self.quiet).format_export(report)
+    }
+
+    fn format_sync(&self, report: &SyncReport) -> String {
+        JsonFormatter::new(self.quiet).format_sync(report)
+    }
+
+    fn format_message(&self, message: &str) {
+        if !self.quiet {
+            eprintln!("{}", message);
+        }
+    }
+
+    fn format_error(&self, message: &str) {
+        eprintln!("error: {}", message);
+    }
+
+    fn format_success(&self, message: &str) {
+        if !self.quiet {
+            eprintln!("{}", message);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skill::DiagnosticCode;
+
+    #[test]
+    fn test_validation_report() {
+        let results = vec![
+            ("good/SKILL.md".to_string(), ValidationResult::default()),
+            (
+                "bad/SKILL.md".to_string(),
+                ValidationResult {
+                    errors: vec![Diagnostic {
+                        path: "bad/SKILL.md".to_string(),
+                        line: Some(2),
+                        column: None,
+                        message: "Name <Bad> & \"wrong\"".to_string(),
+                        code: DiagnosticCode::E001,
+                        fix_hint: Some("use lowercase".to_string()),
+                    }],
+                    warnings: Vec::new(),
+                },
+            ),
+        ];
+
+        let expected = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="skilo" tests="2" failures="1">
+  <testsuite name="lint" tests="2" failures="1" errors="0">
+    <testcase name="good/SKILL.md" classname="skilo.lint"/>
+    <testcase name="bad/SKILL.md" classname="skilo.lint">
+      <failure message="1 error(s)" type="E001">E001 bad/SKILL.md:2: Name &lt;Bad&gt; &amp; "wrong"
+  hint: use lowercase
+</failure>
+    </testcase>
+  </testsuite>
+</testsuites>"#;
+        assert_eq!(
+            JunitFormatter::new(false).format_validation(&results),
+            expected
+        );
+    }
+}
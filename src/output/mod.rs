@@ -1,13 +1,27 @@
 //! Output formatting for command results.
 
+mod checkstyle;
+mod github;
 mod json;
+mod junit;
 mod sarif;
 mod text;
 
+use crate::agent::DetectedAgent;
+use crate::cache::{CacheStats, CleanReport};
 use crate::cli::OutputFormat;
-use crate::skill::ValidationResult;
+use crate::config::ConfigEntry;
+use crate::diff::FileDiff;
+use crate::export::ExportReport;
+use crate::scope::{InstalledSkill, RemovalReport};
+use crate::skill::{RuleMeta, ValidationResult};
+use crate::sync::SyncReport;
+use std::path::Path;
 
+pub use checkstyle::CheckstyleFormatter;
+pub use github::GithubFormatter;
 pub use json::JsonFormatter;
+pub use junit::JunitFormatter;
 pub use sarif::SarifFormatter;
 pub use text::TextFormatter;
 
@@ -15,6 +29,26 @@ pub use text::TextFormatter;
 pub trait OutputFormatter {
     /// Format validation results.
     fn format_validation(&self, results: &[(String, ValidationResult)]) -> String;
+    /// Format the diffs of files that need formatting.
+    fn format_diffs(&self, diffs: &[FileDiff], files_checked: usize) -> String;
+    /// Format a listing of installed skills, grouped by scope and agent.
+    fn format_installed_skills(&self, skills: &[InstalledSkill]) -> String;
+    /// Format detected agents, optionally with the full feature matrix.
+    fn format_agents(&self, agents: &[DetectedAgent], verbose: bool) -> String;
+    /// Format git cache statistics for the cache at `cache_dir`.
+    fn format_cache_stats(&self, stats: &CacheStats, cache_dir: &Path) -> String;
+    /// Format the outcome of a `cache clean` command.
+    fn format_cache_clean(&self, report: &CleanReport) -> String;
+    /// Format the outcome of a `remove` command.
+    fn format_removal(&self, report: &RemovalReport) -> String;
+    /// Format rule metadata, as a summary listing or with full documentation.
+    fn format_rules(&self, rules: &[&RuleMeta], long: bool) -> String;
+    /// Format effective config values, optionally with the file each came from.
+    fn format_config(&self, entries: &[ConfigEntry], origin: bool) -> String;
+    /// Format the files written by an export.
+    fn format_export(&self, report: &ExportReport) -> String;
+    /// Format the changes made (or planned) by a sync.
+    fn format_sync(&self, report: &SyncReport) -> String;
     /// Format an informational message.
     fn format_message(&self, message: &str);
     /// Format an error message.
@@ -29,5 +63,19 @@ pub fn get_formatter(format: OutputFormat, quiet: bool) -> Box<dyn OutputFormatt
         OutputFormat::Text => Box::new(TextFormatter::new(quiet)),
         OutputFormat::Json => Box::new(JsonFormatter::new(quiet)),
         OutputFormat::Sarif => Box::new(SarifFormatter::new(quiet)),
+        OutputFormat::Junit => Box::new(JunitFormatter::new(quiet)),
+        OutputFormat::Checkstyle => Box::new(CheckstyleFormatter::new(quiet)),
+        OutputFormat::Github => Box::new(GithubFormatter::new(quiet)),
+    }
+}
+
+/// Serialize a value as an indented XML document with a declaration.
+fn render_xml<T: serde::Serialize>(value: &T) -> String {
+    let mut buffer = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let mut serializer = quick_xml::se::Serializer::new(&mut buffer);
+    serializer.indent(' ', 2);
+    match value.serialize(serializer) {
+        Ok(_) => buffer,
+        Err(e) => format!("<!-- XML serialization failed: {} -->", e),
     }
 }
@@ -1,8 +1,16 @@
 //! SARIF output formatter for code scanning integrations.
 
-use super::OutputFormatter;
-use crate::skill::{DiagnosticCode, ValidationResult};
+use super::{JsonFormatter, OutputFormatter};
+use crate::agent::DetectedAgent;
+use crate::cache::{CacheStats, CleanReport};
+use crate::config::ConfigEntry;
+use crate::diff::FileDiff;
+use crate::export::ExportReport;
+use crate::scope::{InstalledSkill, RemovalReport};
+use crate::skill::{RuleMeta, ValidationResult};
+use crate::sync::SyncReport;
 use serde::Serialize;
+use std::path::Path;
 
 /// Formatter that outputs SARIF (Static Analysis Results Interchange Format).
 pub struct SarifFormatter {
@@ -47,8 +55,16 @@ struct SarifDriver {
 #[derive(Serialize)]
 struct SarifRule {
     id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'static str>,
     #[serde(rename = "shortDescription")]
     short_description: SarifMessage,
+    #[serde(rename = "fullDescription", skip_serializing_if = "Option::is_none")]
+    full_description: Option<SarifMessage>,
+    #[serde(rename = "helpUri", skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help: Option<SarifMultiformatMessage>,
     #[serde(rename = "defaultConfiguration")]
     default_configuration: SarifConfiguration,
 }
@@ -72,6 +88,12 @@ struct SarifMessage {
     text: String,
 }
 
+#[derive(Serialize)]
+struct SarifMultiformatMessage {
+    text: String,
+    markdown: String,
+}
+
 #[derive(Serialize)]
 struct SarifLocation {
     #[serde(rename = "physicalLocation")]
@@ -99,21 +121,50 @@ struct SarifRegion {
     start_column: Option<usize>,
 }
 
-fn get_rule_description(code: DiagnosticCode) -> &'static str {
-    match code {
-        DiagnosticCode::E001 => "Invalid skill name format",
-        DiagnosticCode::E002 => "Skill name exceeds maximum length",
-        DiagnosticCode::E003 => "Skill name does not match directory name",
-        DiagnosticCode::E004 => "Missing skill description",
-        DiagnosticCode::E005 => "Skill description exceeds maximum length",
-        DiagnosticCode::E006 => "Compatibility field exceeds maximum length",
-        DiagnosticCode::E007 => "Invalid YAML in frontmatter",
-        DiagnosticCode::E008 => "Missing SKILL.md file",
-        DiagnosticCode::E009 => "Referenced file not found",
-        DiagnosticCode::W001 => "Skill body exceeds recommended length",
-        DiagnosticCode::W002 => "Script is not executable",
-        DiagnosticCode::W003 => "Script missing shebang line",
-        DiagnosticCode::W004 => "Empty optional directory",
+/// Rule ID used for formatting differences reported by `fmt --check`.
+const FORMAT_RULE_ID: &str = "fmt";
+
+/// Serialize a single-run SARIF log.
+fn render_log(rules: Vec<SarifRule>, results: Vec<SarifResult>) -> String {
+    let log = SarifLog {
+        schema: "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/main/sarif-2.1/schema/sarif-schema-2.1.0.json",
+        version: "2.1.0",
+        runs: vec![SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: "skilo",
+                    version: env!("CARGO_PKG_VERSION"),
+                    information_uri: env!("CARGO_PKG_REPOSITORY"),
+                    rules,
+                },
+            },
+            results,
+        }],
+    };
+
+    serde_json::to_string_pretty(&log).unwrap_or_else(|_| "{}".to_string())
+}
+
+impl From<&RuleMeta> for SarifRule {
+    fn from(meta: &RuleMeta) -> Self {
+        Self {
+            id: meta.code.to_string(),
+            name: Some(meta.name),
+            short_description: SarifMessage {
+                text: meta.summary.to_string(),
+            },
+            full_description: Some(SarifMessage {
+                text: meta.description.trim().to_string(),
+            }),
+            help_uri: Some(meta.help_uri()),
+            help: Some(SarifMultiformatMessage {
+                text: meta.description.trim().to_string(),
+                markdown: meta.to_markdown(),
+            }),
+            default_configuration: SarifConfiguration {
+                level: meta.severity.as_str(),
+            },
+        }
     }
 }
 
@@ -126,19 +177,7 @@ impl OutputFormatter for SarifFormatter {
         for (_, result) in results {
             for diag in result.errors.iter().chain(result.warnings.iter()) {
                 if seen_codes.insert(diag.code) {
-                    rules.push(SarifRule {
-                        id: diag.code.to_string(),
-                        short_description: SarifMessage {
-                            text: get_rule_description(diag.code).to_string(),
-                        },
-                        default_configuration: SarifConfiguration {
-                            level: if diag.code.is_error() {
-                                "error"
-                            } else {
-                                "warning"
-                            },
-                        },
-                    });
+                    rules.push(diag.code.meta().into());
                 }
             }
         }
@@ -171,23 +210,89 @@ impl OutputFormatter for SarifFormatter {
             }
         }
 
-        let log = SarifLog {
-            schema: "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/main/sarif-2.1/schema/sarif-schema-2.1.0.json",
-            version: "2.1.0",
-            runs: vec![SarifRun {
-                tool: SarifTool {
-                    driver: SarifDriver {
-                        name: "skilo",
-                        version: env!("CARGO_PKG_VERSION"),
-                        information_uri: "https://github.com/example/skilo",
-                        rules,
-                    },
+        render_log(rules, sarif_results)
+    }
+
+    fn format_diffs(&self, diffs: &[FileDiff], _files_checked: usize) -> String {
+        let rules = if diffs.is_empty() {
+            Vec::new()
+        } else {
+            vec![SarifRule {
+                id: FORMAT_RULE_ID.to_string(),
+                name: None,
+                short_description: SarifMessage {
+                    text: "File is not formatted".to_string(),
                 },
-                results: sarif_results,
-            }],
+                full_description: None,
+                help_uri: None,
+                help: None,
+                default_configuration: SarifConfiguration { level: "warning" },
+            }]
         };
 
-        serde_json::to_string_pretty(&log).unwrap_or_else(|_| "{}".to_string())
+        let results = diffs
+            .iter()
+            .flat_map(|diff| {
+                diff.hunks.iter().map(|hunk| SarifResult {
+                    rule_id: FORMAT_RULE_ID.to_string(),
+                    level: "warning",
+                    message: SarifMessage {
+                        text: format!("Formatting differs: {}", hunk.header()),
+                    },
+                    locations: vec![SarifLocation {
+                        physical_location: SarifPhysicalLocation {
+                            artifact_location: SarifArtifactLocation {
+                                uri: diff.path.clone(),
+                            },
+                            region: Some(SarifRegion {
+                                start_line: hunk.old_start.max(1),
+                                start_column: None,
+                            }),
+                        },
+                    }],
+                })
+            })
+            .collect();
+
+        render_log(rules, results)
+    }
+
+    // SARIF only describes analysis results; other listings fall back to JSON.
+
+    fn format_installed_skills(&self, skills: &[InstalledSkill]) -> String {
+        JsonFormatter::new(self.quiet).format_installed_skills(skills)
+    }
+
+    fn format_agents(&self, agents: &[DetectedAgent], verbose: bool) -> String {
+        JsonFormatter::new(self.quiet).format_agents(agents, verbose)
+    }
+
+    fn format_cache_stats(&self, stats: &CacheStats, cache_dir: &Path) -> String {
+        JsonFormatter::new(self.quiet).format_cache_stats(stats, cache_dir)
+    }
+
+    fn format_cache_clean(&self, report: &CleanReport) -> String {
+        JsonFormatter::new(self.quiet).format_cache_clean(report)
+    }
+
+    fn format_removal(&self, report: &RemovalReport) -> String {
+        JsonFormatter::new(self.quiet).format_removal(report)
+    }
+
+    fn format_rules(&self, rules: &[&RuleMeta], long: bool) -> String {
+        JsonFormatter::new(self.quiet).format_rules(rules, long)
+    }
+
+    fn format_config(&self, entries: &[ConfigEntry], origin: bool) -> String {
+        JsonFormatter::new(self.quiet).format_config(entries, origin)
+    }
+
+    fn format_export(&self, report: &ExportReport) -> String {
+        JsonFormatter::new(self.quiet).format_export(report)
+    }
+
+    fn format_sync(&self, report: &SyncReport) -> String {
+        JsonFormatter::new(self.quiet).format_sync(report)
     }
 
     fn format_message(&self, message: &str) {
@@ -1,8 +1,18 @@
 //! Human-readable text output formatter.
 
 use super::OutputFormatter;
-use crate::skill::ValidationResult;
+use crate::agent::{Agent, AgentFeatures, DetectedAgent};
+use crate::cache::{format_size, CacheStats, CleanReport};
+use crate::config::ConfigEntry;
+use crate::diff::{FileDiff, LineKind};
+use crate::export::{ExportReport, WriteStatus};
+use crate::scope::{InstalledSkill, RemovalReport, Scope};
+use crate::skill::{RuleMeta, Severity, ValidationResult};
+use crate::sync::{SyncAction, SyncReport};
 use colored::Colorize;
+use std::collections::HashSet;
+use std::path::Path;
+use std::time::SystemTime;
 
 /// Formatter that outputs human-readable text.
 pub struct TextFormatter {
@@ -95,6 +105,436 @@ impl OutputFormatter for TextFormatter {
         output
     }
 
+    fn format_diffs(&self, diffs: &[FileDiff], _files_checked: usize) -> String {
+        let mut output = String::new();
+
+        for diff in diffs {
+            let (old_header, new_header) = diff.file_headers();
+            output.push_str(&format!(
+                "{}\n{}\n",
+                old_header.dimmed(),
+                new_header.dimmed()
+            ));
+
+            for hunk in &diff.hunks {
+                output.push_str(&format!("{}\n", hunk.header().cyan()));
+
+                for line in &hunk.lines {
+                    let text = format!("{}{}", line.kind.prefix(), line.content);
+                    match line.kind {
+                        LineKind::Context => output.push_str(&text),
+                        LineKind::Delete => output.push_str(&text.red().to_string()),
+                        LineKind::Insert => output.push_str(&text.green().to_string()),
+                    }
+                    output.push('\n');
+
+                    if line.missing_newline {
+                        output.push_str(&format!("{}\n", "\\ No newline at end of file".dimmed()));
+                    }
+                }
+            }
+        }
+
+        output
+    }
+
+    fn format_installed_skills(&self, skills: &[InstalledSkill]) -> String {
+        let mut output = String::new();
+
+        for scope in [Scope::Project, Scope::Global] {
+            let in_scope: Vec<&InstalledSkill> =
+                skills.iter().filter(|s| s.scope == scope).collect();
+            if in_scope.is_empty() {
+                continue;
+            }
+
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            let heading = match scope {
+                Scope::Project => "Project skills:",
+                Scope::Global => "Global skills:",
+            };
+            output.push_str(&format!("{}\n", heading.bold()));
+
+            // Group by agent, keeping the order in which agents first appear
+            let mut agents: Vec<Option<Agent>> = Vec::new();
+            for skill in &in_scope {
+                if !agents.contains(&skill.agent) {
+                    agents.push(skill.agent);
+                }
+            }
+
+            for agent in agents {
+                let group: Vec<&InstalledSkill> = in_scope
+                    .iter()
+                    .filter(|s| s.agent == agent)
+                    .copied()
+                    .collect();
+
+                let (name, dir) = match agent {
+                    Some(agent) if scope.is_global() => {
+                        (agent.display_name(), agent.global_skills_dir())
+                    }
+                    Some(agent) => (agent.display_name(), agent.skills_dir()),
+                    None => ("Skills", "skills"),
+                };
+                output.push_str(&format!("  {} ({}):\n", name.cyan(), dir.dimmed()));
+
+                let max_name_len = group
+                    .iter()
+                    .map(|s| s.name.len())
+                    .max()
+                    .unwrap_or(20)
+                    .max(10);
+
+                for skill in group {
+                    output.push_str(&format!(
+                        "    {:<width$}  {}\n",
+                        skill.name.cyan(),
+                        truncate_description(&skill.description, 50),
+                        width = max_name_len
+                    ));
+                }
+            }
+        }
+
+        // Global skills hidden by a project skill of the same agent and name
+        let project: HashSet<(Option<Agent>, &str)> = skills
+            .iter()
+            .filter(|s| s.scope.is_project())
+            .map(|s| (s.agent, s.name.as_str()))
+            .collect();
+        let shadowed: Vec<&InstalledSkill> = skills
+            .iter()
+            .filter(|s| s.scope.is_global() && project.contains(&(s.agent, s.name.as_str())))
+            .collect();
+
+        if !shadowed.is_empty() {
+            output.push_str(&format!(
+                "\n{}: {} global skill(s) shadowed by project skills:\n",
+                "Note".yellow(),
+                shadowed.len()
+            ));
+            for skill in shadowed {
+                output.push_str(&format!("  {} {}\n", "-".dimmed(), skill.name.dimmed()));
+            }
+        }
+
+        output
+    }
+
+    fn format_agents(&self, agents: &[DetectedAgent], verbose: bool) -> String {
+        let mut output = String::new();
+
+        for (heading, global) in [("Project agents:", false), ("Global agents:", true)] {
+            let group: Vec<&DetectedAgent> =
+                agents.iter().filter(|a| a.is_global == global).collect();
+            if group.is_empty() {
+                continue;
+            }
+
+            output.push_str(&format!("{}\n", heading.bold()));
+            for agent in group {
+                let skill_text = if agent.skill_count == 1 {
+                    "1 skill".to_string()
+                } else {
+                    format!("{} skills", agent.skill_count)
+                };
+
+                output.push_str(&format!(
+                    "  {:<14} {}  ({})\n",
+                    agent.agent.display_name().cyan(),
+                    agent.skills_path.display(),
+                    skill_text.dimmed()
+                ));
+
+                if verbose {
+                    let supported = supported_features(&agent.agent.features());
+                    if !supported.is_empty() {
+                        output.push_str(&format!(
+                            "    {} {}\n",
+                            "Features:".dimmed(),
+                            supported.join(", ").dimmed()
+                        ));
+                    }
+                }
+            }
+            output.push('\n');
+        }
+
+        if verbose {
+            output.push_str(&format!("{}\n\n", "Feature support:".bold()));
+            output.push_str(&format!(
+                "  {:<14} {:^12} {:^8} {:^14} {:^8}\n",
+                "Agent".bold(),
+                "context:fork".bold(),
+                "hooks".bold(),
+                "allowed-tools".bold(),
+                "scripts".bold()
+            ));
+            output.push_str(&format!("  {}\n", "-".repeat(60)));
+
+            for agent in Agent::all() {
+                let features = agent.features();
+                output.push_str(&format!(
+                    "  {:<14} {:^12} {:^8} {:^14} {:^8}\n",
+                    agent.display_name(),
+                    feature_mark(features.context_fork),
+                    feature_mark(features.hooks),
+                    feature_mark(features.allowed_tools),
+                    feature_mark(features.scripts),
+                ));
+            }
+        }
+
+        output
+    }
+
+    fn format_cache_stats(&self, stats: &CacheStats, cache_dir: &Path) -> String {
+        let mut output = format!(
+            "Cache directory: {}\n\n",
+            cache_dir.display().to_string().cyan()
+        );
+
+        output.push_str(&format!(
+            "  {}: {} repositories, {}\n",
+            "db/".bold(),
+            stats.repos.len(),
+            format_size(stats.db_size)
+        ));
+        for repo in &stats.repos {
+            output.push_str(&format!("    {}\n", repo.name));
+        }
+
+        if !stats.repos.is_empty() && !stats.checkouts.is_empty() {
+            output.push('\n');
+        }
+
+        output.push_str(&format!(
+            "  {}: {} checkouts, {}\n",
+            "checkouts/".bold(),
+            stats.checkouts.len(),
+            format_size(stats.checkouts_size)
+        ));
+        for checkout in &stats.checkouts {
+            output.push_str(&format!(
+                "    {} {}\n",
+                checkout.name,
+                format_age(checkout.modified).dimmed()
+            ));
+        }
+
+        if !stats.checkouts.is_empty() || !stats.repos.is_empty() {
+            output.push_str(&format!(
+                "\nTotal: {}\n",
+                format_size(stats.total_size()).cyan()
+            ));
+        }
+
+        output
+    }
+
+    fn format_cache_clean(&self, report: &CleanReport) -> String {
+        let removed = report.checkouts_removed;
+        match report.max_age_days {
+            None => format!(
+                "{} Removed {} repositories, {} checkouts ({} freed)\n",
+                "✓".green().bold(),
+                report.repos_removed,
+                removed,
+                format_size(report.freed)
+            ),
+            Some(days) if removed == 0 => {
+                format!("No checkouts older than {} days found\n", days)
+            }
+            Some(_) => format!(
+                "{} Removed {} checkout{} ({} freed)\n",
+                "✓".green().bold(),
+                removed,
+                if removed == 1 { "" } else { "s" },
+                format_size(report.freed)
+            ),
+        }
+    }
+
+    fn format_removal(&self, report: &RemovalReport) -> String {
+        let removed = report.removed.len();
+        format!(
+            "{} Removed {} skill{}\n",
+            "✓".green().bold(),
+            removed,
+            if removed == 1 { "" } else { "s" }
+        )
+    }
+
+    fn format_config(&self, entries: &[ConfigEntry], origin: bool) -> String {
+        let lines: Vec<(String, String)> = entries
+            .iter()
+            .map(|entry| {
+                (
+                    format!("{} = {}", entry.key, entry.value),
+                    entry.origin_label(),
+                )
+            })
+            .collect();
+        let width = lines.iter().map(|(line, _)| line.len()).max().unwrap_or(0);
+
+        let mut output = String::new();
+        for (line, label) in lines {
+            if origin {
+                output.push_str(&format!(
+                    "{:width$}  {}\n",
+                    line,
+                    format!("# {}", label).dimmed(),
+                    width = width
+                ));
+            } else {
+                output.push_str(&format!("{}\n", line));
+            }
+        }
+        output
+    }
+
+    fn format_export(&self, report: &ExportReport) -> String {
+        let mut output = String::new();
+        let (mut created, mut updated, mut unchanged, mut skipped) = (0, 0, 0, 0);
+
+        for file in &report.files {
+            let path = file.path.display().to_string();
+            let source = format!("({} → {})", file.skill, file.target).dimmed();
+            match &file.status {
+                WriteStatus::Created => {
+                    created += 1;
+                    output.push_str(&format!("{} {} {}\n", "+".green(), path, source));
+                }
+                WriteStatus::Updated => {
+                    updated += 1;
+                    output.push_str(&format!("{} {} {}\n", "~".yellow(), path, source));
+                }
+                WriteStatus::Unchanged => {
+                    unchanged += 1;
+                    if !self.quiet {
+                        output
+                            .push_str(&format!("{}\n", format!("= {} {}", path, source).dimmed()));
+                    }
+                }
+                WriteStatus::Skipped(reason) => {
+                    skipped += 1;
+                    output.push_str(&format!("{} {} {}: {}\n", "!".red(), path, source, reason));
+                }
+            }
+        }
+
+        let verb = if report.dry_run {
+            "Would write"
+        } else {
+            "Wrote"
+        };
+        let mark = if report.is_ok() {
+            "✓".green().bold()
+        } else {
+            "✗".red().bold()
+        };
+        output.push_str(&format!(
+            "\n{} {} {} file(s): {} created, {} updated, {} unchanged, {} skipped\n",
+            mark,
+            verb,
+            created + updated,
+            created,
+            updated,
+            unchanged,
+            skipped
+        ));
+        output
+    }
+
+    fn format_sync(&self, report: &SyncReport) -> String {
+        let mut output = String::new();
+
+        for change in &report.changes {
+            let path = change.path.display().to_string();
+            let agent = format!("({})", change.agent.display_name()).dimmed();
+            match change.action {
+                SyncAction::Added => {
+                    output.push_str(&format!("{} {} {}\n", "+".green(), path, agent));
+                }
+                SyncAction::Updated => {
+                    output.push_str(&format!("{} {} {}\n", "~".yellow(), path, agent));
+                }
+                SyncAction::Deleted => {
+                    output.push_str(&format!("{} {} {}\n", "-".red(), path, agent));
+                }
+                SyncAction::Unchanged => {
+                    if !self.quiet {
+                        output.push_str(&format!("{}\n", format!("= {} {}", path, agent).dimmed()));
+                    }
+                }
+            }
+        }
+
+        if report.is_in_sync() {
+            output.push_str(&format!(
+                "\n{} {} agent(s) in sync with {}\n",
+                "✓".green().bold(),
+                report.agents.len(),
+                report.source.display()
+            ));
+            return output;
+        }
+
+        let (mark, verb) = if report.dry_run {
+            ("!".yellow().bold(), "Would sync")
+        } else {
+            ("✓".green().bold(), "Synced")
+        };
+        output.push_str(&format!(
+            "\n{} {} {} agent(s) from {}: {} added, {} updated, {} deleted, {} unchanged\n",
+            mark,
+            verb,
+            report.agents.len(),
+            report.source.display(),
+            report.count(SyncAction::Added),
+            report.count(SyncAction::Updated),
+            report.count(SyncAction::Deleted),
+            report.count(SyncAction::Unchanged)
+        ));
+        output
+    }
+
+    fn format_rules(&self, rules: &[&RuleMeta], long: bool) -> String {
+        if long {
+            return rules
+                .iter()
+                .map(|meta| format!("{}\n{}\n", meta.to_markdown(), meta.help_uri().dimmed()))
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+
+        let name_width = rules.iter().map(|meta| meta.name.len()).max().unwrap_or(0);
+        let mut output = String::new();
+        for meta in rules {
+            let severity = match meta.severity {
+                Severity::Error => "error  ".red(),
+                Severity::Warning => "warning".yellow(),
+            };
+            output.push_str(&format!(
+                "  {}  {:<width$}  {}  {}\n",
+                meta.code.to_string().bold(),
+                meta.name,
+                severity,
+                meta.summary,
+                width = name_width
+            ));
+        }
+        output.push_str(&format!(
+            "\nRun {} for details on a rule.\n",
+            "skilo explain <CODE>".cyan()
+        ));
+
+        output
+    }
+
     fn format_message(&self, message: &str) {
         if !self.quiet {
             println!("{}", message);
@@ -111,3 +551,97 @@ impl OutputFormatter for TextFormatter {
         }
     }
 }
+
+/// Truncate a description to its first sentence and a maximum length.
+fn truncate_description(s: &str, max_len: usize) -> String {
+    if s.is_empty() {
+        return "(no description)".dimmed().to_string();
+    }
+
+    let first_sentence = s.split(". ").next().unwrap_or(s);
+
+    // Count characters, not bytes, so multibyte text is never split
+    if first_sentence.chars().count() <= max_len {
+        first_sentence.to_string()
+    } else {
+        let truncated: String = first_sentence
+            .chars()
+            .take(max_len.saturating_sub(3))
+            .collect();
+        format!("{}...", truncated)
+    }
+}
+
+/// Names of the features an agent supports.
+fn supported_features(features: &AgentFeatures) -> Vec<&'static str> {
+    let mut supported = Vec::new();
+    if features.context_fork {
+        supported.push("context:fork");
+    }
+    if features.hooks {
+        supported.push("hooks");
+    }
+    if features.allowed_tools {
+        supported.push("allowed-tools");
+    }
+    if features.scripts {
+        supported.push("scripts");
+    }
+    supported
+}
+
+/// Return a mark for feature support.
+fn feature_mark(supported: bool) -> &'static str {
+    if supported {
+        "Yes"
+    } else {
+        "-"
+    }
+}
+
+/// Format age as a human-readable string.
+fn format_age(modified: Option<SystemTime>) -> String {
+    let Some(modified) = modified else {
+        return String::new();
+    };
+
+    let Ok(age) = SystemTime::now().duration_since(modified) else {
+        return String::new();
+    };
+
+    let secs = age.as_secs();
+    let mins = secs / 60;
+    let hours = mins / 60;
+    let days = hours / 24;
+    let weeks = days / 7;
+
+    if weeks > 0 {
+        format!("({} week{} ago)", weeks, if weeks == 1 { "" } else { "s" })
+    } else if days > 0 {
+        format!("({} day{} ago)", days, if days == 1 { "" } else { "s" })
+    } else if hours > 0 {
+        format!("({} hour{} ago)", hours, if hours == 1 { "" } else { "s" })
+    } else if mins > 0 {
+        format!("({} minute{} ago)", mins, if mins == 1 { "" } else { "s" })
+    } else {
+        "(just now)".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_description_keeps_short_text() {
+        assert_eq!(truncate_description("Read PDFs. More.", 50), "Read PDFs");
+    }
+
+    #[test]
+    fn test_truncate_description_multibyte() {
+        let description = "日本語のドキュメントを処理して要約を作成するスキル";
+        let truncated = truncate_description(description, 10);
+        assert_eq!(truncated, "日本語のドキュ...");
+        assert_eq!(truncate_description("🎉🎉🎉🎉🎉🎉", 5), "🎉🎉...");
+    }
+}
@@ -0,0 +1,276 @@
+//! Token budgeting for generated prompts.
+//!
+//! Token counts are estimated offline: runs of letters and digits count one
+//! token per four characters and every other non-space character counts as
+//! one token, which tracks BPE tokenizers closely enough for budgeting.
+
+use super::PromptSkill;
+use serde::Serialize;
+
+/// Descriptions longer than this many characters are shortened first.
+pub const DESCRIPTION_LIMIT: usize = 160;
+
+/// Metadata key holding a skill's priority (higher is more important).
+pub const PRIORITY_KEY: &str = "priority";
+
+/// Estimate the number of tokens in `text`.
+pub fn estimate_tokens(text: &str) -> usize {
+    let mut tokens: usize = 0;
+    let mut run: usize = 0;
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            run += 1;
+            continue;
+        }
+        tokens += run.div_ceil(4);
+        run = 0;
+        if !c.is_whitespace() {
+            tokens += 1;
+        }
+    }
+    tokens + run.div_ceil(4)
+}
+
+/// What happened to a skill while fitting the budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    /// Included unchanged.
+    Kept,
+    /// Included with a shortened description.
+    Shortened,
+    /// Left out to fit the budget.
+    Dropped,
+}
+
+/// Estimated cost of a single skill.
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillCost {
+    /// Skill name.
+    pub name: String,
+    /// Estimated tokens the skill adds to the prompt (0 when dropped).
+    pub tokens: usize,
+    /// What happened to the skill.
+    pub outcome: Outcome,
+}
+
+/// Token usage of a generated prompt.
+#[derive(Debug, Clone, Serialize)]
+pub struct BudgetReport {
+    /// Requested budget, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<usize>,
+    /// Estimated tokens of the whole output.
+    pub total_tokens: usize,
+    /// Per-skill costs, in output order.
+    pub skills: Vec<SkillCost>,
+}
+
+impl BudgetReport {
+    /// Number of skills left out of the prompt.
+    pub fn dropped(&self) -> usize {
+        self.skills
+            .iter()
+            .filter(|s| s.outcome == Outcome::Dropped)
+            .count()
+    }
+}
+
+/// Order skill indices from most to least important.
+///
+/// Skills listed in `priority` come first, in that order. The rest are
+/// ranked by their `priority` metadata value (higher first), then by how
+/// recently their SKILL.md changed, then by name.
+pub fn rank(skills: &[PromptSkill], priority: &[String]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..skills.len()).collect();
+    order.sort_by_key(|&i| {
+        let skill = &skills[i];
+        let listed = priority
+            .iter()
+            .position(|name| *name == skill.name)
+            .unwrap_or(usize::MAX);
+        let declared = skill
+            .metadata
+            .get(PRIORITY_KEY)
+            .and_then(|p| p.trim().parse::<i64>().ok())
+            .unwrap_or(0);
+        (
+            listed,
+            std::cmp::Reverse(declared),
+            std::cmp::Reverse(skill.modified),
+            skill.name.clone(),
+        )
+    });
+    order
+}
+
+/// Fit `skills` into `max_tokens`, measuring rendered output with `measure`.
+///
+/// Overlong descriptions are shortened first; if the output still does not
+/// fit, the least important skills are dropped. Kept skills stay in their
+/// original order.
+pub fn fit(
+    mut skills: Vec<PromptSkill>,
+    max_tokens: Option<usize>,
+    priority: &[String],
+    measure: impl Fn(&[PromptSkill]) -> usize,
+) -> (Vec<PromptSkill>, BudgetReport) {
+    let mut outcomes = vec![Outcome::Kept; skills.len()];
+
+    if let Some(max) = max_tokens {
+        if measure(&skills) > max {
+            for (skill, outcome) in skills.iter_mut().zip(outcomes.iter_mut()) {
+                if let Some(short) = shorten(&skill.description, DESCRIPTION_LIMIT) {
+                    skill.description = short;
+                    *outcome = Outcome::Shortened;
+                }
+            }
+        }
+
+        let mut order = rank(&skills, priority);
+        while measure(&kept(&skills, &outcomes)) > max {
+            let Some(least) = order.pop() else {
+                break;
+            };
+            outcomes[least] = Outcome::Dropped;
+        }
+    }
+
+    let empty = measure(&[]);
+    let costs = skills
+        .iter()
+        .zip(&outcomes)
+        .map(|(skill, outcome)| SkillCost {
+            name: skill.name.clone(),
+            tokens: match outcome {
+                Outcome::Dropped => 0,
+                _ => measure(std::slice::from_ref(skill)).saturating_sub(empty),
+            },
+            outcome: *outcome,
+        })
+        .collect();
+
+    let skills = kept(&skills, &outcomes);
+    let report = BudgetReport {
+        max_tokens,
+        total_tokens: measure(&skills),
+        skills: costs,
+    };
+    (skills, report)
+}
+
+fn kept(skills: &[PromptSkill], outcomes: &[Outcome]) -> Vec<PromptSkill> {
+    skills
+        .iter()
+        .zip(outcomes)
+        .filter(|(_, outcome)| **outcome != Outcome::Dropped)
+        .map(|(skill, _)| skill.clone())
+        .collect()
+}
+
+/// Shorten `text` to at most `limit` characters at a word boundary.
+///
+/// Returns `None` when the text already fits.
+fn shorten(text: &str, limit: usize) -> Option<String> {
+    if text.chars().count() <= limit {
+        return None;
+    }
+
+    let cut: String = text.chars().take(limit.saturating_sub(1)).collect();
+    let cut = match cut.rfind(char::is_whitespace) {
+        Some(space) if space > 0 => &cut[..space],
+        _ => cut.as_str(),
+    };
+    Some(format!(
+        "{}…",
+        cut.trim_end_matches(|c: char| c.is_whitespace() || c == ',')
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn skill(name: &str, description: &str, priority: Option<&str>) -> PromptSkill {
+        let mut metadata = BTreeMap::new();
+        if let Some(p) = priority {
+            metadata.insert(PRIORITY_KEY.to_string(), p.to_string());
+        }
+        PromptSkill {
+            name: name.to_string(),
+            description: description.to_string(),
+            location: format!("{}/SKILL.md", name),
+            license: None,
+            metadata,
+            instructions: None,
+            files: Vec::new(),
+            modified: None,
+        }
+    }
+
+    fn measure(skills: &[PromptSkill]) -> usize {
+        skills
+            .iter()
+            .map(|s| estimate_tokens(&format!("{}: {}", s.name, s.description)))
+            .sum()
+    }
+
+    #[test]
+    fn test_estimate_tokens() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("pdf"), 1);
+        assert_eq!(estimate_tokens("extract text"), 3);
+        assert_eq!(estimate_tokens("<name>pdf</name>"), 8);
+    }
+
+    #[test]
+    fn test_rank_uses_config_then_metadata_then_name() {
+        let skills = vec![
+            skill("a", "", None),
+            skill("b", "", Some("5")),
+            skill("c", "", Some("1")),
+            skill("d", "", None),
+        ];
+        assert_eq!(rank(&skills, &["d".to_string()]), vec![3, 1, 2, 0]);
+    }
+
+    #[test]
+    fn test_fit_without_budget_keeps_everything() {
+        let skills = vec![skill("a", "First skill", None)];
+        let (kept, report) = fit(skills, None, &[], measure);
+
+        assert_eq!(kept.len(), 1);
+        assert_eq!(report.skills[0].outcome, Outcome::Kept);
+        assert_eq!(report.total_tokens, report.skills[0].tokens);
+    }
+
+    #[test]
+    fn test_fit_shortens_then_drops_lowest_priority() {
+        let long = "word ".repeat(60);
+        let skills = vec![
+            skill("low", "Rarely needed", None),
+            skill("high", &long, Some("10")),
+            skill("mid", "Sometimes needed", Some("1")),
+        ];
+        let (kept, report) = fit(skills, Some(44), &[], measure);
+
+        let names: Vec<&str> = kept.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["high", "mid"]);
+        assert!(kept[0].description.ends_with('…'));
+        assert!(kept[0].description.chars().count() <= DESCRIPTION_LIMIT);
+        assert_eq!(report.skills[0].outcome, Outcome::Dropped);
+        assert_eq!(report.skills[1].outcome, Outcome::Shortened);
+        assert_eq!(report.dropped(), 1);
+        assert!(report.total_tokens <= 44);
+    }
+
+    #[test]
+    fn test_shorten_at_word_boundary() {
+        assert_eq!(shorten("short", 10), None);
+        assert_eq!(
+            shorten("one two three four", 12).as_deref(),
+            Some("one two…")
+        );
+    }
+}
@@ -0,0 +1,192 @@
+//! Embedding skill instructions and referenced files into prompts.
+//!
+//! Harnesses that cannot read files at run time need the skill body, and
+//! optionally the files it references under `references/`, inside the
+//! prompt itself.
+
+use super::PromptSkill;
+use crate::skill::rules::ReferencesExistRule;
+use crate::skill::Manifest;
+use serde::Serialize;
+use std::path::Path;
+
+/// Default cap on the size of each embedded body or file, in bytes.
+pub const DEFAULT_MAX_BYTES: usize = 32 * 1024;
+
+/// How much content to embed.
+#[derive(Debug, Clone, Copy)]
+pub struct InlineOptions {
+    /// Also embed files referenced under `references/`.
+    pub references: bool,
+    /// Truncate each body or file to this many bytes.
+    pub max_bytes: usize,
+}
+
+/// A referenced file embedded in a prompt.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InlineFile {
+    /// Path relative to the skill directory.
+    pub path: String,
+    /// File contents, possibly truncated.
+    pub content: String,
+}
+
+/// Embed the body of `manifest` (and its references) into `skill`.
+///
+/// Returns warnings for files that were truncated, skipped as binary or
+/// could not be read.
+pub fn inline(skill: &mut PromptSkill, manifest: &Manifest, options: InlineOptions) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let location = manifest.path.display();
+
+    let (body, truncated) = truncate(manifest.body.trim(), options.max_bytes);
+    if truncated {
+        warnings.push(format!(
+            "{}: instructions truncated to {} bytes",
+            location, options.max_bytes
+        ));
+    }
+    skill.instructions = Some(body);
+
+    if !options.references {
+        return warnings;
+    }
+    let Some(skill_dir) = manifest.path.parent() else {
+        return warnings;
+    };
+
+    for path in ReferencesExistRule::referenced_paths(&manifest.body) {
+        if !path.starts_with("references/") {
+            continue;
+        }
+        match read_text(skill_dir, path) {
+            Ok(text) => {
+                let (content, truncated) = truncate(&text, options.max_bytes);
+                if truncated {
+                    warnings.push(format!(
+                        "{}: {} truncated to {} bytes",
+                        location, path, options.max_bytes
+                    ));
+                }
+                skill.files.push(InlineFile {
+                    path: path.to_string(),
+                    content,
+                });
+            }
+            Err(reason) => warnings.push(format!("{}: skipped {} ({})", location, path, reason)),
+        }
+    }
+
+    warnings
+}
+
+/// Read a text file inside `skill_dir`, refusing binaries and paths outside it.
+fn read_text(skill_dir: &Path, relative: &str) -> Result<String, &'static str> {
+    let root = skill_dir.canonicalize().map_err(|_| "not found")?;
+    let path = skill_dir
+        .join(relative)
+        .canonicalize()
+        .map_err(|_| "not found")?;
+    if !path.starts_with(&root) {
+        return Err("outside the skill directory");
+    }
+    if !path.is_file() {
+        return Err("not a file");
+    }
+
+    let bytes = std::fs::read(&path).map_err(|_| "unreadable")?;
+    if is_binary(&bytes) {
+        return Err("binary file");
+    }
+    String::from_utf8(bytes).map_err(|_| "binary file")
+}
+
+/// Treat content with NUL bytes near the start as binary, like git does.
+fn is_binary(bytes: &[u8]) -> bool {
+    bytes.iter().take(8000).any(|&b| b == 0)
+}
+
+/// Truncate `text` to at most `max_bytes`, preferring a line boundary.
+fn truncate(text: &str, max_bytes: usize) -> (String, bool) {
+    if text.len() <= max_bytes {
+        return (text.to_string(), false);
+    }
+
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let cut = &text[..end];
+    let cut = cut.rfind('\n').map_or(cut, |newline| &cut[..newline]);
+    (format!("{}\n[… truncated]", cut.trim_end()), true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn skill(dir: &Path, body: &str) -> (PromptSkill, Manifest) {
+        let content = format!("---\nname: docs\ndescription: Docs\n---\n\n{}", body);
+        let path = dir.join("SKILL.md");
+        std::fs::write(&path, &content).unwrap();
+        let manifest = Manifest::parse(path).unwrap();
+        (PromptSkill::from(&manifest), manifest)
+    }
+
+    #[test]
+    fn test_inline_body_only() {
+        let temp = TempDir::new().unwrap();
+        let (mut prompt_skill, manifest) =
+            skill(temp.path(), "# Docs\n\nSee `references/api.md`.\n");
+        let options = InlineOptions {
+            references: false,
+            max_bytes: DEFAULT_MAX_BYTES,
+        };
+
+        assert!(inline(&mut prompt_skill, &manifest, options).is_empty());
+        assert_eq!(
+            prompt_skill.instructions.as_deref(),
+            Some("# Docs\n\nSee `references/api.md`.")
+        );
+        assert!(prompt_skill.files.is_empty());
+    }
+
+    #[test]
+    fn test_inline_references_skips_binary_and_missing_files() {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir(temp.path().join("references")).unwrap();
+        std::fs::write(temp.path().join("references/api.md"), "GET /items\n").unwrap();
+        std::fs::write(temp.path().join("references/logo.bin"), [0u8, 1, 2]).unwrap();
+        let (mut prompt_skill, manifest) = skill(
+            temp.path(),
+            "Read `references/api.md`, `references/logo.bin`, `references/missing.md` and `scripts/run.sh`.\n",
+        );
+        let options = InlineOptions {
+            references: true,
+            max_bytes: DEFAULT_MAX_BYTES,
+        };
+
+        let warnings = inline(&mut prompt_skill, &manifest, options);
+        assert_eq!(
+            prompt_skill.files,
+            vec![InlineFile {
+                path: "references/api.md".to_string(),
+                content: "GET /items\n".to_string(),
+            }]
+        );
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("binary file"));
+        assert!(warnings[1].contains("not found"));
+    }
+
+    #[test]
+    fn test_truncate_at_line_boundary() {
+        assert_eq!(truncate("short", 10), ("short".to_string(), false));
+        assert_eq!(
+            truncate("line one\nline two\n", 12),
+            ("line one\n[… truncated]".to_string(), true)
+        );
+        assert!(truncate("ééé", 3).0.starts_with('é'));
+    }
+}
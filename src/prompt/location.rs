@@ -0,0 +1,160 @@
+//! Rewriting skill locations for the agent that reads the prompt.
+//!
+//! Paths discovered on the machine generating the prompt rarely match what
+//! the agent sees: absolute paths leak the build machine's layout and
+//! relative paths depend on the agent's working directory.
+
+use crate::agent::Agent;
+use std::path::{Component, Path, PathBuf};
+
+/// How to write the `location` of each skill.
+#[derive(Debug, Clone)]
+pub enum Location {
+    /// The path as discovered from the command-line arguments.
+    Verbatim,
+    /// The canonical absolute path.
+    Absolute,
+    /// A path relative to the given directory.
+    Relative(PathBuf),
+    /// Where the agent installs the skill.
+    Agent {
+        /// The agent reading the prompt.
+        agent: Agent,
+        /// Use the agent's global skills directory instead of the project one.
+        global: bool,
+        /// Directory the project skills directory is resolved against.
+        base_dir: Option<PathBuf>,
+    },
+}
+
+impl Location {
+    /// Location of the skill named `name` whose SKILL.md is at `manifest_path`.
+    pub fn resolve(&self, manifest_path: &Path, name: &str) -> String {
+        let path = match self {
+            Self::Verbatim => manifest_path.to_path_buf(),
+            Self::Absolute => absolute(manifest_path),
+            Self::Relative(base) => {
+                relative_to(&absolute(manifest_path), &absolute(base)).unwrap_or_else(|| {
+                    // Different roots (e.g. Windows drives) have no relative path
+                    absolute(manifest_path)
+                })
+            }
+            Self::Agent {
+                agent,
+                global,
+                base_dir,
+            } => {
+                let skills_dir = if *global {
+                    agent.global_skills_dir()
+                } else {
+                    agent.skills_dir()
+                };
+                let path = Path::new(skills_dir).join(name).join("SKILL.md");
+                match base_dir {
+                    Some(base) if !*global => base.join(path),
+                    _ => path,
+                }
+            }
+        };
+
+        path.to_string_lossy().replace('\\', "/")
+    }
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| {
+        std::env::current_dir()
+            .map(|cwd| cwd.join(path))
+            .unwrap_or_else(|_| path.to_path_buf())
+    })
+}
+
+/// Express absolute `path` relative to absolute `base`.
+fn relative_to(path: &Path, base: &Path) -> Option<PathBuf> {
+    let path: Vec<Component> = path.components().collect();
+    let base: Vec<Component> = base.components().collect();
+    if path.first() != base.first() {
+        return None;
+    }
+
+    let common = path.iter().zip(&base).take_while(|(a, b)| a == b).count();
+    let mut relative = PathBuf::new();
+    for _ in common..base.len() {
+        relative.push("..");
+    }
+    for component in &path[common..] {
+        relative.push(component);
+    }
+    Some(relative)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_to() {
+        let relative = |path: &str, base: &str| {
+            relative_to(Path::new(path), Path::new(base))
+                .unwrap()
+                .to_string_lossy()
+                .into_owned()
+        };
+
+        assert_eq!(
+            relative("/repo/skills/a/SKILL.md", "/repo"),
+            "skills/a/SKILL.md"
+        );
+        assert_eq!(
+            relative("/repo/skills/a/SKILL.md", "/repo/docs"),
+            "../skills/a/SKILL.md"
+        );
+        assert_eq!(relative("/repo/SKILL.md", "/repo"), "SKILL.md");
+    }
+
+    #[test]
+    fn test_agent_locations() {
+        let manifest = Path::new("/ci/build/skills/pdf-tools/SKILL.md");
+
+        let project = Location::Agent {
+            agent: Agent::Claude,
+            global: false,
+            base_dir: None,
+        };
+        assert_eq!(
+            project.resolve(manifest, "pdf-tools"),
+            ".claude/skills/pdf-tools/SKILL.md"
+        );
+
+        let global = Location::Agent {
+            agent: Agent::Codex,
+            global: true,
+            base_dir: Some(PathBuf::from("/workspace")),
+        };
+        assert_eq!(
+            global.resolve(manifest, "pdf-tools"),
+            "~/.codex/skills/pdf-tools/SKILL.md"
+        );
+
+        let based = Location::Agent {
+            agent: Agent::Claude,
+            global: false,
+            base_dir: Some(PathBuf::from("/workspace")),
+        };
+        assert_eq!(
+            based.resolve(manifest, "pdf-tools"),
+            "/workspace/.claude/skills/pdf-tools/SKILL.md"
+        );
+    }
+
+    #[test]
+    fn test_relative_location_from_base_dir() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill = temp.path().join("skills/a");
+        std::fs::create_dir_all(&skill).unwrap();
+        std::fs::write(skill.join("SKILL.md"), "").unwrap();
+
+        let location = Location::Relative(temp.path().join("skills"));
+        assert_eq!(location.resolve(&skill.join("SKILL.md"), "a"), "a/SKILL.md");
+    }
+}
@@ -0,0 +1,266 @@
+//! Rendering of skill listings for agent prompts.
+//!
+//! Skills can be rendered in several built-in shapes ([`PromptFormat`]) or
+//! through a user-supplied [`template`], optionally with their instructions
+//! and references embedded ([`inline`]).
+
+pub mod budget;
+pub mod inline;
+pub mod location;
+pub mod template;
+
+use crate::cli::PromptFormat;
+use crate::error::SkiloError;
+use crate::skill::Manifest;
+use inline::InlineFile;
+use quick_xml::escape::partial_escape;
+use quick_xml::events::BytesText;
+use quick_xml::Writer;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::SystemTime;
+
+/// A skill as it appears in a generated prompt.
+#[derive(Debug, Clone, Serialize)]
+pub struct PromptSkill {
+    /// Skill name.
+    pub name: String,
+    /// Skill description.
+    pub description: String,
+    /// Path to the SKILL.md file.
+    pub location: String,
+    /// License, if declared.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    /// Frontmatter metadata, ordered by key.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
+    /// Skill instructions (the SKILL.md body), when inlined.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// Referenced files embedded in the prompt.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<InlineFile>,
+    /// When the SKILL.md was last modified.
+    #[serde(skip)]
+    pub modified: Option<SystemTime>,
+}
+
+impl From<&Manifest> for PromptSkill {
+    fn from(manifest: &Manifest) -> Self {
+        let frontmatter = &manifest.frontmatter;
+        Self {
+            name: frontmatter.name.clone(),
+            description: frontmatter.description.clone(),
+            location: manifest.path.display().to_string(),
+            license: frontmatter.license.clone(),
+            metadata: frontmatter
+                .metadata
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .collect(),
+            instructions: None,
+            files: Vec::new(),
+            modified: std::fs::metadata(&manifest.path)
+                .and_then(|m| m.modified())
+                .ok(),
+        }
+    }
+}
+
+/// Render skills in one of the built-in formats.
+pub fn render(skills: &[PromptSkill], format: PromptFormat) -> Result<String, SkiloError> {
+    match format {
+        PromptFormat::Xml => render_xml(skills),
+        PromptFormat::Json => serde_json::to_string_pretty(skills)
+            .map_err(|e| SkiloError::Config(format!("JSON serialization failed: {}", e))),
+        PromptFormat::Yaml => serde_yaml::to_string(skills)
+            .map(|yaml| yaml.trim_end().to_string())
+            .map_err(|e| SkiloError::Config(format!("YAML serialization failed: {}", e))),
+        PromptFormat::Markdown => Ok(render_markdown(skills)),
+        PromptFormat::Plain => Ok(render_plain(skills)),
+    }
+}
+
+fn render_xml(skills: &[PromptSkill]) -> Result<String, SkiloError> {
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+    writer
+        .create_element("available_skills")
+        .write_inner_content(|w| {
+            for skill in skills {
+                w.create_element("skill").write_inner_content(|w| {
+                    w.create_element("name")
+                        .write_text_content(text(&skill.name))?;
+                    w.create_element("description")
+                        .write_text_content(text(&skill.description))?;
+                    w.create_element("location")
+                        .write_text_content(text(&skill.location))?;
+                    if let Some(instructions) = &skill.instructions {
+                        w.create_element("instructions")
+                            .write_text_content(cdata(instructions))?;
+                    }
+                    for file in &skill.files {
+                        w.create_element("file")
+                            .with_attribute(("path", file.path.as_str()))
+                            .write_text_content(cdata(&file.content))?;
+                    }
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        })
+        .map_err(|e| SkiloError::Config(format!("XML serialization failed: {}", e)))?;
+
+    String::from_utf8(writer.into_inner())
+        .map_err(|e| SkiloError::Config(format!("XML serialization failed: {}", e)))
+}
+
+/// Escape `text` for element content, leaving quotes readable.
+fn text(text: &str) -> BytesText<'_> {
+    BytesText::from_escaped(partial_escape(text))
+}
+
+/// Wrap `text` in a CDATA section, splitting it around any `]]>`.
+fn cdata(text: &str) -> BytesText<'static> {
+    let escaped = text.replace("]]>", "]]]]><![CDATA[>");
+    BytesText::from_escaped(format!("<![CDATA[{}]]>", escaped))
+}
+
+fn render_markdown(skills: &[PromptSkill]) -> String {
+    let mut out = String::from("## Available skills\n");
+    if skills.iter().all(|s| s.instructions.is_none()) {
+        for skill in skills {
+            out.push_str(&format!(
+                "\n- **{}**: {} (`{}`)",
+                skill.name, skill.description, skill.location
+            ));
+        }
+        return out;
+    }
+
+    // With inlined content, give every skill its own section
+    for skill in skills {
+        out.push_str(&format!(
+            "\n### {}\n\n{}\n\nLocation: `{}`\n",
+            skill.name, skill.description, skill.location
+        ));
+        if let Some(instructions) = &skill.instructions {
+            out.push_str(&format!("\n{}\n", instructions));
+        }
+        for file in &skill.files {
+            out.push_str(&format!(
+                "\n#### {}\n\n{}\n",
+                file.path,
+                fenced(&file.content)
+            ));
+        }
+    }
+    out.trim_end().to_string()
+}
+
+/// Wrap `text` in a code fence longer than any backtick run inside it.
+fn fenced(text: &str) -> String {
+    let longest = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest.max(2) + 1);
+    format!("{}\n{}\n{}", fence, text.trim_end(), fence)
+}
+
+fn render_plain(skills: &[PromptSkill]) -> String {
+    skills
+        .iter()
+        .map(|skill| {
+            let mut entry = format!(
+                "{}: {}\n  {}",
+                skill.name, skill.description, skill.location
+            );
+            if let Some(instructions) = &skill.instructions {
+                entry.push_str(&format!("\n\n{}\n", instructions));
+            }
+            for file in &skill.files {
+                entry.push_str(&format!(
+                    "\n==> {} <==\n{}\n",
+                    file.path,
+                    file.content.trim_end()
+                ));
+            }
+            entry
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn skill() -> PromptSkill {
+        PromptSkill {
+            name: "pdf-tools".to_string(),
+            description: "Work with PDFs".to_string(),
+            location: "skills/pdf-tools/SKILL.md".to_string(),
+            license: Some("MIT".to_string()),
+            metadata: BTreeMap::from([("author".to_string(), "me".to_string())]),
+            instructions: None,
+            files: Vec::new(),
+            modified: None,
+        }
+    }
+
+    fn inlined() -> PromptSkill {
+        PromptSkill {
+            instructions: Some("Use `pdftotext` ]]> carefully.".to_string()),
+            files: vec![InlineFile {
+                path: "references/api.md".to_string(),
+                content: "```sh\npdftotext in.pdf\n```\n".to_string(),
+            }],
+            ..skill()
+        }
+    }
+
+    #[test]
+    fn test_render_xml_keeps_available_skills_shape() {
+        let xml = render(&[skill()], PromptFormat::Xml).unwrap();
+        assert!(xml.starts_with("<available_skills>"));
+        assert!(xml.contains("<name>pdf-tools</name>"));
+        assert!(xml.contains("<location>skills/pdf-tools/SKILL.md</location>"));
+        assert!(!xml.contains("MIT"));
+    }
+
+    #[test]
+    fn test_render_json_includes_license_and_metadata() {
+        let json = render(&[skill()], PromptFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["name"], "pdf-tools");
+        assert_eq!(value[0]["license"], "MIT");
+        assert_eq!(value[0]["metadata"]["author"], "me");
+    }
+
+    #[test]
+    fn test_render_markdown_and_plain() {
+        assert_eq!(
+            render(&[skill()], PromptFormat::Markdown).unwrap(),
+            "## Available skills\n\n- **pdf-tools**: Work with PDFs (`skills/pdf-tools/SKILL.md`)"
+        );
+        assert_eq!(
+            render(&[skill()], PromptFormat::Plain).unwrap(),
+            "pdf-tools: Work with PDFs\n  skills/pdf-tools/SKILL.md"
+        );
+    }
+
+    #[test]
+    fn test_render_xml_inline_uses_cdata() {
+        let xml = render(&[inlined()], PromptFormat::Xml).unwrap();
+        assert!(xml.contains(
+            "<instructions><![CDATA[Use `pdftotext` ]]]]><![CDATA[> carefully.]]></instructions>"
+        ));
+        assert!(xml.contains("<file path=\"references/api.md\"><![CDATA[```sh"));
+    }
+
+    #[test]
+    fn test_render_markdown_inline_sections() {
+        let markdown = render(&[inlined()], PromptFormat::Markdown).unwrap();
+        assert!(markdown.contains("### pdf-tools\n\nWork with PDFs\n"));
+        assert!(markdown.contains("#### references/api.md\n\n````\n```sh"));
+    }
+}
@@ -0,0 +1,226 @@
+//! User-supplied prompt templates.
+//!
+//! Templates use a small Mustache-like syntax:
+//!
+//! - `{{name}}`, `{{description}}`, `{{location}}`, `{{license}}`: skill fields
+//! - `{{metadata.<key>}}`: a single metadata value
+//! - `{{metadata}}`: all metadata as `key=value` pairs separated by commas
+//! - `{{instructions}}`: the SKILL.md body (with `to-prompt --inline`)
+//! - `{{files}}`: inlined reference files, each under a `==> path <==` header
+//! - `{{count}}`: the number of skills
+//! - `{{#skills}}…{{/skills}}`: repeat the enclosed text for every skill
+//! - `{{#field}}…{{/field}}`: render the enclosed text only when `field` is non-empty
+//!
+//! A template without a `{{#skills}}` section is rendered once per skill.
+//! Unknown variables render as empty strings.
+
+use super::PromptSkill;
+use crate::error::SkiloError;
+
+/// A parsed template fragment.
+#[derive(Debug, PartialEq)]
+enum Node {
+    Text(String),
+    Var(String),
+    Section(String, Vec<Node>),
+}
+
+/// A parsed prompt template.
+#[derive(Debug)]
+pub struct Template {
+    nodes: Vec<Node>,
+}
+
+impl Template {
+    /// Parse a template, checking that sections are balanced.
+    pub fn parse(source: &str) -> Result<Self, SkiloError> {
+        let mut stack: Vec<(String, Vec<Node>)> = vec![(String::new(), Vec::new())];
+        let mut rest = source;
+
+        while let Some(start) = rest.find("{{") {
+            push_text(&mut stack, &rest[..start]);
+            let after = &rest[start + 2..];
+            let end = after
+                .find("}}")
+                .ok_or_else(|| invalid("tag is missing its closing '}}'".to_string()))?;
+            let tag = after[..end].trim();
+            rest = &after[end + 2..];
+
+            if let Some(name) = tag.strip_prefix('#') {
+                stack.push((name.trim().to_string(), Vec::new()));
+            } else if let Some(name) = tag.strip_prefix('/') {
+                let name = name.trim();
+                let (open, nodes) = stack.pop().filter(|_| !stack.is_empty()).ok_or_else(|| {
+                    invalid(format!("section '{}' is closed but never opened", name))
+                })?;
+                if open != name {
+                    return Err(invalid(format!(
+                        "section '{}' is closed while '{}' is open",
+                        name, open
+                    )));
+                }
+                current(&mut stack).push(Node::Section(open, nodes));
+            } else {
+                current(&mut stack).push(Node::Var(tag.to_string()));
+            }
+        }
+        push_text(&mut stack, rest);
+
+        if stack.len() > 1 {
+            let (open, _) = stack.pop().unwrap_or_default();
+            return Err(invalid(format!("section '{}' is never closed", open)));
+        }
+        let (_, nodes) = stack.pop().unwrap_or_default();
+        Ok(Self { nodes })
+    }
+
+    /// Render the template for a list of skills.
+    pub fn render(&self, skills: &[PromptSkill]) -> String {
+        let mut out = String::new();
+        if contains_skills_section(&self.nodes) {
+            render_nodes(&self.nodes, skills, None, &mut out);
+        } else {
+            for skill in skills {
+                render_nodes(&self.nodes, skills, Some(skill), &mut out);
+            }
+        }
+        out
+    }
+}
+
+fn invalid(message: String) -> SkiloError {
+    SkiloError::Template(message)
+}
+
+fn current(stack: &mut [(String, Vec<Node>)]) -> &mut Vec<Node> {
+    // The root frame is never popped while parsing
+    &mut stack.last_mut().expect("template stack is never empty").1
+}
+
+fn push_text(stack: &mut [(String, Vec<Node>)], text: &str) {
+    if !text.is_empty() {
+        current(stack).push(Node::Text(text.to_string()));
+    }
+}
+
+fn contains_skills_section(nodes: &[Node]) -> bool {
+    nodes.iter().any(|node| match node {
+        Node::Section(name, children) => name == "skills" || contains_skills_section(children),
+        _ => false,
+    })
+}
+
+fn render_nodes(
+    nodes: &[Node],
+    skills: &[PromptSkill],
+    skill: Option<&PromptSkill>,
+    out: &mut String,
+) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Var(name) => out.push_str(&lookup(name, skills, skill)),
+            Node::Section(name, children) if name == "skills" => {
+                for skill in skills {
+                    render_nodes(children, skills, Some(skill), out);
+                }
+            }
+            Node::Section(name, children) => {
+                if !lookup(name, skills, skill).is_empty() {
+                    render_nodes(children, skills, skill, out);
+                }
+            }
+        }
+    }
+}
+
+fn lookup(name: &str, skills: &[PromptSkill], skill: Option<&PromptSkill>) -> String {
+    if name == "count" {
+        return skills.len().to_string();
+    }
+    let Some(skill) = skill else {
+        return String::new();
+    };
+
+    match name {
+        "name" => skill.name.clone(),
+        "description" => skill.description.clone(),
+        "location" => skill.location.clone(),
+        "license" => skill.license.clone().unwrap_or_default(),
+        "instructions" => skill.instructions.clone().unwrap_or_default(),
+        "files" => skill
+            .files
+            .iter()
+            .map(|f| format!("==> {} <==\n{}", f.path, f.content.trim_end()))
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+        "metadata" => skill
+            .metadata
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => name
+            .strip_prefix("metadata.")
+            .and_then(|key| skill.metadata.get(key))
+            .cloned()
+            .unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn skills() -> Vec<PromptSkill> {
+        vec![
+            PromptSkill {
+                name: "a".to_string(),
+                description: "First".to_string(),
+                location: "a/SKILL.md".to_string(),
+                license: Some("MIT".to_string()),
+                metadata: BTreeMap::from([("owner".to_string(), "docs".to_string())]),
+                instructions: None,
+                files: Vec::new(),
+                modified: None,
+            },
+            PromptSkill {
+                name: "b".to_string(),
+                description: "Second".to_string(),
+                location: "b/SKILL.md".to_string(),
+                license: None,
+                metadata: BTreeMap::new(),
+                instructions: None,
+                files: Vec::new(),
+                modified: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_template_without_section_renders_per_skill() {
+        let template = Template::parse("- {{name}}: {{description}}\n").unwrap();
+        assert_eq!(template.render(&skills()), "- a: First\n- b: Second\n");
+    }
+
+    #[test]
+    fn test_skills_section_and_conditionals() {
+        let template = Template::parse(
+            "{{count}} skills\n{{#skills}}[{{name}}]({{location}}){{#license}} ({{license}}){{/license}} {{metadata.owner}}\n{{/skills}}",
+        )
+        .unwrap();
+        assert_eq!(
+            template.render(&skills()),
+            "2 skills\n[a](a/SKILL.md) (MIT) docs\n[b](b/SKILL.md) \n"
+        );
+    }
+
+    #[test]
+    fn test_unbalanced_sections_are_rejected() {
+        assert!(Template::parse("{{#skills}}{{name}}").is_err());
+        assert!(Template::parse("{{name}}{{/skills}}").is_err());
+        assert!(Template::parse("{{#skills}}{{/license}}").is_err());
+        assert!(Template::parse("{{name").is_err());
+    }
+}
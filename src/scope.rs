@@ -62,6 +62,24 @@ pub struct InstalledSkill {
     pub scope: Scope,
 }
 
+/// Outcome of removing installed skills.
+#[derive(Debug, Default)]
+pub struct RemovalReport {
+    /// Skills that were removed (name, path).
+    pub removed: Vec<(String, PathBuf)>,
+    /// Skills that could not be removed (name, path, error message).
+    pub failed: Vec<(String, PathBuf, String)>,
+    /// Requested names that are not installed.
+    pub not_found: Vec<String>,
+}
+
+impl RemovalReport {
+    /// Returns true if at least one skill was removed and none failed.
+    pub fn is_ok(&self) -> bool {
+        !self.removed.is_empty() && self.failed.is_empty()
+    }
+}
+
 /// List installed skills at a given scope.
 pub fn list_skills(agent: Agent, scope: Scope, project_root: &Path) -> Vec<InstalledSkill> {
     let Some(skills_dir) = scope.resolve_skills_dir(agent, project_root) else {
@@ -1,7 +1,7 @@
 //! Skill discovery utilities.
 
 use crate::skill::manifest::{Manifest, ManifestError};
-use globset::{Glob, GlobSetBuilder};
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
@@ -29,16 +29,7 @@ impl Discovery {
             return vec![skill_md];
         }
 
-        // Build a GlobSet from ignore patterns
-        let mut builder = GlobSetBuilder::new();
-        for pattern in ignore_patterns {
-            if let Ok(glob) = Glob::new(pattern) {
-                builder.add(glob);
-            }
-        }
-        let globset = builder
-            .build()
-            .unwrap_or_else(|_| GlobSetBuilder::new().build().unwrap());
+        let globset = ignore_set(ignore_patterns);
 
         // Otherwise, search recursively, skipping ignored directories
         WalkDir::new(root)
@@ -51,26 +42,7 @@ impl Discovery {
                 }
 
                 // Skip ignored directories using glob matching against relative path
-                if e.file_type().is_dir() {
-                    // Get relative path from root for matching
-                    if let Ok(rel_path) = e.path().strip_prefix(root) {
-                        // Match against both the relative path and just the directory name
-                        // This supports both "target" and "foo/bar" style patterns
-                        let path_str = rel_path.to_string_lossy();
-                        if globset.is_match(path_str.as_ref()) {
-                            return false;
-                        }
-
-                        // Also check just the directory name for simple patterns
-                        if let Some(name) = e.file_name().to_str() {
-                            if globset.is_match(name) {
-                                return false;
-                            }
-                        }
-                    }
-                }
-
-                true
+                !(e.file_type().is_dir() && is_ignored_dir(&globset, root, e.path()))
             })
             .filter_map(|e| e.ok())
             .filter(|e| e.file_name() == "SKILL.md")
@@ -78,6 +50,25 @@ impl Discovery {
             .collect()
     }
 
+    /// Check whether `path` lies inside a directory skipped by `ignore_patterns`.
+    ///
+    /// Uses the same matching rules as [`Discovery::find_skills`], applied to
+    /// every directory between `root` and `path`.
+    pub fn is_ignored(root: &Path, path: &Path, ignore_patterns: &[String]) -> bool {
+        let globset = ignore_set(ignore_patterns);
+        let Ok(rel_path) = path.strip_prefix(root) else {
+            return false;
+        };
+
+        rel_path
+            .ancestors()
+            .filter(|a| !a.as_os_str().is_empty())
+            .any(|a| {
+                let dir = root.join(a);
+                (dir != path || path.is_dir()) && is_ignored_dir(&globset, root, &dir)
+            })
+    }
+
     /// Load all skills from a list of paths.
     pub fn load_skills(paths: &[PathBuf]) -> Vec<Result<Manifest, (PathBuf, ManifestError)>> {
         paths
@@ -96,6 +87,39 @@ impl Discovery {
     }
 }
 
+/// Build a GlobSet from ignore patterns, skipping invalid ones.
+fn ignore_set(ignore_patterns: &[String]) -> GlobSet {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in ignore_patterns {
+        if let Ok(glob) = Glob::new(pattern) {
+            builder.add(glob);
+        }
+    }
+    builder
+        .build()
+        .unwrap_or_else(|_| GlobSetBuilder::new().build().unwrap())
+}
+
+/// Check whether the directory `dir` under `root` matches an ignore pattern.
+fn is_ignored_dir(globset: &GlobSet, root: &Path, dir: &Path) -> bool {
+    // Get relative path from root for matching
+    let Ok(rel_path) = dir.strip_prefix(root) else {
+        return false;
+    };
+
+    // Match against both the relative path and just the directory name
+    // This supports both "target" and "foo/bar" style patterns
+    let path_str = rel_path.to_string_lossy();
+    if globset.is_match(path_str.as_ref()) {
+        return true;
+    }
+
+    // Also check just the directory name for simple patterns
+    dir.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|name| globset.is_match(name))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -336,4 +360,31 @@ mod tests {
         assert_eq!(skills.len(), 1);
         assert!(skills[0].to_string_lossy().contains("root-skill"));
     }
+
+    #[test]
+    fn test_is_ignored() {
+        let root = Path::new("/repo");
+        let ignore = ["target".to_string(), "build-*".to_string()];
+
+        assert!(Discovery::is_ignored(
+            root,
+            Path::new("/repo/target/skill/SKILL.md"),
+            &ignore
+        ));
+        assert!(Discovery::is_ignored(
+            root,
+            Path::new("/repo/a/build-x/SKILL.md"),
+            &ignore
+        ));
+        assert!(!Discovery::is_ignored(
+            root,
+            Path::new("/repo/my-skill/SKILL.md"),
+            &ignore
+        ));
+        assert!(!Discovery::is_ignored(
+            root,
+            Path::new("/elsewhere/target/SKILL.md"),
+            &ignore
+        ));
+    }
 }
@@ -1,7 +1,7 @@
 //! Skill frontmatter types.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// YAML frontmatter from a SKILL.md file.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,7 +22,7 @@ pub struct Frontmatter {
 
     /// Additional metadata key-value pairs.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<HashMap<String, String>>,
+    pub metadata: Option<BTreeMap<String, String>>,
 
     /// Pre-approved tools (space-delimited).
     #[serde(rename = "allowed-tools", skip_serializing_if = "Option::is_none")]
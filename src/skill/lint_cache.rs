@@ -0,0 +1,286 @@
+//! On-disk cache of lint results for unchanged skills.
+//!
+//! Each skill is fingerprinted by its SKILL.md content, the content and
+//! permissions of the other files in its directory, the lint configuration
+//! and the skilo version. A matching fingerprint means the rules would
+//! produce the same diagnostics, so validation can be skipped.
+
+use crate::cache::skilo_home;
+use crate::config::LintConfig;
+use crate::skill::validator::{Diagnostic, ValidationResult};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Directory of lint caches within the skilo home directory.
+const CACHE_DIR: &str = "lint";
+
+#[derive(Default, Serialize, Deserialize)]
+struct CacheFile {
+    entries: HashMap<String, CacheEntry>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    key: String,
+    errors: Vec<Diagnostic>,
+    warnings: Vec<Diagnostic>,
+}
+
+/// Lint results cached by skill fingerprint.
+pub struct LintCache {
+    path: PathBuf,
+    config_hash: String,
+    file: CacheFile,
+    dirty: bool,
+}
+
+impl LintCache {
+    /// Path of the cache for skills linted under `root` (`~/.skilo/lint/<hash>.json`).
+    ///
+    /// Each linted root has its own file, so linting another directory never
+    /// writes into the current one.
+    pub fn path_for(root: &Path) -> Option<PathBuf> {
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        let id = hex_digest(root.to_string_lossy().as_bytes());
+        skilo_home().map(|home| home.join(CACHE_DIR).join(format!("{}.json", &id[..16])))
+    }
+
+    /// Load the cache file at `path`, starting empty if it is missing or unreadable.
+    pub fn load(path: &Path, config: &LintConfig) -> Self {
+        let file = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+
+        Self {
+            path: path.to_path_buf(),
+            // Any change to rule settings invalidates every entry
+            config_hash: hex_digest(
+                format!("{:?}{:?}", config.rules, config.target_agents).as_bytes(),
+            ),
+            file,
+            dirty: false,
+        }
+    }
+
+    /// Compute the fingerprint of the skill at `manifest_path`.
+    ///
+    /// Returns `None` if the skill cannot be read, in which case it should
+    /// be validated without caching.
+    pub fn fingerprint(&self, manifest_path: &Path) -> Option<String> {
+        let mut hasher = Sha256::new();
+        hasher.update(env!("CARGO_PKG_VERSION"));
+        hasher.update([0]);
+        hasher.update(&self.config_hash);
+        hasher.update([0]);
+        hasher.update(manifest_path.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update(std::fs::read(manifest_path).ok()?);
+
+        let skill_dir = manifest_path.parent()?;
+        let mut files: Vec<PathBuf> = WalkDir::new(skill_dir)
+            .min_depth(1)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file() && e.path() != manifest_path)
+            .map(|e| e.into_path())
+            .collect();
+        files.sort();
+
+        for file in files {
+            let relative = file.strip_prefix(skill_dir).unwrap_or(&file);
+            hasher.update([0]);
+            hasher.update(relative.to_string_lossy().as_bytes());
+            hasher.update([0]);
+            hasher.update(permission_bits(&file).to_le_bytes());
+            hasher.update(std::fs::read(&file).ok()?);
+        }
+
+        Some(format!("{:x}", hasher.finalize()))
+    }
+
+    /// Get the cached result for a skill if its fingerprint still matches.
+    pub fn get(&self, manifest_path: &Path, fingerprint: &str) -> Option<ValidationResult> {
+        let entry = self.file.entries.get(&cache_id(manifest_path))?;
+        if entry.key != fingerprint {
+            return None;
+        }
+
+        Some(ValidationResult {
+            errors: entry.errors.clone(),
+            warnings: entry.warnings.clone(),
+        })
+    }
+
+    /// Store the result for a skill, replacing any previous entry.
+    pub fn insert(&mut self, manifest_path: &Path, fingerprint: String, result: &ValidationResult) {
+        self.file.entries.insert(
+            cache_id(manifest_path),
+            CacheEntry {
+                key: fingerprint,
+                errors: result.errors.clone(),
+                warnings: result.warnings.clone(),
+            },
+        );
+        self.dirty = true;
+    }
+
+    /// Drop the entries of skills other than `manifest_paths`, such as
+    /// skills that were deleted since the last run.
+    pub fn retain(&mut self, manifest_paths: &[PathBuf]) {
+        let present: HashSet<String> = manifest_paths.iter().map(|p| cache_id(p)).collect();
+        let before = self.file.entries.len();
+        self.file.entries.retain(|id, _| present.contains(id));
+        self.dirty |= self.file.entries.len() != before;
+    }
+
+    /// Write the cache back to disk if it changed.
+    pub fn save(&self) -> io::Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let content = serde_json::to_string(&self.file).map_err(io::Error::other)?;
+        std::fs::write(&self.path, content)
+    }
+}
+
+/// Cache entries are keyed by the manifest path as given on the command line.
+fn cache_id(manifest_path: &Path) -> String {
+    manifest_path.display().to_string()
+}
+
+fn hex_digest(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+#[cfg(unix)]
+fn permission_bits(path: &Path) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata().map(|m| m.permissions().mode()).unwrap_or(0)
+}
+
+#[cfg(not(unix))]
+fn permission_bits(_path: &Path) -> u32 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skill::{DiagnosticCode, Manifest, Validator};
+    use tempfile::TempDir;
+
+    fn write_skill(root: &Path, description: &str) -> PathBuf {
+        let dir = root.join("my-skill");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("SKILL.md");
+        std::fs::write(
+            &path,
+            format!(
+                "---\nname: my-skill\ndescription: {}\n---\n\n# My Skill\n",
+                description
+            ),
+        )
+        .unwrap();
+        path
+    }
+
+    fn validate(path: &Path) -> ValidationResult {
+        Validator::default().validate(&Manifest::parse(path.to_path_buf()).unwrap())
+    }
+
+    #[test]
+    fn test_roundtrip_through_disk() {
+        let temp = TempDir::new().unwrap();
+        let cache_path = temp.path().join("cache").join("lint.json");
+        let path = write_skill(temp.path(), "\"\"");
+        let config = LintConfig::default();
+
+        let mut cache = LintCache::load(&cache_path, &config);
+        let key = cache.fingerprint(&path).unwrap();
+        assert!(cache.get(&path, &key).is_none());
+        cache.insert(&path, key.clone(), &validate(&path));
+        cache.save().unwrap();
+
+        let cache = LintCache::load(&cache_path, &config);
+        let cached = cache.get(&path, &key).unwrap();
+        assert_eq!(cached.errors.len(), 1);
+        assert_eq!(cached.errors[0].code, DiagnosticCode::E004);
+    }
+
+    #[test]
+    fn test_retain_drops_removed_skills() {
+        let temp = TempDir::new().unwrap();
+        let cache_path = temp.path().join("lint.json");
+        let path = write_skill(temp.path(), "A skill");
+        let config = LintConfig::default();
+
+        let mut cache = LintCache::load(&cache_path, &config);
+        let key = cache.fingerprint(&path).unwrap();
+        cache.insert(&path, key.clone(), &validate(&path));
+        cache.retain(std::slice::from_ref(&path));
+        cache.save().unwrap();
+        assert!(LintCache::load(&cache_path, &config)
+            .get(&path, &key)
+            .is_some());
+
+        let mut cache = LintCache::load(&cache_path, &config);
+        cache.retain(&[]);
+        cache.save().unwrap();
+        assert!(LintCache::load(&cache_path, &config)
+            .get(&path, &key)
+            .is_none());
+    }
+
+    #[test]
+    fn test_path_is_per_root() {
+        let temp = TempDir::new().unwrap();
+        let a = LintCache::path_for(&temp.path().join("a"));
+        let b = LintCache::path_for(&temp.path().join("b"));
+        assert_ne!(a, b);
+        assert!(a.is_some_and(|p| !p.starts_with(temp.path())));
+    }
+
+    #[test]
+    fn test_fingerprint_changes_with_content() {
+        let temp = TempDir::new().unwrap();
+        let path = write_skill(temp.path(), "First");
+        let cache = LintCache::load(&temp.path().join("lint.json"), &LintConfig::default());
+
+        let before = cache.fingerprint(&path).unwrap();
+        assert_eq!(before, cache.fingerprint(&path).unwrap());
+
+        std::fs::write(path.parent().unwrap().join("notes.txt"), "extra").unwrap();
+        let with_file = cache.fingerprint(&path).unwrap();
+        assert_ne!(before, with_file);
+
+        write_skill(temp.path(), "Second");
+        assert_ne!(with_file, cache.fingerprint(&path).unwrap());
+    }
+
+    #[test]
+    fn test_fingerprint_changes_with_config() {
+        let temp = TempDir::new().unwrap();
+        let path = write_skill(temp.path(), "A skill");
+        let cache_path = temp.path().join("lint.json");
+
+        let default = LintCache::load(&cache_path, &LintConfig::default());
+        let mut config = LintConfig::default();
+        config.rules.name_format = false;
+        let custom = LintCache::load(&cache_path, &config);
+
+        assert_ne!(
+            default.fingerprint(&path).unwrap(),
+            custom.fingerprint(&path).unwrap()
+        );
+    }
+}
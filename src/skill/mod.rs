@@ -4,17 +4,23 @@
 //! - [`Manifest`] - Parse SKILL.md files
 //! - [`Frontmatter`] - Skill metadata (name, description, etc.)
 //! - [`Discovery`] - Find skills in directories
+//! - [`Requirements`] - Requirements declared in the compatibility field
 //! - [`Validator`] - Validate skills against the specification
 
 pub mod discovery;
 pub mod formatter;
 pub mod frontmatter;
+pub mod lint_cache;
 pub mod manifest;
+pub mod requirements;
 pub mod rules;
 pub mod validator;
 
 pub use discovery::Discovery;
 pub use formatter::{Formatter, FormatterConfig};
 pub use frontmatter::Frontmatter;
+pub use lint_cache::LintCache;
 pub use manifest::Manifest;
+pub use requirements::Requirements;
+pub use rules::{RuleMeta, Severity};
 pub use validator::{Diagnostic, DiagnosticCode, ValidationResult, Validator};
@@ -0,0 +1,247 @@
+//! Structured requirements parsed from the free-text `compatibility` field.
+//!
+//! The field is prose ("Requires git and python 3.10+, works with Claude Code
+//! on macOS or Linux"), so parsing is best effort: agents are recognized by
+//! their display name as written, or by any name in a list after "works
+//! with", "for" or "supports"; operating systems by their common names; and
+//! tools only as single words listed after "requires", "needs" or "uses".
+
+use crate::agent::Agent;
+use serde::Serialize;
+
+/// Words that introduce a list of required tools.
+const TOOL_TRIGGERS: &[&str] = &["requires", "require", "needs", "need", "uses", "using"];
+
+/// Words that introduce a list of agents: "works with", "built for", "supports".
+const AGENT_TRIGGERS: &[&str] = &["with", "for", "supports", "support"];
+
+/// Single words after a trigger that are not tools.
+const NOT_TOOLS: &[&str] = &[
+    "a",
+    "an",
+    "the",
+    "access",
+    "internet",
+    "network",
+    "permission",
+    "permissions",
+    "sudo",
+];
+
+/// Requirements declared by a skill's `compatibility` field.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct Requirements {
+    /// Agents the skill declares it works with.
+    pub agents: Vec<Agent>,
+    /// Operating systems, normalized to `linux`, `macos`, `windows` or `unix`.
+    pub os: Vec<String>,
+    /// Command-line tools the skill needs, without versions.
+    pub tools: Vec<String>,
+}
+
+impl Requirements {
+    /// Parse the requirements mentioned in `text`.
+    pub fn parse(text: &str) -> Self {
+        let lower = text.to_lowercase();
+        let words: Vec<&str> = lower
+            .split(|c: char| !(c.is_alphanumeric() || c == '-'))
+            .filter(|w| !w.is_empty())
+            .collect();
+
+        let mut requirements = Self {
+            agents: agents_in(text),
+            ..Self::default()
+        };
+
+        for word in &words {
+            if let Some(os) = normalize_os(word) {
+                push_unique(&mut requirements.os, os);
+            }
+        }
+
+        for clause in lower.split([';', '\n', '(', ')']) {
+            // "python 3.10+." ends a sentence; "3.10" does not
+            for sentence in clause.split(". ") {
+                for tool in tools_in(sentence) {
+                    let is_agent = requirements.agents.iter().any(|a| a.cli_name() == tool);
+                    if !is_agent && normalize_os(&tool).is_none() {
+                        push_unique(&mut requirements.tools, &tool);
+                    }
+                }
+            }
+        }
+
+        requirements
+    }
+
+    /// Returns true if nothing was recognized.
+    pub fn is_empty(&self) -> bool {
+        self.agents.is_empty() && self.os.is_empty() && self.tools.is_empty()
+    }
+}
+
+/// Agents named in `text`, in [`Agent::all`] order.
+///
+/// Display names count anywhere when written as such ("Claude Code",
+/// "Cursor"); lowercase and CLI names only in a list after a trigger word,
+/// so "moves the cursor" names no agent but "works with cursor" does.
+fn agents_in(text: &str) -> Vec<Agent> {
+    let words: Vec<&str> = text
+        .split(|c: char| !(c.is_alphanumeric() || c == '-'))
+        .filter(|w| !w.is_empty())
+        .collect();
+    let lower: Vec<String> = words.iter().map(|w| w.to_lowercase()).collect();
+    let agents = Agent::all();
+
+    let mut named: Vec<Agent> = agents
+        .iter()
+        .filter(|agent| {
+            let display: Vec<&str> = agent.display_name().split_whitespace().collect();
+            !display.is_empty() && words.windows(display.len()).any(|w| w == display)
+        })
+        .copied()
+        .collect();
+
+    for (i, word) in lower.iter().enumerate() {
+        if !AGENT_TRIGGERS.contains(&word.as_str()) {
+            continue;
+        }
+        let mut rest = &lower[i + 1..];
+        while let Some(first) = rest.first() {
+            if first == "and" || first == "or" {
+                rest = &rest[1..];
+                continue;
+            }
+            let Some((agent, len)) = agents.iter().find_map(|agent| agent_at(*agent, rest)) else {
+                break;
+            };
+            named.push(agent);
+            rest = &rest[len..];
+        }
+    }
+
+    agents.into_iter().filter(|a| named.contains(a)).collect()
+}
+
+/// The number of lowercase `words` naming `agent` at their start, if any.
+fn agent_at(agent: Agent, words: &[String]) -> Option<(Agent, usize)> {
+    let display: Vec<String> = agent
+        .display_name()
+        .to_lowercase()
+        .split_whitespace()
+        .map(str::to_string)
+        .collect();
+    if !display.is_empty() && words.starts_with(&display) {
+        return Some((agent, display.len()));
+    }
+    let first = words.first()?;
+    (first == agent.cli_name() || first == agent.config_name()).then_some((agent, 1))
+}
+
+/// Canonical name of an operating system word.
+fn normalize_os(word: &str) -> Option<&'static str> {
+    match word {
+        "linux" => Some("linux"),
+        "macos" | "mac" | "osx" | "darwin" => Some("macos"),
+        "windows" | "win32" => Some("windows"),
+        "unix" | "posix" => Some("unix"),
+        _ => None,
+    }
+}
+
+/// Tools listed after a trigger word in one sentence.
+fn tools_in(sentence: &str) -> Vec<String> {
+    let mut words = sentence.split_whitespace();
+    if !words.any(|w| TOOL_TRIGGERS.contains(&w)) {
+        return Vec::new();
+    }
+
+    let rest = words.collect::<Vec<_>>().join(" ");
+    rest.split([',', '&', '/'])
+        .flat_map(|item| item.split(" and "))
+        .flat_map(|item| item.split(" or "))
+        .filter_map(|item| {
+            let item = item
+                .trim()
+                .trim_start_matches("and ")
+                .trim_start_matches("or ");
+            let words: Vec<&str> = item.split_whitespace().collect();
+            let tool = match words.as_slice() {
+                [tool] => *tool,
+                // "python 3.10+" or "node v20"
+                [tool, version] if is_version(version) => *tool,
+                _ => return None,
+            };
+            let tool = tool.trim_end_matches(|c: char| !c.is_alphanumeric());
+            let is_command = tool.starts_with(|c: char| c.is_ascii_lowercase())
+                && tool
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '+'));
+            (is_command && !NOT_TOOLS.contains(&tool)).then(|| tool.to_string())
+        })
+        .collect()
+}
+
+/// Returns true for version numbers such as `3.10+`, `>=18` or `v20`.
+fn is_version(word: &str) -> bool {
+    word.trim_start_matches(['>', '=', '^', '~', 'v'])
+        .starts_with(|c: char| c.is_ascii_digit())
+}
+
+fn push_unique(list: &mut Vec<String>, value: &str) {
+    if !list.iter().any(|v| v == value) {
+        list.push(value.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_requirements() {
+        let requirements = Requirements::parse(
+            "Works with Claude Code and Cursor on macOS or Linux. Requires git, jq and python 3.10+; needs network access",
+        );
+        assert_eq!(requirements.agents, vec![Agent::Claude, Agent::Cursor]);
+        assert_eq!(requirements.os, vec!["macos", "linux"]);
+        assert_eq!(requirements.tools, vec!["git", "jq", "python"]);
+    }
+
+    #[test]
+    fn test_agents_named_in_lists_or_by_display_name() {
+        let agents = |text| Requirements::parse(text).agents;
+        assert_eq!(
+            agents("works with claude, codex or gemini"),
+            vec![Agent::Claude, Agent::Codex, Agent::Gemini]
+        );
+        assert_eq!(agents("Built for roo code"), vec![Agent::RooCode]);
+        assert_eq!(
+            agents("Tested in Cursor and Windsurf"),
+            vec![Agent::Cursor, Agent::Windsurf]
+        );
+    }
+
+    #[test]
+    fn test_common_words_are_not_agents() {
+        for text in [
+            "Moves the cursor to the next heading",
+            "Turns the amp up for louder alerts",
+            "Feeds the goose; needs a droid to fly",
+            "Works with cursor-based pagination",
+            "Designed for the codex of internal rules",
+        ] {
+            assert!(Requirements::parse(text).agents.is_empty(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_parse_prose_without_requirements() {
+        let requirements = Requirements::parse("Designed for internal use by the data team");
+        assert!(requirements.is_empty());
+        assert_eq!(
+            Requirements::parse("Requires python3 and network access").tools,
+            vec!["python3"]
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Checks that skills only use features their target agents support.
+
+use crate::agent::Agent;
+use crate::cli::ScriptLang;
+use crate::skill::manifest::Manifest;
+use crate::skill::requirements::Requirements;
+use crate::skill::rules::scripts::script_files;
+use crate::skill::rules::{Rule, RuleExample, RuleMeta, Severity};
+use crate::skill::validator::{Diagnostic, DiagnosticCode};
+use clap::ValueEnum;
+use std::path::Path;
+
+/// W005: Warns about features a target agent does not support.
+pub struct AgentCompatibilityRule {
+    /// Agents every skill should work with.
+    targets: Vec<Agent>,
+}
+
+impl AgentCompatibilityRule {
+    /// Metadata for W005.
+    pub const META: RuleMeta = RuleMeta {
+        code: DiagnosticCode::W005,
+        name: "agent-compatibility",
+        severity: Severity::Warning,
+        summary: "Skill uses a feature a target agent does not support",
+        description: r#"Agents differ in which parts of a skill they understand. When target agents
+are set with `--target-agents` or `lint.target_agents`, each skill is
+checked against every target for:
+
+- `allowed-tools`, which not every agent honors
+- `hooks`, supported by Claude Code only
+- `context: fork`, supported by Claude Code only
+- files in `scripts/`, for agents configured with `scripts = false`
+- scripts in a language the agent cannot run, by extension or shebang, for
+  agents configured with `script_langs`
+- a `compatibility` field that names agents but not the target
+
+Agents are recognized in `compatibility` by their display name, e.g. "Claude
+Code", or by any of their names in a list after "works with", "for" or
+"supports". Operating systems and tools in `compatibility` are not checked;
+`read-properties` reports them. Custom agents declare their features in
+`[[agents]]` config entries.
+"#,
+        config_key: Some("lint.target_agents"),
+        examples: &[
+            RuleExample {
+                title: "Configuration",
+                lang: "toml",
+                code: "[lint]\ntarget_agents = [\"claude\", \"cursor\"]",
+            },
+            RuleExample {
+                title: "Warns when targeting Cursor",
+                lang: "yaml",
+                code: "context: fork\ncompatibility: Works with Claude Code",
+            },
+        ],
+    };
+
+    /// Create a rule checking skills against `targets`.
+    pub fn new(targets: Vec<Agent>) -> Self {
+        Self { targets }
+    }
+}
+
+impl Rule for AgentCompatibilityRule {
+    fn meta(&self) -> &'static RuleMeta {
+        &Self::META
+    }
+
+    fn check(&self, manifest: &Manifest) -> Vec<Diagnostic> {
+        let fields: serde_yaml::Mapping =
+            serde_yaml::from_str(&manifest.frontmatter_raw).unwrap_or_default();
+        let uses_hooks = fields.contains_key("hooks");
+        let forks = fields.get("context").and_then(|v| v.as_str()) == Some("fork");
+        let uses_allowed_tools = manifest.frontmatter.allowed_tools.is_some();
+        let scripts = script_files(manifest);
+        let declared = manifest
+            .frontmatter
+            .compatibility
+            .as_deref()
+            .map(Requirements::parse)
+            .unwrap_or_default();
+
+        let mut diagnostics = Vec::new();
+        let mut report = |path: &Path, line: Option<usize>, message: String, fix_hint| {
+            diagnostics.push(Diagnostic {
+                path: path.display().to_string(),
+                line,
+                column: line.map(|_| 1),
+                message,
+                code: DiagnosticCode::W005,
+                fix_hint,
+            });
+        };
+
+        for agent in &self.targets {
+            let name = agent.display_name();
+            let features = agent.features();
+
+            if !declared.agents.is_empty() && !declared.agents.contains(agent) {
+                let listed: Vec<&str> = declared.agents.iter().map(|a| a.display_name()).collect();
+                report(
+                    &manifest.path,
+                    field_line(manifest, "compatibility"),
+                    format!(
+                        "Compatibility names {} but not target agent {}",
+                        listed.join(", "),
+                        name
+                    ),
+                    Some(format!(
+                        "Mention {} in compatibility if the skill works with it",
+                        name
+                    )),
+                );
+            }
+            if uses_allowed_tools && !features.allowed_tools {
+                report(
+                    &manifest.path,
+                    field_line(manifest, "allowed-tools"),
+                    format!("{} does not support 'allowed-tools'", name),
+                    None,
+                );
+            }
+            if uses_hooks && !features.hooks {
+                report(
+                    &manifest.path,
+                    field_line(manifest, "hooks"),
+                    format!("{} does not support hooks", name),
+                    None,
+                );
+            }
+            if forks && !features.context_fork {
+                report(
+                    &manifest.path,
+                    field_line(manifest, "context"),
+                    format!("{} does not support 'context: fork'", name),
+                    Some("The skill runs in the main conversation instead".into()),
+                );
+            }
+            if !scripts.is_empty() && !features.scripts {
+                report(
+                    &manifest.path,
+                    None,
+                    format!(
+                        "{} does not run scripts, but the skill has {} in scripts/",
+                        name,
+                        scripts.len()
+                    ),
+                    None,
+                );
+            }
+            if let (true, Some(langs)) = (features.scripts, &features.script_langs) {
+                for script in &scripts {
+                    let Some(lang) = unsupported_lang(script, langs) else {
+                        continue;
+                    };
+                    report(
+                        script,
+                        None,
+                        format!("{} does not run {} scripts", name, lang),
+                        None,
+                    );
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// The language of `script` if neither its extension nor its shebang is
+/// one of `langs`; scripts of unknown language are not reported.
+fn unsupported_lang(script: &Path, langs: &[ScriptLang]) -> Option<String> {
+    let by_extension = script
+        .extension()
+        .and_then(|e| e.to_str())
+        .and_then(ScriptLang::from_extension);
+    let interpreter = std::fs::read_to_string(script)
+        .ok()
+        .and_then(|content| ScriptLang::interpreter(content.lines().next().unwrap_or_default()));
+
+    let extension_runs =
+        by_extension.is_some_and(|lang| langs.iter().any(|l| l.extension() == lang.extension()));
+    let shebang_runs = interpreter
+        .as_deref()
+        .is_some_and(|interpreter| langs.iter().any(|l| l.runs_with(interpreter)));
+    if extension_runs || shebang_runs {
+        return None;
+    }
+
+    match (by_extension, interpreter) {
+        (Some(lang), _) => lang.to_possible_value().map(|v| v.get_name().to_string()),
+        (None, interpreter) => interpreter,
+    }
+}
+
+/// Line of a top-level frontmatter key in SKILL.md, after the opening `---`.
+fn field_line(manifest: &Manifest, key: &str) -> Option<usize> {
+    manifest
+        .frontmatter_raw
+        .lines()
+        .position(|line| {
+            line.strip_prefix(key)
+                .is_some_and(|rest| rest.starts_with(':'))
+        })
+        .map(|index| index + 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::{AgentFeatures, CustomAgent};
+    use once_cell::sync::Lazy;
+    use std::path::PathBuf;
+
+    fn manifest(frontmatter: &str) -> Manifest {
+        let content = format!(
+            "---\nname: demo\ndescription: A demo skill.\n{}\n---\n\n# Demo\n",
+            frontmatter
+        );
+        Manifest::parse_content(PathBuf::from("demo/SKILL.md"), &content).unwrap()
+    }
+
+    #[test]
+    fn test_reports_unsupported_features_per_target() {
+        let rule = AgentCompatibilityRule::new(vec![Agent::Claude, Agent::Cursor, Agent::Goose]);
+        let diagnostics = rule.check(&manifest(
+            "context: fork\nallowed-tools: Bash Read\nhooks:\n  PreToolUse: []",
+        ));
+        let messages: Vec<(Option<usize>, &str)> = diagnostics
+            .iter()
+            .map(|d| (d.line, d.message.as_str()))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                (Some(6), "Cursor does not support hooks"),
+                (Some(4), "Cursor does not support 'context: fork'"),
+                (Some(5), "Goose does not support 'allowed-tools'"),
+                (Some(6), "Goose does not support hooks"),
+                (Some(4), "Goose does not support 'context: fork'"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reports_scripts_in_unsupported_languages() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill = temp.path().join("demo");
+        std::fs::create_dir_all(skill.join("scripts")).unwrap();
+        std::fs::write(
+            skill.join("SKILL.md"),
+            "---\nname: demo\ndescription: A demo skill.\n---\n\n# Demo\n",
+        )
+        .unwrap();
+        std::fs::write(skill.join("scripts/run.py"), "#!/usr/bin/env python3\n").unwrap();
+        std::fs::write(skill.join("scripts/build.rb"), "#!/usr/bin/env ruby\n").unwrap();
+        std::fs::write(skill.join("scripts/deploy"), "#!/usr/bin/env bash\n").unwrap();
+        std::fs::write(skill.join("scripts/notes"), "plain text\n").unwrap();
+        let manifest = Manifest::parse(skill.join("SKILL.md")).unwrap();
+
+        static ACME: Lazy<CustomAgent> = Lazy::new(|| CustomAgent {
+            name: "acme".to_string(),
+            skills_dir: ".acme/skills".to_string(),
+            global_skills_dir: "~/.acme/skills".to_string(),
+            features: AgentFeatures {
+                script_langs: Some(vec![ScriptLang::Python]),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let rule = AgentCompatibilityRule::new(vec![Agent::Custom(&ACME), Agent::Claude]);
+        let mut messages: Vec<String> = rule
+            .check(&manifest)
+            .iter()
+            .map(|d| format!("{}: {}", d.path.rsplit('/').next().unwrap(), d.message))
+            .collect();
+        messages.sort();
+        assert_eq!(
+            messages,
+            vec![
+                "build.rb: acme does not run ruby scripts",
+                "deploy: acme does not run bash scripts",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reports_targets_missing_from_compatibility() {
+        let rule = AgentCompatibilityRule::new(vec![Agent::Claude, Agent::Codex]);
+        let diagnostics = rule.check(&manifest("compatibility: Works with Claude Code"));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Compatibility names Claude Code but not target agent Codex"
+        );
+
+        let rule = AgentCompatibilityRule::new(vec![Agent::Codex]);
+        assert!(rule
+            .check(&manifest("compatibility: Requires git and network access"))
+            .is_empty());
+    }
+}
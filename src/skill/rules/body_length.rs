@@ -1,7 +1,7 @@
 //! Warns when the skill body exceeds a recommended line count.
 
 use crate::skill::manifest::Manifest;
-use crate::skill::rules::Rule;
+use crate::skill::rules::{Rule, RuleExample, RuleMeta, Severity};
 use crate::skill::validator::{Diagnostic, DiagnosticCode};
 
 /// W001: Warns if body exceeds max_body_lines.
@@ -11,6 +11,25 @@ pub struct BodyLengthRule {
 }
 
 impl BodyLengthRule {
+    /// Metadata for W001.
+    pub const META: RuleMeta = RuleMeta {
+        code: DiagnosticCode::W001,
+        name: "body-length",
+        severity: Severity::Warning,
+        summary: "Skill body exceeds recommended length",
+        description: r#"The body of SKILL.md is loaded into the context window when the skill is
+activated. Bodies longer than 500 lines by default waste context; move
+detailed material into files under `references/` and link to them so agents
+load them only when needed.
+"#,
+        config_key: Some("lint.rules.body_length"),
+        examples: &[RuleExample {
+            title: "Configuration",
+            lang: "toml",
+            code: "[lint.rules]\nbody_length = 300",
+        }],
+    };
+
     /// Create a new body length rule with the specified maximum lines.
     pub fn new(max_lines: usize) -> Self {
         Self { max_lines }
@@ -18,8 +37,8 @@ impl BodyLengthRule {
 }
 
 impl Rule for BodyLengthRule {
-    fn name(&self) -> &'static str {
-        "body-length"
+    fn meta(&self) -> &'static RuleMeta {
+        &Self::META
     }
 
     fn check(&self, manifest: &Manifest) -> Vec<Diagnostic> {
@@ -1,7 +1,7 @@
 //! Validates the length of the compatibility field in frontmatter.
 
 use crate::skill::manifest::Manifest;
-use crate::skill::rules::Rule;
+use crate::skill::rules::{Rule, RuleExample, RuleMeta, Severity};
 use crate::skill::validator::{Diagnostic, DiagnosticCode};
 
 /// E006: Validates compatibility field length.
@@ -11,6 +11,24 @@ pub struct CompatibilityLengthRule {
 }
 
 impl CompatibilityLengthRule {
+    /// Metadata for E006.
+    pub const META: RuleMeta = RuleMeta {
+        code: DiagnosticCode::E006,
+        name: "compatibility-length",
+        severity: Severity::Error,
+        summary: "Compatibility field exceeds maximum length",
+        description: r#"The optional `compatibility` field describes environment requirements such
+as required tools or network access. It is limited to 500 characters by
+default; longer explanations belong in the body of SKILL.md.
+"#,
+        config_key: Some("lint.rules.compatibility_length"),
+        examples: &[RuleExample {
+            title: "Valid",
+            lang: "yaml",
+            code: "compatibility: Requires python3 and network access",
+        }],
+    };
+
     /// Create a new compatibility length rule with the specified maximum.
     pub fn new(max_length: usize) -> Self {
         Self { max_length }
@@ -18,8 +36,8 @@ impl CompatibilityLengthRule {
 }
 
 impl Rule for CompatibilityLengthRule {
-    fn name(&self) -> &'static str {
-        "compatibility-length"
+    fn meta(&self) -> &'static RuleMeta {
+        &Self::META
     }
 
     fn check(&self, manifest: &Manifest) -> Vec<Diagnostic> {
@@ -1,15 +1,36 @@
 //! Validates skill descriptions: presence and length.
 
 use crate::skill::manifest::Manifest;
-use crate::skill::rules::Rule;
+use crate::skill::rules::{Rule, RuleExample, RuleMeta, Severity};
 use crate::skill::validator::{Diagnostic, DiagnosticCode};
 
 /// E004: Validates description is not empty.
 pub struct DescriptionRequiredRule;
 
+impl DescriptionRequiredRule {
+    /// Metadata for E004.
+    pub const META: RuleMeta = RuleMeta {
+        code: DiagnosticCode::E004,
+        name: "description-required",
+        severity: Severity::Error,
+        summary: "Missing skill description",
+        description: r#"The `description` field tells agents what the skill does and when to use it.
+Agents decide whether to load a skill based on this text alone, so a skill
+without a description is effectively never activated.
+"#,
+        config_key: Some("lint.rules.description_required"),
+        examples: &[RuleExample {
+            title: "Valid",
+            lang: "yaml",
+            code:
+                "description: Extract text and tables from PDF files. Use when working with PDFs.",
+        }],
+    };
+}
+
 impl Rule for DescriptionRequiredRule {
-    fn name(&self) -> &'static str {
-        "description-required"
+    fn meta(&self) -> &'static RuleMeta {
+        &Self::META
     }
 
     fn check(&self, manifest: &Manifest) -> Vec<Diagnostic> {
@@ -37,6 +58,25 @@ pub struct DescriptionLengthRule {
 }
 
 impl DescriptionLengthRule {
+    /// Metadata for E005.
+    pub const META: RuleMeta = RuleMeta {
+        code: DiagnosticCode::E005,
+        name: "description-length",
+        severity: Severity::Error,
+        summary: "Skill description exceeds maximum length",
+        description: r#"Descriptions are included in the system prompt of every session, so they
+must stay short. The default limit is 1024 characters. Move detailed usage
+instructions into the body of SKILL.md, which is only loaded when the skill
+is activated.
+"#,
+        config_key: Some("lint.rules.description_length"),
+        examples: &[RuleExample {
+            title: "Configuration",
+            lang: "toml",
+            code: "[lint.rules]\ndescription_length = 512",
+        }],
+    };
+
     /// Create a new description length rule with the specified maximum.
     pub fn new(max_length: usize) -> Self {
         Self { max_length }
@@ -44,8 +84,8 @@ impl DescriptionLengthRule {
 }
 
 impl Rule for DescriptionLengthRule {
-    fn name(&self) -> &'static str {
-        "description-length"
+    fn meta(&self) -> &'static RuleMeta {
+        &Self::META
     }
 
     fn check(&self, manifest: &Manifest) -> Vec<Diagnostic> {
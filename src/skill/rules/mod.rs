@@ -1,15 +1,19 @@
 //! Validation rules for skill manifests.
 //!
 //! This module contains individual lint rules that check different aspects
-//! of skill manifests, from name format to script permissions.
+//! of skill manifests, from name format to script permissions. Every
+//! diagnostic code is documented by a [`RuleMeta`] in the [`registry`].
 
+mod agents;
 mod body_length;
 mod compatibility;
 mod description;
 mod name;
 mod references;
+pub mod registry;
 mod scripts;
 
+pub use agents::AgentCompatibilityRule;
 pub use body_length::BodyLengthRule;
 pub use compatibility::CompatibilityLengthRule;
 pub use description::{DescriptionLengthRule, DescriptionRequiredRule};
@@ -18,12 +22,111 @@ pub use references::ReferencesExistRule;
 pub use scripts::{ScriptExecutableRule, ScriptShebangRule};
 
 use crate::skill::manifest::Manifest;
-use crate::skill::validator::Diagnostic;
+use crate::skill::validator::{Diagnostic, DiagnosticCode};
+
+/// Base URL of the rule reference documentation.
+const RULES_DOC_URL: &str = concat!(env!("CARGO_PKG_REPOSITORY"), "/blob/main/docs/rules.md");
+
+/// Default severity of a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// Fails validation.
+    Error,
+    /// Reported, but only fails validation in strict mode.
+    Warning,
+}
+
+impl Severity {
+    /// Lowercase name of the severity, as used in reports.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+        }
+    }
+}
+
+/// A short example attached to a rule's documentation.
+#[derive(Debug)]
+pub struct RuleExample {
+    /// What the example demonstrates (e.g., "Invalid").
+    pub title: &'static str,
+    /// Language of the snippet, used for the code fence.
+    pub lang: &'static str,
+    /// The snippet itself.
+    pub code: &'static str,
+}
+
+/// Static metadata describing a lint rule.
+#[derive(Debug)]
+pub struct RuleMeta {
+    /// Diagnostic code reported by the rule.
+    pub code: DiagnosticCode,
+    /// Human-readable name (e.g., "name-format").
+    pub name: &'static str,
+    /// Default severity.
+    pub severity: Severity,
+    /// One-line description.
+    pub summary: &'static str,
+    /// Long-form explanation in Markdown.
+    pub description: &'static str,
+    /// Configuration key that controls the rule, if any.
+    pub config_key: Option<&'static str>,
+    /// Examples of offending and fixed input.
+    pub examples: &'static [RuleExample],
+}
+
+impl RuleMeta {
+    /// URL of this rule's section in the rule reference.
+    pub fn help_uri(&self) -> String {
+        format!(
+            "{}#{}-{}",
+            RULES_DOC_URL,
+            self.code.to_string().to_lowercase(),
+            self.name
+        )
+    }
+
+    /// Render the long-form documentation for this rule as Markdown.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("## {}: {}\n\n", self.code, self.name);
+
+        out.push_str(&format!("**Severity:** {}", self.severity.as_str()));
+        if let Some(key) = self.config_key {
+            out.push_str(&format!(" | **Config:** `{}`", key));
+        }
+        out.push_str("\n\n");
+
+        out.push_str(self.summary);
+        out.push_str("\n\n");
+        out.push_str(self.description.trim());
+        out.push('\n');
+
+        if !self.examples.is_empty() {
+            out.push_str("\n### Examples\n");
+            for example in self.examples {
+                out.push_str(&format!(
+                    "\n{}:\n\n```{}\n{}\n```\n",
+                    example.title,
+                    example.lang,
+                    example.code.trim()
+                ));
+            }
+        }
+
+        out
+    }
+}
 
 /// A lint rule that checks a manifest for issues.
 pub trait Rule: Send + Sync {
+    /// Metadata describing this rule.
+    fn meta(&self) -> &'static RuleMeta;
+
     /// Human-readable name for this rule (e.g., "name-format")
-    fn name(&self) -> &'static str;
+    fn name(&self) -> &'static str {
+        self.meta().name
+    }
 
     /// Check the manifest and return any diagnostics found.
     fn check(&self, manifest: &Manifest) -> Vec<Diagnostic>;
@@ -1,7 +1,7 @@
 //! Validates skill names: format, length, and directory matching.
 
 use crate::skill::manifest::Manifest;
-use crate::skill::rules::Rule;
+use crate::skill::rules::{Rule, RuleExample, RuleMeta, Severity};
 use crate::skill::validator::{Diagnostic, DiagnosticCode};
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -12,9 +12,43 @@ static NAME_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-z0-9]+(-[a-z0-9]+
 /// E001: Validates name format (lowercase alphanumeric + single hyphens)
 pub struct NameFormatRule;
 
+impl NameFormatRule {
+    /// Metadata for E001.
+    pub const META: RuleMeta = RuleMeta {
+        code: DiagnosticCode::E001,
+        name: "name-format",
+        severity: Severity::Error,
+        summary: "Invalid skill name format",
+        description: r#"Skill names are used as directory names, in install paths and in agent
+prompts, so they must be portable identifiers. A valid name consists of
+lowercase ASCII letters and digits, optionally separated by single hyphens.
+Names may not start or end with a hyphen, and may not contain consecutive
+hyphens, underscores, spaces or uppercase letters.
+"#,
+        config_key: Some("lint.rules.name_format"),
+        examples: &[
+            RuleExample {
+                title: "Invalid",
+                lang: "yaml",
+                code: "name: My_Skill",
+            },
+            RuleExample {
+                title: "Valid",
+                lang: "yaml",
+                code: "name: my-skill",
+            },
+        ],
+    };
+
+    /// Returns true if `name` is a valid skill name.
+    pub fn is_valid(name: &str) -> bool {
+        NAME_REGEX.is_match(name)
+    }
+}
+
 impl Rule for NameFormatRule {
-    fn name(&self) -> &'static str {
-        "name-format"
+    fn meta(&self) -> &'static RuleMeta {
+        &Self::META
     }
 
     fn check(&self, manifest: &Manifest) -> Vec<Diagnostic> {
@@ -45,6 +79,25 @@ pub struct NameLengthRule {
 }
 
 impl NameLengthRule {
+    /// Metadata for E002.
+    pub const META: RuleMeta = RuleMeta {
+        code: DiagnosticCode::E002,
+        name: "name-length",
+        severity: Severity::Error,
+        summary: "Skill name exceeds maximum length",
+        description: r#"Skill names must be at most 64 characters long by default. Long names are
+truncated or rejected by some agents and make install paths unwieldy.
+The limit can be changed, or the check disabled, with the
+`lint.rules.name_length` setting.
+"#,
+        config_key: Some("lint.rules.name_length"),
+        examples: &[RuleExample {
+            title: "Configuration",
+            lang: "toml",
+            code: "[lint.rules]\nname_length = 32",
+        }],
+    };
+
     /// Create a new name length rule with the specified maximum.
     pub fn new(max_length: usize) -> Self {
         Self { max_length }
@@ -52,8 +105,8 @@ impl NameLengthRule {
 }
 
 impl Rule for NameLengthRule {
-    fn name(&self) -> &'static str {
-        "name-length"
+    fn meta(&self) -> &'static RuleMeta {
+        &Self::META
     }
 
     fn check(&self, manifest: &Manifest) -> Vec<Diagnostic> {
@@ -81,9 +134,37 @@ impl Rule for NameLengthRule {
 /// E003: Validates name matches parent directory
 pub struct NameDirectoryRule;
 
+impl NameDirectoryRule {
+    /// Metadata for E003.
+    pub const META: RuleMeta = RuleMeta {
+        code: DiagnosticCode::E003,
+        name: "name-directory",
+        severity: Severity::Error,
+        summary: "Skill name does not match directory name",
+        description: r#"A skill lives in a directory named after it, e.g. `pdf-tools/SKILL.md`
+declares `name: pdf-tools`. Agents locate skills by directory name, so a
+mismatch means the skill is installed or referenced under a different name
+than the one it declares. Rename either the directory or the `name` field.
+"#,
+        config_key: Some("lint.rules.name_directory"),
+        examples: &[
+            RuleExample {
+                title: "Invalid",
+                lang: "text",
+                code: "pdf-tools/SKILL.md  # name: pdf-utils",
+            },
+            RuleExample {
+                title: "Valid",
+                lang: "text",
+                code: "pdf-tools/SKILL.md  # name: pdf-tools",
+            },
+        ],
+    };
+}
+
 impl Rule for NameDirectoryRule {
-    fn name(&self) -> &'static str {
-        "name-directory"
+    fn meta(&self) -> &'static RuleMeta {
+        &Self::META
     }
 
     fn check(&self, manifest: &Manifest) -> Vec<Diagnostic> {
@@ -1,7 +1,7 @@
 //! Validates that files referenced in the skill body actually exist.
 
 use crate::skill::manifest::Manifest;
-use crate::skill::rules::Rule;
+use crate::skill::rules::{Rule, RuleExample, RuleMeta, Severity};
 use crate::skill::validator::{Diagnostic, DiagnosticCode};
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -13,9 +13,51 @@ static REF_REGEX: Lazy<Regex> =
 /// E009: Validates that referenced files exist
 pub struct ReferencesExistRule;
 
+impl ReferencesExistRule {
+    /// Metadata for E009.
+    pub const META: RuleMeta = RuleMeta {
+        code: DiagnosticCode::E009,
+        name: "references-exist",
+        severity: Severity::Error,
+        summary: "Referenced file not found",
+        description: r#"Files under `scripts/`, `references/` or `assets/` that are mentioned in
+backticks in the skill body must exist relative to the skill directory.
+Agents follow these references when executing the skill, and a missing file
+makes the instructions fail at run time.
+"#,
+        config_key: Some("lint.rules.references_exist"),
+        examples: &[
+            RuleExample {
+                title: "Invalid",
+                lang: "markdown",
+                code: "Run `scripts/extract.py` on the input file.\n<!-- but scripts/extract.py does not exist -->",
+            },
+            RuleExample {
+                title: "Valid",
+                lang: "markdown",
+                code: "Run `scripts/extract.py` on the input file.\n<!-- with my-skill/scripts/extract.py present -->",
+            },
+        ],
+    };
+
+    /// Paths under `scripts/`, `references/` or `assets/` referenced in `body`,
+    /// in order of first appearance.
+    pub fn referenced_paths(body: &str) -> Vec<&str> {
+        let mut paths: Vec<&str> = Vec::new();
+        for cap in REF_REGEX.captures_iter(body) {
+            if let Some(path) = cap.get(1).map(|m| m.as_str()) {
+                if !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+        }
+        paths
+    }
+}
+
 impl Rule for ReferencesExistRule {
-    fn name(&self) -> &'static str {
-        "references-exist"
+    fn meta(&self) -> &'static RuleMeta {
+        &Self::META
     }
 
     fn check(&self, manifest: &Manifest) -> Vec<Diagnostic> {
@@ -25,8 +67,7 @@ impl Rule for ReferencesExistRule {
 
         let mut diagnostics = Vec::new();
 
-        for cap in REF_REGEX.captures_iter(&manifest.body) {
-            let ref_path = &cap[1];
+        for ref_path in Self::referenced_paths(&manifest.body) {
             let full_path = skill_dir.join(ref_path);
 
             if !full_path.exists() {
@@ -0,0 +1,163 @@
+//! Registry of rule metadata for every diagnostic code.
+//!
+//! Rules implemented by a [`Rule`](super::Rule) carry their own metadata;
+//! codes reported outside the rule pipeline (parse errors, missing files)
+//! are described here.
+
+use crate::skill::rules::{
+    AgentCompatibilityRule, BodyLengthRule, CompatibilityLengthRule, DescriptionLengthRule,
+    DescriptionRequiredRule, NameDirectoryRule, NameFormatRule, NameLengthRule,
+    ReferencesExistRule, RuleExample, RuleMeta, ScriptExecutableRule, ScriptShebangRule, Severity,
+};
+use crate::skill::validator::DiagnosticCode;
+
+/// E007: Frontmatter could not be parsed.
+pub const INVALID_YAML: RuleMeta = RuleMeta {
+    code: DiagnosticCode::E007,
+    name: "invalid-yaml",
+    severity: Severity::Error,
+    summary: "Invalid YAML in frontmatter",
+    description: r#"SKILL.md must start with a YAML frontmatter block delimited by `---`
+lines. The block must be valid YAML and contain at least the `name` and
+`description` fields. Other rules cannot run on a skill whose frontmatter
+fails to parse.
+"#,
+    config_key: None,
+    examples: &[RuleExample {
+        title: "Valid",
+        lang: "markdown",
+        code: "---\nname: my-skill\ndescription: What the skill does and when to use it.\n---\n\n# My Skill",
+    }],
+};
+
+/// E008: Skill directory without a SKILL.md.
+pub const MISSING_SKILL_MD: RuleMeta = RuleMeta {
+    code: DiagnosticCode::E008,
+    name: "missing-skill-md",
+    severity: Severity::Error,
+    summary: "Missing SKILL.md file",
+    description: r#"Every skill is a directory containing a `SKILL.md` file. The file name is
+case-sensitive; agents ignore directories without it.
+"#,
+    config_key: None,
+    examples: &[RuleExample {
+        title: "Valid",
+        lang: "text",
+        code: "my-skill/\n  SKILL.md\n  scripts/",
+    }],
+};
+
+/// W004: Optional directory without any files.
+pub const EMPTY_DIRECTORY: RuleMeta = RuleMeta {
+    code: DiagnosticCode::W004,
+    name: "empty-directory",
+    severity: Severity::Warning,
+    summary: "Empty optional directory",
+    description: r#"The optional `scripts/`, `references/` and `assets/` directories should
+contain files when present. Empty directories are usually left over from a
+template and can be removed.
+"#,
+    config_key: None,
+    examples: &[],
+};
+
+/// Get the metadata for a diagnostic code.
+pub fn lookup(code: DiagnosticCode) -> &'static RuleMeta {
+    match code {
+        DiagnosticCode::E001 => &NameFormatRule::META,
+        DiagnosticCode::E002 => &NameLengthRule::META,
+        DiagnosticCode::E003 => &NameDirectoryRule::META,
+        DiagnosticCode::E004 => &DescriptionRequiredRule::META,
+        DiagnosticCode::E005 => &DescriptionLengthRule::META,
+        DiagnosticCode::E006 => &CompatibilityLengthRule::META,
+        DiagnosticCode::E007 => &INVALID_YAML,
+        DiagnosticCode::E008 => &MISSING_SKILL_MD,
+        DiagnosticCode::E009 => &ReferencesExistRule::META,
+        DiagnosticCode::W001 => &BodyLengthRule::META,
+        DiagnosticCode::W002 => &ScriptExecutableRule::META,
+        DiagnosticCode::W003 => &ScriptShebangRule::META,
+        DiagnosticCode::W004 => &EMPTY_DIRECTORY,
+        DiagnosticCode::W005 => &AgentCompatibilityRule::META,
+    }
+}
+
+/// All registered rules, ordered by code.
+pub fn all() -> Vec<&'static RuleMeta> {
+    DiagnosticCode::ALL
+        .iter()
+        .map(|code| lookup(*code))
+        .collect()
+}
+
+/// Find a rule by code (e.g., "E009", case-insensitive) or name (e.g., "references-exist").
+pub fn find(query: &str) -> Option<&'static RuleMeta> {
+    all()
+        .into_iter()
+        .find(|meta| meta.code.to_string().eq_ignore_ascii_case(query) || meta.name == query)
+}
+
+/// Render the full rule reference as a Markdown document.
+pub fn render_docs() -> String {
+    let mut out = String::from("# Lint rules\n\n");
+    out.push_str("<!-- Generated by `skilo explain --all`. Do not edit by hand. -->\n\n");
+    out.push_str("| Code | Name | Severity | Description |\n");
+    out.push_str("|------|------|----------|-------------|\n");
+    for meta in all() {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            meta.code,
+            meta.name,
+            meta.severity.as_str(),
+            meta.summary
+        ));
+    }
+
+    for meta in all() {
+        out.push('\n');
+        out.push_str(&meta.to_markdown());
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_and_names_are_unique() {
+        let rules = all();
+        for (i, a) in rules.iter().enumerate() {
+            assert_eq!(lookup(a.code).code, a.code);
+            for b in &rules[i + 1..] {
+                assert_ne!(a.code, b.code);
+                assert_ne!(a.name, b.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_by_code_or_name() {
+        assert_eq!(find("E009").unwrap().name, "references-exist");
+        assert_eq!(find("e009").unwrap().code, DiagnosticCode::E009);
+        assert_eq!(find("body-length").unwrap().code, DiagnosticCode::W001);
+        assert!(find("E999").is_none());
+    }
+
+    #[test]
+    fn test_help_uri_points_at_section() {
+        assert!(lookup(DiagnosticCode::E001)
+            .help_uri()
+            .ends_with("docs/rules.md#e001-name-format"));
+    }
+
+    #[test]
+    fn test_docs_are_up_to_date() {
+        let docs = include_str!("../../../docs/rules.md");
+        assert_eq!(
+            docs,
+            render_docs(),
+            "docs/rules.md is stale; regenerate it with `skilo explain --all > docs/rules.md`"
+        );
+    }
+}
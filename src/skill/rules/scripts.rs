@@ -1,39 +1,66 @@
 //! Validates script files: executable permissions and shebang lines.
 
+use crate::cli::ScriptLang;
+use crate::lang::MANIFEST_FILES;
 use crate::skill::manifest::Manifest;
-use crate::skill::rules::Rule;
+use crate::skill::rules::{Rule, RuleExample, RuleMeta, Severity};
 use crate::skill::validator::{Diagnostic, DiagnosticCode};
 
+/// Script files in `scripts/`, skipping dependency manifests and lock files.
+pub(super) fn script_files(manifest: &Manifest) -> Vec<std::path::PathBuf> {
+    let Some(skill_dir) = manifest.path.parent() else {
+        return Vec::new();
+    };
+
+    let Ok(entries) = std::fs::read_dir(skill_dir.join("scripts")) else {
+        return Vec::new();
+    };
+
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .map_or(true, |n| !MANIFEST_FILES.contains(&n))
+        })
+        .collect()
+}
+
 /// W002: Warns if scripts are not executable.
 pub struct ScriptExecutableRule;
 
+impl ScriptExecutableRule {
+    /// Metadata for W002.
+    pub const META: RuleMeta = RuleMeta {
+        code: DiagnosticCode::W002,
+        name: "script-executable",
+        severity: Severity::Warning,
+        summary: "Script is not executable",
+        description: r#"Files in `scripts/` are meant to be run directly by agents. On Unix systems
+they need the executable bit, otherwise invoking them by path fails with a
+permission error. This check is skipped on platforms without Unix
+permissions.
+"#,
+        config_key: Some("lint.rules.script_executable"),
+        examples: &[RuleExample {
+            title: "Fix",
+            lang: "sh",
+            code: "chmod +x my-skill/scripts/*",
+        }],
+    };
+}
+
 impl Rule for ScriptExecutableRule {
-    fn name(&self) -> &'static str {
-        "script-executable"
+    fn meta(&self) -> &'static RuleMeta {
+        &Self::META
     }
 
     fn check(&self, manifest: &Manifest) -> Vec<Diagnostic> {
-        let Some(skill_dir) = manifest.path.parent() else {
-            return Vec::new();
-        };
-
-        let scripts_dir = skill_dir.join("scripts");
-        if !scripts_dir.exists() {
-            return Vec::new();
-        }
-
-        let Ok(entries) = std::fs::read_dir(&scripts_dir) else {
-            return Vec::new();
-        };
-
         let mut diagnostics = Vec::new();
 
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if !path.is_file() {
-                continue;
-            }
-
+        for path in script_files(manifest) {
             #[cfg(unix)]
             {
                 use std::os::unix::fs::PermissionsExt;
@@ -56,47 +83,83 @@ impl Rule for ScriptExecutableRule {
     }
 }
 
-/// W003: Warns if scripts are missing shebang
+/// W003: Warns if scripts are missing a shebang or name the wrong interpreter.
 pub struct ScriptShebangRule;
 
+impl ScriptShebangRule {
+    /// Message of the diagnostic for a script without a shebang.
+    pub const MISSING_MESSAGE: &'static str = "Script missing shebang line";
+
+    /// Metadata for W003.
+    pub const META: RuleMeta = RuleMeta {
+        code: DiagnosticCode::W003,
+        name: "script-shebang",
+        severity: Severity::Warning,
+        summary: "Script missing shebang line",
+        description: r#"Scripts should start with a shebang line so they run with the intended
+interpreter regardless of the caller's shell. Prefer `/usr/bin/env` to
+locate the interpreter on `PATH`.
+
+The interpreter must also match the file extension, e.g. `python3` or
+`uv run --script` for `.py`, `deno run` or `tsx` for `.ts`, `rust-script`
+for `.rs`, `ruby` for `.rb` and `pwsh` for `.ps1`. Go does not allow `#!`,
+so `.go` scripts start with `//usr/bin/env go run "$0" "$@"; exit` instead.
+Dependency manifests such as `requirements.txt`, `package.json`, `go.mod`
+and `Gemfile` are not checked.
+"#,
+        config_key: Some("lint.rules.script_shebang"),
+        examples: &[RuleExample {
+            title: "Valid",
+            lang: "python",
+            code: "#!/usr/bin/env python3\nprint(\"hello\")",
+        }],
+    };
+}
+
 impl Rule for ScriptShebangRule {
-    fn name(&self) -> &'static str {
-        "script-shebang"
+    fn meta(&self) -> &'static RuleMeta {
+        &Self::META
     }
 
     fn check(&self, manifest: &Manifest) -> Vec<Diagnostic> {
-        let Some(skill_dir) = manifest.path.parent() else {
-            return Vec::new();
-        };
-
-        let scripts_dir = skill_dir.join("scripts");
-        if !scripts_dir.exists() {
-            return Vec::new();
-        }
-
-        let Ok(entries) = std::fs::read_dir(&scripts_dir) else {
-            return Vec::new();
-        };
-
         let mut diagnostics = Vec::new();
 
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if !path.is_file() {
+        for path in script_files(manifest) {
+            let Ok(content) = std::fs::read_to_string(&path) else {
                 continue;
-            }
-
-            if let Ok(content) = std::fs::read_to_string(&path) {
-                if !content.starts_with("#!") {
-                    diagnostics.push(Diagnostic {
+            };
+            let first_line = content.lines().next().unwrap_or_default();
+            let lang = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .and_then(ScriptLang::from_extension);
+
+            match (ScriptLang::interpreter(first_line), lang) {
+                (None, lang) => diagnostics.push(Diagnostic {
+                    path: path.display().to_string(),
+                    line: Some(1),
+                    column: Some(1),
+                    message: Self::MISSING_MESSAGE.into(),
+                    code: DiagnosticCode::W003,
+                    fix_hint: Some(match lang {
+                        Some(lang) => format!("Add {} as first line", lang.shebang()),
+                        None => "Add #!/usr/bin/env <interpreter> as first line".into(),
+                    }),
+                }),
+                (Some(interpreter), Some(lang)) if !lang.runs_with(&interpreter) => diagnostics
+                    .push(Diagnostic {
                         path: path.display().to_string(),
                         line: Some(1),
                         column: Some(1),
-                        message: "Script missing shebang line".into(),
+                        message: format!(
+                            "Shebang runs '{}', which does not match the .{} extension",
+                            interpreter,
+                            lang.extension()
+                        ),
                         code: DiagnosticCode::W003,
-                        fix_hint: Some("Add #!/usr/bin/env <interpreter> as first line".into()),
-                    });
-                }
+                        fix_hint: Some(format!("Use {}", lang.shebang())),
+                    }),
+                _ => {}
             }
         }
 
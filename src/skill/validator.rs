@@ -3,10 +3,12 @@
 use crate::config::LintConfig;
 use crate::skill::manifest::Manifest;
 use crate::skill::rules::{
-    BodyLengthRule, CompatibilityLengthRule, DescriptionLengthRule, DescriptionRequiredRule,
-    NameDirectoryRule, NameFormatRule, NameLengthRule, ReferencesExistRule, Rule,
-    ScriptExecutableRule, ScriptShebangRule,
+    registry, AgentCompatibilityRule, BodyLengthRule, CompatibilityLengthRule,
+    DescriptionLengthRule, DescriptionRequiredRule, NameDirectoryRule, NameFormatRule,
+    NameLengthRule, ReferencesExistRule, Rule, RuleMeta, ScriptExecutableRule, ScriptShebangRule,
+    Severity,
 };
+use serde::{Deserialize, Serialize};
 
 /// Result of validating a skill.
 #[derive(Debug, Default)]
@@ -36,7 +38,7 @@ impl ValidationResult {
 }
 
 /// A validation diagnostic (error or warning).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Diagnostic {
     /// Path to the file with the issue.
     pub path: String,
@@ -53,7 +55,7 @@ pub struct Diagnostic {
 }
 
 /// Diagnostic codes for validation issues.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DiagnosticCode {
     /// Invalid name format.
     E001,
@@ -82,6 +84,8 @@ pub enum DiagnosticCode {
     W003,
     /// Empty optional directory.
     W004,
+    /// Feature unsupported by a target agent.
+    W005,
 }
 
 impl std::fmt::Display for DiagnosticCode {
@@ -100,25 +104,38 @@ impl std::fmt::Display for DiagnosticCode {
             Self::W002 => write!(f, "W002"),
             Self::W003 => write!(f, "W003"),
             Self::W004 => write!(f, "W004"),
+            Self::W005 => write!(f, "W005"),
         }
     }
 }
 
 impl DiagnosticCode {
+    /// All diagnostic codes, in order.
+    pub const ALL: [DiagnosticCode; 14] = [
+        Self::E001,
+        Self::E002,
+        Self::E003,
+        Self::E004,
+        Self::E005,
+        Self::E006,
+        Self::E007,
+        Self::E008,
+        Self::E009,
+        Self::W001,
+        Self::W002,
+        Self::W003,
+        Self::W004,
+        Self::W005,
+    ];
+
+    /// Metadata describing the rule that reports this code.
+    pub fn meta(&self) -> &'static RuleMeta {
+        registry::lookup(*self)
+    }
+
     /// Returns true if this is an error (not a warning).
     pub fn is_error(&self) -> bool {
-        matches!(
-            self,
-            Self::E001
-                | Self::E002
-                | Self::E003
-                | Self::E004
-                | Self::E005
-                | Self::E006
-                | Self::E007
-                | Self::E008
-                | Self::E009
-        )
+        self.meta().severity == Severity::Error
     }
 }
 
@@ -168,6 +185,11 @@ impl Validator {
         if config.rules.script_shebang {
             rules.push(Box::new(ScriptShebangRule));
         }
+        if !config.target_agents.is_empty() {
+            rules.push(Box::new(AgentCompatibilityRule::new(
+                config.target_agents.clone(),
+            )));
+        }
 
         Self { rules }
     }
@@ -0,0 +1,344 @@
+//! Mirroring of skills across agents' skills directories.
+//!
+//! One directory (`./skills/` or an agent's skills directory) is the source
+//! of truth. Each target directory is made to match it: skills missing from
+//! the target are added, differing ones replaced and skills absent from the
+//! source deleted. In symlink mode targets hold relative links to the source
+//! skills instead of copies, so later edits need no further sync.
+
+use crate::agent::Agent;
+use crate::cli::SyncMode;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+
+/// What a sync does to one skill in one target directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncAction {
+    /// The skill is missing from the target.
+    Added,
+    /// The target's copy or link differs from the source.
+    Updated,
+    /// The skill is not in the source.
+    Deleted,
+    /// The target already matches the source.
+    Unchanged,
+}
+
+/// One skill in one agent's skills directory.
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncChange {
+    /// Agent owning the target directory.
+    pub agent: Agent,
+    /// Skill directory name.
+    pub skill: String,
+    /// Path of the skill in the target directory.
+    pub path: PathBuf,
+    /// What the sync does to the skill.
+    pub action: SyncAction,
+}
+
+/// Results of a sync run.
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    /// Source skills directory.
+    pub source: PathBuf,
+    /// Agents whose directories were compared with the source.
+    pub agents: Vec<Agent>,
+    /// Every skill considered, grouped by agent.
+    pub changes: Vec<SyncChange>,
+    /// Whether files were left untouched.
+    pub dry_run: bool,
+}
+
+impl SyncReport {
+    /// Number of skills with the given action.
+    pub fn count(&self, action: SyncAction) -> usize {
+        self.changes.iter().filter(|c| c.action == action).count()
+    }
+
+    /// Returns true if every target already matches the source.
+    pub fn is_in_sync(&self) -> bool {
+        self.changes
+            .iter()
+            .all(|c| c.action == SyncAction::Unchanged)
+    }
+}
+
+/// Skill directories (or links to them) in `dir`, by directory name.
+pub fn skills_in(dir: &Path) -> BTreeMap<String, PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return BTreeMap::new();
+    };
+
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.join("SKILL.md").is_file())
+        .filter_map(|path| Some((path.file_name()?.to_str()?.to_string(), path)))
+        .collect()
+}
+
+/// Compare `target` with `source` and list what syncing would do.
+pub fn plan(agent: Agent, source: &Path, target: &Path, mode: SyncMode) -> Vec<SyncChange> {
+    let wanted = skills_in(source);
+    let existing = skills_in(target);
+
+    let mut changes: Vec<SyncChange> = wanted
+        .iter()
+        .map(|(name, source_skill)| {
+            let path = target.join(name);
+            let action = if !existing.contains_key(name) {
+                SyncAction::Added
+            } else if matches_source(source_skill, &path, mode) {
+                SyncAction::Unchanged
+            } else {
+                SyncAction::Updated
+            };
+            SyncChange {
+                agent,
+                skill: name.clone(),
+                path,
+                action,
+            }
+        })
+        .collect();
+
+    changes.extend(
+        existing
+            .into_iter()
+            .filter(|(name, _)| !wanted.contains_key(name))
+            .map(|(skill, path)| SyncChange {
+                agent,
+                skill,
+                path,
+                action: SyncAction::Deleted,
+            }),
+    );
+    changes.sort_by(|a, b| a.skill.cmp(&b.skill));
+    changes
+}
+
+/// Carry out `change`, copying or linking the skill from `source`.
+pub fn apply(change: &SyncChange, source: &Path, mode: SyncMode) -> io::Result<()> {
+    match change.action {
+        SyncAction::Unchanged => return Ok(()),
+        SyncAction::Updated | SyncAction::Deleted => remove(&change.path)?,
+        SyncAction::Added => {}
+    }
+    if change.action == SyncAction::Deleted {
+        return Ok(());
+    }
+
+    let source_skill = source.join(&change.skill);
+    let target_dir = change.path.parent().unwrap_or(Path::new("."));
+    fs::create_dir_all(target_dir)?;
+    match mode {
+        SyncMode::Copy => copy_dir(&source_skill, &change.path),
+        SyncMode::Symlink => {
+            let link = relative_path(target_dir, &source_skill)?;
+            symlink_dir(&link, &change.path)
+        }
+    }
+}
+
+/// Recursively copy a directory, following symlinks.
+pub fn copy_dir(src: &Path, dst: &Path) -> io::Result<()> {
+    fs::create_dir_all(dst)?;
+
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let src_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
+
+        if src_path.is_dir() {
+            copy_dir(&src_path, &dst_path)?;
+        } else {
+            fs::copy(&src_path, &dst_path)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns true if the skill at `target` already mirrors `source`.
+fn matches_source(source: &Path, target: &Path, mode: SyncMode) -> bool {
+    let is_link = target
+        .symlink_metadata()
+        .is_ok_and(|m| m.file_type().is_symlink());
+
+    // The source may itself link to the target, which must not be replaced
+    if !is_link && fs::canonicalize(target).ok() == fs::canonicalize(source).ok() {
+        return true;
+    }
+
+    match mode {
+        SyncMode::Symlink => {
+            is_link && fs::canonicalize(target).ok() == fs::canonicalize(source).ok()
+        }
+        SyncMode::Copy => !is_link && tree(source).is_some_and(|s| Some(s) == tree(target)),
+    }
+}
+
+/// Relative paths, contents and executable bits of every file under `dir`.
+fn tree(dir: &Path) -> Option<Vec<(PathBuf, Vec<u8>, bool)>> {
+    WalkDir::new(dir)
+        .follow_links(true)
+        .sort_by_file_name()
+        .into_iter()
+        .filter(|entry| entry.as_ref().map_or(true, |e| e.file_type().is_file()))
+        .map(|entry| {
+            let entry = entry.ok()?;
+            let relative = entry.path().strip_prefix(dir).ok()?.to_path_buf();
+            let content = fs::read(entry.path()).ok()?;
+            Some((relative, content, is_executable(entry.path())))
+        })
+        .collect()
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .is_ok_and(|m| m.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_path: &Path) -> bool {
+    false
+}
+
+/// Remove a skill directory, or the link standing in for one.
+fn remove(path: &Path) -> io::Result<()> {
+    let metadata = path.symlink_metadata()?;
+    if metadata.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        // Directory symlinks are removed like directories on Windows
+        fs::remove_file(path).or_else(|_| fs::remove_dir(path))
+    }
+}
+
+#[cfg(unix)]
+fn symlink_dir(original: &Path, link: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(original, link)
+}
+
+#[cfg(windows)]
+fn symlink_dir(original: &Path, link: &Path) -> io::Result<()> {
+    std::os::windows::fs::symlink_dir(original, link)
+}
+
+/// Path of `to` relative to the directory `from`, e.g. `../../skills/demo`.
+fn relative_path(from: &Path, to: &Path) -> io::Result<PathBuf> {
+    let cwd = std::env::current_dir()?;
+    let (from, to) = (cwd.join(from), cwd.join(to));
+    let from: Vec<Component> = from.components().filter(is_not_cur_dir).collect();
+    let to: Vec<Component> = to.components().filter(is_not_cur_dir).collect();
+
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let mut path: PathBuf = from[common..]
+        .iter()
+        .map(|_| Component::ParentDir)
+        .collect();
+    path.extend(&to[common..]);
+    Ok(path)
+}
+
+fn is_not_cur_dir(component: &Component) -> bool {
+    !matches!(component, Component::CurDir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_skill(dir: &Path, name: &str, body: &str) {
+        let skill = dir.join(name);
+        fs::create_dir_all(&skill).unwrap();
+        fs::write(
+            skill.join("SKILL.md"),
+            format!("---\nname: {}\ndescription: Test.\n---\n\n{}\n", name, body),
+        )
+        .unwrap();
+    }
+
+    fn actions(changes: &[SyncChange]) -> Vec<(&str, SyncAction)> {
+        changes
+            .iter()
+            .map(|c| (c.skill.as_str(), c.action))
+            .collect()
+    }
+
+    #[test]
+    fn test_plan_and_apply_copy() {
+        let temp = TempDir::new().unwrap();
+        let source = temp.path().join("skills");
+        let target = temp.path().join(".cursor/skills");
+        write_skill(&source, "added", "New");
+        write_skill(&source, "changed", "Fresh");
+        write_skill(&source, "same", "Same");
+        write_skill(&target, "changed", "Stale");
+        write_skill(&target, "same", "Same");
+        write_skill(&target, "stale", "Old");
+
+        let changes = plan(Agent::Cursor, &source, &target, SyncMode::Copy);
+        assert_eq!(
+            actions(&changes),
+            vec![
+                ("added", SyncAction::Added),
+                ("changed", SyncAction::Updated),
+                ("same", SyncAction::Unchanged),
+                ("stale", SyncAction::Deleted),
+            ]
+        );
+
+        for change in &changes {
+            apply(change, &source, SyncMode::Copy).unwrap();
+        }
+        assert!(fs::read_to_string(target.join("changed/SKILL.md"))
+            .unwrap()
+            .contains("Fresh"));
+        assert!(!target.join("stale").exists());
+        assert!(plan(Agent::Cursor, &source, &target, SyncMode::Copy)
+            .iter()
+            .all(|c| c.action == SyncAction::Unchanged));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink_mode_links_relative_to_target() {
+        let temp = TempDir::new().unwrap();
+        let source = temp.path().join("skills");
+        let target = temp.path().join(".claude/skills");
+        write_skill(&source, "demo", "Demo");
+        write_skill(&target, "demo", "Demo");
+
+        // An identical copy is replaced by a link
+        let changes = plan(Agent::Claude, &source, &target, SyncMode::Symlink);
+        assert_eq!(actions(&changes), vec![("demo", SyncAction::Updated)]);
+        apply(&changes[0], &source, SyncMode::Symlink).unwrap();
+
+        let link = fs::read_link(target.join("demo")).unwrap();
+        assert_eq!(link, Path::new("../../skills/demo"));
+        assert_eq!(
+            plan(Agent::Claude, &source, &target, SyncMode::Symlink)[0].action,
+            SyncAction::Unchanged
+        );
+        // Switching back to copies replaces the link
+        assert_eq!(
+            plan(Agent::Claude, &source, &target, SyncMode::Copy)[0].action,
+            SyncAction::Updated
+        );
+        // Syncing back never replaces the directory the link points to
+        assert_eq!(
+            plan(Agent::Clawdbot, &target, &source, SyncMode::Symlink)[0].action,
+            SyncAction::Unchanged
+        );
+    }
+}
@@ -0,0 +1,417 @@
+//! Templates loaded from a directory on disk or in a git repository.
+//!
+//! Every file in the template directory is copied into the new skill, with
+//! `{{variable}}` placeholders substituted in both file contents and paths.
+//! `{{#variable}}...{{/variable}}` keeps its contents only when the variable
+//! is non-empty. An optional `template.toml` declares extra variables:
+//!
+//! ```toml
+//! description = "Platform team skill"
+//!
+//! [[prompts]]
+//! name = "team"
+//! message = "Owning team"
+//! default = "platform"
+//! ```
+
+use super::{to_title_case, SkillTemplate, TemplateContext};
+use crate::error::SkiloError;
+use crate::git::FetchResult;
+use clap::ValueEnum;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Name of the optional template manifest.
+pub const MANIFEST_FILE: &str = "template.toml";
+
+/// Variables every template can use.
+pub const BUILTIN_VARIABLES: &[&str] = &[
+    "name",
+    "title",
+    "description",
+    "license",
+    "lang",
+    "ext",
+    "date",
+];
+
+/// Pattern for `{{variable}}`, `{{#variable}}` and `{{/variable}}` tags.
+static TAG_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\{\{\s*([#/]?)\s*([A-Za-z0-9_-]+)\s*\}\}").unwrap());
+
+/// Contents of `template.toml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct TemplateManifest {
+    /// What the template is for.
+    pub description: Option<String>,
+    /// Extra variables to ask for when creating a skill.
+    pub prompts: Vec<TemplatePrompt>,
+}
+
+/// A custom variable declared by a template.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TemplatePrompt {
+    /// Variable name, used as `{{name}}`.
+    pub name: String,
+    /// Question shown when asking for the value.
+    pub message: Option<String>,
+    /// Value used when none is given.
+    pub default: Option<String>,
+}
+
+/// A template rendered from the files in a directory.
+pub struct CustomTemplate {
+    root: PathBuf,
+    manifest: TemplateManifest,
+    /// Keeps a temporary checkout alive while the template is in use.
+    _checkout: Option<FetchResult>,
+}
+
+impl CustomTemplate {
+    /// Load the template in `root`, checking that it only uses known variables.
+    pub fn load(root: PathBuf, checkout: Option<FetchResult>) -> Result<Self, SkiloError> {
+        if !root.is_dir() {
+            return Err(SkiloError::SkillTemplate(format!(
+                "{} is not a directory",
+                root.display()
+            )));
+        }
+
+        let manifest_path = root.join(MANIFEST_FILE);
+        let manifest: TemplateManifest = if manifest_path.is_file() {
+            toml::from_str(&fs::read_to_string(&manifest_path)?).map_err(|e| {
+                SkiloError::SkillTemplate(format!("{}: {}", manifest_path.display(), e))
+            })?
+        } else {
+            TemplateManifest::default()
+        };
+
+        let template = Self {
+            root,
+            manifest,
+            _checkout: checkout,
+        };
+        template.check_variables()?;
+        Ok(template)
+    }
+
+    /// What the template is for, from `template.toml`.
+    pub fn description(&self) -> Option<&str> {
+        self.manifest.description.as_deref()
+    }
+
+    /// Template files, relative to the template root.
+    fn files(&self) -> Vec<PathBuf> {
+        WalkDir::new(&self.root)
+            .min_depth(1)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|e| e.file_name() != ".git")
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .filter_map(|e| {
+                e.path()
+                    .strip_prefix(&self.root)
+                    .ok()
+                    .map(Path::to_path_buf)
+            })
+            .filter(|p| p != Path::new(MANIFEST_FILE))
+            .collect()
+    }
+
+    /// Reject placeholders that are neither built in nor declared as prompts,
+    /// and sections that are not closed in order.
+    fn check_variables(&self) -> Result<(), SkiloError> {
+        for relative in self.files() {
+            let invalid = |message: String| {
+                SkiloError::SkillTemplate(format!("{}: {}", relative.display(), message))
+            };
+            let mut texts = vec![relative.to_string_lossy().into_owned()];
+            if let Ok(content) = fs::read_to_string(self.root.join(&relative)) {
+                texts.push(content);
+            }
+            for text in &texts {
+                let mut open: Vec<&str> = Vec::new();
+                for cap in TAG_REGEX.captures_iter(text) {
+                    let name = cap.get(2).unwrap().as_str();
+                    let known = BUILTIN_VARIABLES.contains(&name)
+                        || self.manifest.prompts.iter().any(|p| p.name == name);
+                    if !known {
+                        return Err(invalid(format!("unknown variable '{}'", name)));
+                    }
+
+                    match &cap[1] {
+                        "#" if open.contains(&name) => {
+                            return Err(invalid(format!(
+                                "'{{{{#{}}}}}' is nested inside a section of the same name",
+                                name
+                            )));
+                        }
+                        "#" => open.push(name),
+                        "/" if open.last() == Some(&name) => {
+                            open.pop();
+                        }
+                        "/" => {
+                            return Err(invalid(format!(
+                                "'{{{{/{}}}}}' does not close an open '{{{{#{}}}}}'",
+                                name, name
+                            )));
+                        }
+                        _ => {}
+                    }
+                }
+                if let Some(name) = open.last() {
+                    return Err(invalid(format!(
+                        "'{{{{#{}}}}}' is never closed with '{{{{/{}}}}}'",
+                        name, name
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SkillTemplate for CustomTemplate {
+    fn render(&self, ctx: &TemplateContext, output_dir: &Path) -> std::io::Result<()> {
+        let variables = variables(ctx);
+        let skill_dir = output_dir.join(&ctx.name);
+
+        // Values end up in paths, so check every target before writing anything
+        let mut targets = Vec::new();
+        for relative in self.files() {
+            let target = PathBuf::from(substitute(&relative.to_string_lossy(), &variables));
+            let inside = target
+                .components()
+                .all(|c| matches!(c, Component::Normal(_)));
+            if !inside {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "{}: path '{}' is not inside the skill directory",
+                        relative.display(),
+                        target.display()
+                    ),
+                ));
+            }
+            targets.push((relative, target));
+        }
+
+        fs::create_dir_all(&skill_dir)?;
+        for (relative, target) in targets {
+            let source = self.root.join(&relative);
+            let target = skill_dir.join(target);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            match fs::read_to_string(&source) {
+                Ok(content) => fs::write(&target, substitute(&content, &variables))?,
+                // Binary files are copied as they are
+                Err(_) => {
+                    fs::copy(&source, &target)?;
+                }
+            }
+            fs::set_permissions(&target, fs::metadata(&source)?.permissions())?;
+        }
+
+        Ok(())
+    }
+
+    fn prompts(&self) -> &[TemplatePrompt] {
+        &self.manifest.prompts
+    }
+}
+
+/// Values of all variables for `ctx`.
+fn variables(ctx: &TemplateContext) -> BTreeMap<String, String> {
+    let mut variables = ctx.variables.clone();
+    variables.insert("name".into(), ctx.name.clone());
+    variables.insert("title".into(), to_title_case(&ctx.name));
+    variables.insert("description".into(), ctx.description.replace('\n', " "));
+    variables.insert("license".into(), ctx.license.clone().unwrap_or_default());
+    if let Some(lang) = ctx.lang.to_possible_value() {
+        variables.insert("lang".into(), lang.get_name().into());
+    }
+    variables.insert("ext".into(), ctx.lang.extension().into());
+    variables.insert("date".into(), today());
+    variables
+}
+
+/// Replace the tags in `text` with `variables`.
+fn substitute(text: &str, variables: &BTreeMap<String, String>) -> String {
+    let value = |name: &str| variables.get(name).map(String::as_str).unwrap_or("");
+
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(cap) = TAG_REGEX.captures(rest) {
+        let tag = cap.get(0).unwrap();
+        let name = &cap[2];
+        out.push_str(&rest[..tag.start()]);
+        rest = &rest[tag.end()..];
+
+        match &cap[1] {
+            "#" => {
+                let end = TAG_REGEX
+                    .captures_iter(rest)
+                    .find(|c| &c[1] == "/" && &c[2] == name)
+                    .and_then(|c| c.get(0));
+                // Templates are checked for balanced sections when loaded
+                let (inner, after) = match end {
+                    Some(m) => (&rest[..m.start()], &rest[m.end()..]),
+                    None => (rest, ""),
+                };
+                if !value(name).is_empty() {
+                    out.push_str(&substitute(inner, variables));
+                }
+                rest = after;
+            }
+            "/" => {}
+            _ => out.push_str(value(name)),
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Today's date in UTC as `YYYY-MM-DD`.
+fn today() -> String {
+    let days = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() / 86_400)
+        .unwrap_or_default() as i64;
+
+    // Civil-from-days, from Howard Hinnant's date algorithms
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::ScriptLang;
+    use tempfile::TempDir;
+
+    fn context(license: Option<&str>) -> TemplateContext {
+        TemplateContext {
+            name: "pdf-tools".into(),
+            description: "Work with PDFs".into(),
+            license: license.map(Into::into),
+            lang: ScriptLang::Bash,
+            include_optional_dirs: true,
+            include_scripts: true,
+            variables: BTreeMap::from([("team".to_string(), "platform".to_string())]),
+        }
+    }
+
+    #[test]
+    fn test_render_substitutes_contents_and_paths() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().join("template");
+        fs::create_dir_all(root.join("scripts")).unwrap();
+        fs::write(
+            root.join(MANIFEST_FILE),
+            "[[prompts]]\nname = \"team\"\ndefault = \"core\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("SKILL.md"),
+            "---\nname: {{name}}\ndescription: {{ description }}\n{{#license}}license: {{license}}\n{{/license}}---\n\n# {{title}}\n\nOwned by {{team}}.\n",
+        )
+        .unwrap();
+        fs::write(root.join("scripts/run.{{ext}}"), "echo {{name}}\n").unwrap();
+
+        let template = CustomTemplate::load(root, None).unwrap();
+        assert_eq!(template.prompts()[0].default.as_deref(), Some("core"));
+
+        let out = temp.path().join("out");
+        template.render(&context(None), &out).unwrap();
+        assert_eq!(
+            fs::read_to_string(out.join("pdf-tools/SKILL.md")).unwrap(),
+            "---\nname: pdf-tools\ndescription: Work with PDFs\n---\n\n# Pdf Tools\n\nOwned by platform.\n"
+        );
+        assert_eq!(
+            fs::read_to_string(out.join("pdf-tools/scripts/run.sh")).unwrap(),
+            "echo pdf-tools\n"
+        );
+        assert!(!out.join("pdf-tools").join(MANIFEST_FILE).exists());
+
+        let licensed = temp.path().join("licensed");
+        template.render(&context(Some("MIT")), &licensed).unwrap();
+        assert!(fs::read_to_string(licensed.join("pdf-tools/SKILL.md"))
+            .unwrap()
+            .contains("license: MIT\n---"));
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_variables() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join("SKILL.md"), "# {{titel}}\n").unwrap();
+
+        let err = CustomTemplate::load(temp.path().to_path_buf(), None)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("unknown variable 'titel'"));
+    }
+
+    #[test]
+    fn test_load_rejects_unbalanced_sections() {
+        let temp = TempDir::new().unwrap();
+        fs::write(
+            temp.path().join("SKILL.md"),
+            "{{#license}}License: {{license}}\n\nRest of the file\n",
+        )
+        .unwrap();
+
+        let err = CustomTemplate::load(temp.path().to_path_buf(), None)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("'{{#license}}' is never closed"));
+
+        fs::write(temp.path().join("SKILL.md"), "{{/license}}\n").unwrap();
+        let err = CustomTemplate::load(temp.path().to_path_buf(), None)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("does not close an open"));
+    }
+
+    #[test]
+    fn test_render_rejects_paths_outside_the_skill() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().join("template");
+        fs::create_dir_all(root.join("{{team}}")).unwrap();
+        fs::write(root.join(MANIFEST_FILE), "[[prompts]]\nname = \"team\"\n").unwrap();
+        fs::write(root.join("{{team}}/notes.md"), "notes\n").unwrap();
+        let template = CustomTemplate::load(root, None).unwrap();
+
+        let mut ctx = context(None);
+        ctx.variables
+            .insert("team".to_string(), "../../escaped".to_string());
+        let out = temp.path().join("out");
+        let err = template.render(&ctx, &out).unwrap_err();
+        assert!(err.to_string().contains("not inside the skill directory"));
+        assert!(!temp.path().join("escaped").exists());
+        assert!(!out.exists());
+    }
+
+    #[test]
+    fn test_today_format() {
+        let date = today();
+        assert_eq!(date.len(), 10);
+        assert!(date.starts_with("20"));
+    }
+}
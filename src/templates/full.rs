@@ -1,7 +1,7 @@
 //! Creates a complete skill structure with scripts, references,
 //! and assets directories, suitable for feature-rich skills.
 
-use super::{to_title_case, SkillTemplate, TemplateContext};
+use super::{to_title_case, write_dependency_manifest, SkillTemplate, TemplateContext};
 use crate::cli::ScriptLang;
 use std::fs;
 use std::path::Path;
@@ -40,6 +40,7 @@ impl SkillTemplate for FullTemplate {
             perms.set_mode(0o755);
             fs::set_permissions(&script_path, perms)?;
         }
+        write_dependency_manifest(ctx, &scripts_dir)?;
 
         // Write reference document
         fs::write(
@@ -103,7 +104,7 @@ Static assets are stored in the `assets/` directory.
     /// Render the main script content for the selected language.
     fn render_script(&self, ctx: &TemplateContext) -> String {
         match ctx.lang {
-            ScriptLang::Python => format!(
+            ScriptLang::Python | ScriptLang::Uv => format!(
                 r#"{}
 """Main entry point for {}."""
 
@@ -125,7 +126,7 @@ def main():
 if __name__ == "__main__":
     main()
 "#,
-                ctx.lang.shebang(),
+                ctx.lang.header(),
                 ctx.name,
                 ctx.description,
                 ctx.name
@@ -158,7 +159,7 @@ fi
 
 echo "Hello from {}!"
 "#,
-                ctx.lang.shebang(),
+                ctx.lang.header(),
                 ctx.name,
                 ctx.name
             ),
@@ -176,7 +177,7 @@ if (verbose) {{
 
 console.log("Hello from {}!");
 "#,
-                ctx.lang.shebang(),
+                ctx.lang.header(),
                 ctx.name,
                 ctx.name
             ),
@@ -194,7 +195,108 @@ if (verbose) {{
 
 console.log("Hello from {}!");
 "#,
-                ctx.lang.shebang(),
+                ctx.lang.header(),
+                ctx.name,
+                ctx.name
+            ),
+
+            ScriptLang::Deno => format!(
+                r#"{}
+// Main entry point for {}.
+
+const verbose: boolean = Deno.args.includes("-v") || Deno.args.includes("--verbose");
+
+if (verbose) {{
+    console.log("Verbose mode enabled");
+}}
+
+console.log("Hello from {}!");
+"#,
+                ctx.lang.header(),
+                ctx.name,
+                ctx.name
+            ),
+
+            ScriptLang::Go => format!(
+                r#"{}
+
+// Main entry point for {}.
+package main
+
+import (
+	"flag"
+	"fmt"
+)
+
+func main() {{
+	verbose := flag.Bool("verbose", false, "Enable verbose output")
+	flag.BoolVar(verbose, "v", false, "Enable verbose output")
+	flag.Parse()
+
+	if *verbose {{
+		fmt.Println("Verbose mode enabled")
+	}}
+
+	fmt.Println("Hello from {}!")
+}}
+"#,
+                ctx.lang.header(),
+                ctx.name,
+                ctx.name
+            ),
+
+            ScriptLang::Rust => format!(
+                r#"{}
+// Main entry point for {}.
+
+fn main() {{
+    let verbose = std::env::args().any(|arg| arg == "-v" || arg == "--verbose");
+
+    if verbose {{
+        println!("Verbose mode enabled");
+    }}
+
+    println!("Hello from {}!");
+}}
+"#,
+                ctx.lang.header(),
+                ctx.name,
+                ctx.name
+            ),
+
+            ScriptLang::Ruby => format!(
+                r#"{}
+# Main entry point for {}.
+
+require "optparse"
+
+verbose = false
+OptionParser.new do |opts|
+  opts.on("-v", "--verbose", "Enable verbose output") {{ verbose = true }}
+end.parse!
+
+puts "Verbose mode enabled" if verbose
+
+puts "Hello from {}!"
+"#,
+                ctx.lang.header(),
+                ctx.name,
+                ctx.name
+            ),
+
+            ScriptLang::Powershell => format!(
+                r#"{}
+# Main entry point for {}.
+
+param([switch]$Verbose)
+
+if ($Verbose) {{
+    Write-Output "Verbose mode enabled"
+}}
+
+Write-Output "Hello from {}!"
+"#,
+                ctx.lang.header(),
                 ctx.name,
                 ctx.name
             ),
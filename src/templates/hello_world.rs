@@ -1,7 +1,7 @@
 //! Creates a skill with a simple greeting script, suitable for
 //! getting started with Agent Skills development.
 
-use super::{to_title_case, SkillTemplate, TemplateContext};
+use super::{to_title_case, write_dependency_manifest, SkillTemplate, TemplateContext};
 use crate::cli::ScriptLang;
 use std::fs;
 use std::path::Path;
@@ -37,6 +37,8 @@ impl SkillTemplate for HelloWorldTemplate {
                 perms.set_mode(0o755);
                 fs::set_permissions(&script_path, perms)?;
             }
+
+            write_dependency_manifest(ctx, &scripts_dir)?;
         }
 
         Ok(())
@@ -91,7 +93,7 @@ Run the greeting script to display a personalized message.
     /// Render the greeting script content for the selected language.
     fn render_script(&self, ctx: &TemplateContext) -> String {
         match ctx.lang {
-            ScriptLang::Python => format!(
+            ScriptLang::Python | ScriptLang::Uv => format!(
                 r#"{}
 """A simple greeting script."""
 
@@ -106,7 +108,7 @@ def main():
 if __name__ == "__main__":
     main()
 "#,
-                ctx.lang.shebang()
+                ctx.lang.header()
             ),
 
             ScriptLang::Bash => format!(
@@ -118,7 +120,7 @@ set -euo pipefail
 name="${{1:-World}}"
 echo "Hello, ${{name}}!"
 "#,
-                ctx.lang.shebang()
+                ctx.lang.header()
             ),
 
             ScriptLang::Javascript => format!(
@@ -128,7 +130,7 @@ echo "Hello, ${{name}}!"
 const name = process.argv[2] || "World";
 console.log(`Hello, ${{name}}!`);
 "#,
-                ctx.lang.shebang()
+                ctx.lang.header()
             ),
 
             ScriptLang::Typescript => format!(
@@ -138,7 +140,72 @@ console.log(`Hello, ${{name}}!`);
 const name: string = process.argv[2] || "World";
 console.log(`Hello, ${{name}}!`);
 "#,
-                ctx.lang.shebang()
+                ctx.lang.header()
+            ),
+
+            ScriptLang::Deno => format!(
+                r#"{}
+// A simple greeting script.
+
+const name: string = Deno.args[0] ?? "World";
+console.log(`Hello, ${{name}}!`);
+"#,
+                ctx.lang.header()
+            ),
+
+            ScriptLang::Go => format!(
+                r#"{}
+
+// A simple greeting script.
+package main
+
+import (
+	"fmt"
+	"os"
+)
+
+func main() {{
+	name := "World"
+	if len(os.Args) > 1 {{
+		name = os.Args[1]
+	}}
+	fmt.Printf("Hello, %s!\n", name)
+}}
+"#,
+                ctx.lang.header()
+            ),
+
+            ScriptLang::Rust => format!(
+                r#"{}
+// A simple greeting script.
+
+fn main() {{
+    let name = std::env::args().nth(1).unwrap_or_else(|| "World".to_string());
+    println!("Hello, {{name}}!");
+}}
+"#,
+                ctx.lang.header()
+            ),
+
+            ScriptLang::Ruby => format!(
+                r#"{}
+# A simple greeting script.
+
+name = ARGV[0] || "World"
+puts "Hello, #{{name}}!"
+"#,
+                ctx.lang.header()
+            ),
+
+            ScriptLang::Powershell => format!(
+                r#"{}
+# A simple greeting script.
+
+param([string]$Name = "World")
+
+Write-Output "Hello, $Name!"
+"#,
+                ctx.lang.header()
             ),
         }
     }
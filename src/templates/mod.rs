@@ -4,14 +4,21 @@
 //! ranging from minimal single-file skills to full-featured skills with
 //! scripts, references, and assets.
 
+pub mod custom;
 mod full;
 mod hello_world;
 mod minimal;
 mod script_based;
 
+use crate::agent::expand_tilde;
 use crate::cli::{ScriptLang, Template};
-use std::path::Path;
+use crate::error::SkiloError;
+use crate::git::{fetch, GitSource, Source};
+use clap::ValueEnum;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
+pub use custom::{CustomTemplate, TemplatePrompt};
 pub use full::FullTemplate;
 pub use hello_world::HelloWorldTemplate;
 pub use minimal::MinimalTemplate;
@@ -33,6 +40,8 @@ pub struct TemplateContext {
     pub include_optional_dirs: bool,
     /// Whether to include script files.
     pub include_scripts: bool,
+    /// Values for the custom variables a template declares.
+    pub variables: BTreeMap<String, String>,
 }
 
 /// Trait for skill templates that generate new skill structures.
@@ -42,6 +51,94 @@ pub trait SkillTemplate {
     /// Creates the skill directory structure, SKILL.md file, and any
     /// additional files based on the template type and context.
     fn render(&self, ctx: &TemplateContext, output_dir: &Path) -> std::io::Result<()>;
+
+    /// Custom variables the template needs values for.
+    fn prompts(&self) -> &[TemplatePrompt] {
+        &[]
+    }
+}
+
+/// Where a template comes from.
+#[derive(Debug, Clone)]
+pub enum TemplateSource {
+    /// One of the templates built into skilo.
+    Builtin(Template),
+    /// A template directory on disk.
+    Local(PathBuf),
+    /// A template directory in a git repository.
+    Git(GitSource),
+}
+
+impl TemplateSource {
+    /// Parse a `--template` value.
+    ///
+    /// Accepts a built-in template name, a local directory, or a repository
+    /// with an optional subdirectory after `#` (e.g. `owner/repo#templates/api`).
+    /// A bare `owner/repo` whose first part is a local directory is taken as a
+    /// missing local path rather than fetched.
+    pub fn parse(spec: &str) -> Result<Self, SkiloError> {
+        if let Ok(template) = Template::from_str(spec, false) {
+            return Ok(Self::Builtin(template));
+        }
+        let local = expand_tilde(spec).unwrap_or_else(|| PathBuf::from(spec));
+        if local.is_dir() {
+            return Ok(Self::Local(local));
+        }
+        let not_found = || {
+            SkiloError::SkillTemplate(format!("template directory not found: {}", local.display()))
+        };
+        let remote = spec.contains('#')
+            || spec.starts_with("git@")
+            || spec.starts_with("http://")
+            || spec.starts_with("https://");
+        if !remote
+            && spec
+                .split('/')
+                .next()
+                .is_some_and(|p| Path::new(p).is_dir())
+        {
+            return Err(not_found());
+        }
+
+        let (repo, subdir) = match spec.split_once('#') {
+            Some((repo, subdir)) => (repo, Some(subdir.trim_matches('/').to_string())),
+            None => (spec, None),
+        };
+        match Source::parse(repo) {
+            Ok(Source::Git(mut git)) => {
+                if subdir.is_some() {
+                    git.subdir = subdir;
+                }
+                Ok(Self::Git(git))
+            }
+            Ok(Source::Local(path)) => {
+                let path = expand_tilde(path.to_str().unwrap_or(".")).unwrap_or(path);
+                if !remote && !path.is_dir() {
+                    return Err(not_found());
+                }
+                Ok(Self::Local(path))
+            }
+            Err(_) => Err(SkiloError::SkillTemplate(format!(
+                "'{}' is not a built-in template, a directory or a repository (owner/repo#dir)",
+                spec
+            ))),
+        }
+    }
+}
+
+/// Load the template from `source`, fetching remote templates through the git cache.
+pub fn load_template(source: &TemplateSource) -> Result<Box<dyn SkillTemplate>, SkiloError> {
+    match source {
+        TemplateSource::Builtin(template) => Ok(get_template(*template)),
+        TemplateSource::Local(path) => Ok(Box::new(CustomTemplate::load(path.clone(), None)?)),
+        TemplateSource::Git(git) => {
+            let checkout = fetch(git)?;
+            Ok(Box::new(CustomTemplate::load(
+                checkout.root.clone(),
+                Some(checkout),
+            )?))
+        }
+    }
 }
 
 /// Get a template implementation for the given template type.
@@ -54,6 +151,18 @@ pub fn get_template(template: Template) -> Box<dyn SkillTemplate> {
     }
 }
 
+/// Write the dependency manifest for `ctx.lang` into `scripts_dir`, if the
+/// language keeps its dependencies outside the scripts.
+pub(crate) fn write_dependency_manifest(
+    ctx: &TemplateContext,
+    scripts_dir: &Path,
+) -> std::io::Result<()> {
+    if let Some((file_name, content)) = ctx.lang.manifest(&ctx.name) {
+        std::fs::write(scripts_dir.join(file_name), content)?;
+    }
+    Ok(())
+}
+
 /// Convert a kebab-case name to Title Case
 pub fn to_title_case(name: &str) -> String {
     name.split('-')
@@ -67,3 +176,38 @@ pub fn to_title_case(name: &str) -> String {
         .collect::<Vec<_>>()
         .join(" ")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expands_tilde() {
+        let Some(home) = dirs::home_dir() else {
+            return;
+        };
+        // The directory does not exist, so the error names the expanded path
+        let err = TemplateSource::parse("~/skilo-templates/api")
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains(&home.join("skilo-templates/api").display().to_string()),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_parse_missing_local_path_is_not_fetched() {
+        // Tests run from the crate root, where `src` is a directory
+        let err = TemplateSource::parse("src/missing")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("template directory not found"), "{}", err);
+        assert!(TemplateSource::parse("./missing").is_err());
+        assert!(matches!(
+            TemplateSource::parse("owner/repo").unwrap(),
+            TemplateSource::Git(_)
+        ));
+    }
+}
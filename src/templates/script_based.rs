@@ -1,7 +1,7 @@
 //! Creates a skill focused on multiple scripts with setup, run,
 //! and cleanup phases, suitable for automation tasks.
 
-use super::{to_title_case, SkillTemplate, TemplateContext};
+use super::{to_title_case, write_dependency_manifest, SkillTemplate, TemplateContext};
 use crate::cli::ScriptLang;
 use std::fs;
 use std::path::Path;
@@ -35,6 +35,7 @@ impl SkillTemplate for ScriptBasedTemplate {
                 fs::set_permissions(&script_path, perms)?;
             }
         }
+        write_dependency_manifest(ctx, &scripts_dir)?;
 
         Ok(())
     }
@@ -97,10 +98,10 @@ This skill provides the following scripts:
     /// Render all scripts (setup, run, cleanup) for the selected language.
     fn render_scripts(&self, ctx: &TemplateContext) -> Vec<(String, String)> {
         let ext = ctx.lang.extension();
-        let shebang = ctx.lang.shebang();
+        let shebang = ctx.lang.header();
 
         match ctx.lang {
-            ScriptLang::Python => vec![
+            ScriptLang::Python | ScriptLang::Uv => vec![
                 (
                     format!("setup.{}", ext),
                     format!(
@@ -290,6 +291,246 @@ console.log(`Running {} with args: ${{args.join(" ")}}`);
 console.log("Cleaning up {}...");
 // Add cleanup logic here
 console.log("Cleanup complete!");
+"#,
+                        shebang, ctx.name, ctx.name
+                    ),
+                ),
+            ],
+
+            ScriptLang::Deno => vec![
+                (
+                    format!("setup.{}", ext),
+                    format!(
+                        r#"{}
+// Setup script for {}.
+
+console.log("Setting up {}...");
+// Add setup logic here
+console.log("Setup complete!");
+"#,
+                        shebang, ctx.name, ctx.name
+                    ),
+                ),
+                (
+                    format!("run.{}", ext),
+                    format!(
+                        r#"{}
+// Main execution script for {}.
+
+console.log(`Running {} with args: ${{Deno.args.join(" ")}}`);
+// Add main logic here
+"#,
+                        shebang, ctx.name, ctx.name
+                    ),
+                ),
+                (
+                    format!("cleanup.{}", ext),
+                    format!(
+                        r#"{}
+// Cleanup script for {}.
+
+console.log("Cleaning up {}...");
+// Add cleanup logic here
+console.log("Cleanup complete!");
+"#,
+                        shebang, ctx.name, ctx.name
+                    ),
+                ),
+            ],
+
+            ScriptLang::Go => vec![
+                (
+                    format!("setup.{}", ext),
+                    format!(
+                        r#"{}
+
+// Setup script for {}.
+package main
+
+import "fmt"
+
+func main() {{
+	fmt.Println("Setting up {}...")
+	// Add setup logic here
+	fmt.Println("Setup complete!")
+}}
+"#,
+                        shebang, ctx.name, ctx.name
+                    ),
+                ),
+                (
+                    format!("run.{}", ext),
+                    format!(
+                        r#"{}
+
+// Main execution script for {}.
+package main
+
+import (
+	"fmt"
+	"os"
+)
+
+func main() {{
+	args := os.Args[1:]
+	fmt.Printf("Running {} with args: %v\n", args)
+	// Add main logic here
+}}
+"#,
+                        shebang, ctx.name, ctx.name
+                    ),
+                ),
+                (
+                    format!("cleanup.{}", ext),
+                    format!(
+                        r#"{}
+
+// Cleanup script for {}.
+package main
+
+import "fmt"
+
+func main() {{
+	fmt.Println("Cleaning up {}...")
+	// Add cleanup logic here
+	fmt.Println("Cleanup complete!")
+}}
+"#,
+                        shebang, ctx.name, ctx.name
+                    ),
+                ),
+            ],
+
+            ScriptLang::Rust => vec![
+                (
+                    format!("setup.{}", ext),
+                    format!(
+                        r#"{}
+// Setup script for {}.
+
+fn main() {{
+    println!("Setting up {}...");
+    // Add setup logic here
+    println!("Setup complete!");
+}}
+"#,
+                        shebang, ctx.name, ctx.name
+                    ),
+                ),
+                (
+                    format!("run.{}", ext),
+                    format!(
+                        r#"{}
+// Main execution script for {}.
+
+fn main() {{
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    println!("Running {} with args: {{args:?}}");
+    // Add main logic here
+}}
+"#,
+                        shebang, ctx.name, ctx.name
+                    ),
+                ),
+                (
+                    format!("cleanup.{}", ext),
+                    format!(
+                        r#"{}
+// Cleanup script for {}.
+
+fn main() {{
+    println!("Cleaning up {}...");
+    // Add cleanup logic here
+    println!("Cleanup complete!");
+}}
+"#,
+                        shebang, ctx.name, ctx.name
+                    ),
+                ),
+            ],
+
+            ScriptLang::Ruby => vec![
+                (
+                    format!("setup.{}", ext),
+                    format!(
+                        r#"{}
+# Setup script for {}.
+
+puts "Setting up {}..."
+# Add setup logic here
+puts "Setup complete!"
+"#,
+                        shebang, ctx.name, ctx.name
+                    ),
+                ),
+                (
+                    format!("run.{}", ext),
+                    format!(
+                        r#"{}
+# Main execution script for {}.
+
+puts "Running {} with args: #{{ARGV.join(" ")}}"
+# Add main logic here
+"#,
+                        shebang, ctx.name, ctx.name
+                    ),
+                ),
+                (
+                    format!("cleanup.{}", ext),
+                    format!(
+                        r#"{}
+# Cleanup script for {}.
+
+puts "Cleaning up {}..."
+# Add cleanup logic here
+puts "Cleanup complete!"
+"#,
+                        shebang, ctx.name, ctx.name
+                    ),
+                ),
+            ],
+
+            ScriptLang::Powershell => vec![
+                (
+                    format!("setup.{}", ext),
+                    format!(
+                        r#"{}
+# Setup script for {}.
+
+$ErrorActionPreference = "Stop"
+
+Write-Output "Setting up {}..."
+# Add setup logic here
+Write-Output "Setup complete!"
+"#,
+                        shebang, ctx.name, ctx.name
+                    ),
+                ),
+                (
+                    format!("run.{}", ext),
+                    format!(
+                        r#"{}
+# Main execution script for {}.
+
+$ErrorActionPreference = "Stop"
+
+Write-Output "Running {} with args: $args"
+# Add main logic here
+"#,
+                        shebang, ctx.name, ctx.name
+                    ),
+                ),
+                (
+                    format!("cleanup.{}", ext),
+                    format!(
+                        r#"{}
+# Cleanup script for {}.
+
+$ErrorActionPreference = "Stop"
+
+Write-Output "Cleaning up {}..."
+# Add cleanup logic here
+Write-Output "Cleanup complete!"
 "#,
                         shebang, ctx.name, ctx.name
                     ),